@@ -4,6 +4,7 @@ extern crate rustling_ontology_values;
 extern crate rustling_ontology_moment;
 
 mod rules;
+mod rules_celebrations;
 mod training;
 
 use rustling_ontology_values::DimensionKind::*;
@@ -14,8 +15,11 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
     rules::rules_numbers(&mut b)?;
     rules::rules_datetime(&mut b)?;
+    rules_celebrations::rules_celebration(&mut b)?;
+    rustling_ontology_values::rules_timestamp::rules_timestamp(&mut b)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
+    rustling_ontology_values::rules_stopwatch::rules_stopwatch(&mut b)?;
     rules::rules_temperature(&mut b)?;
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
@@ -30,6 +34,7 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     let mut v = vec![];
     training::examples_numbers(&mut v);
     training::examples_datetime(&mut v);
+    training::examples_durations(&mut v);
     training::examples_temperature(&mut v);
     training::examples_finance(&mut v);
     training::examples_percentage(&mut v);
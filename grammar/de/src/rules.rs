@@ -2,7 +2,9 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::number_format::{Grouping, NumberFormat};
 use rustling_ontology_moment::{Grain, PeriodComp, Weekday, Period};
+use rustling_ontology_values::rules_semester::SemesterWords;
 
 fn german_article_regex() -> &'static str {
     r#"(?:i[nm]s?|zu[rm]?|beim?|um|w[äa]h?rend|f[uü]r) ?(?:de(?:r|m|s|n)|die|das)?"#
@@ -12,6 +14,20 @@ fn german_article_before_cycle() -> &'static str {
     r#"(?:i[nm]s?|a[nm]) (?:de(?:r|m|s|n)|die|das)|(?:i[nm]s?|a[nm])|(?:de(?:r|m|s|n)|die|das)"#
 }
 
+const NUMBER_FORMAT: NumberFormat = NumberFormat {
+    decimal_separators: &[','],
+    grouping_separators: &['.', '\'', '’', '\u{a0}', '\u{2009}', '\u{202f}'],
+    groupings: &[Grouping::Thousands],
+};
+
+const SEMESTER_WORDS: SemesterWords = SemesterWords {
+    article: r#"das|im|dem"#,
+    of_year: r#"von|des jahres|im jahre?"#,
+    half_of_year: r#"hälfte des jahres"#,
+    half_article: r#"die|in der|der"#,
+    abbreviation: r#"h"#,
+};
+
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
         number_check!(),
@@ -118,6 +134,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
                      unit: a.value().unit,
+                     precision: b.value().precision(),
+                     fuzzy_range: b.value().fuzzy_range(),
                      ..AmountOfMoneyValue::default()
                  })
              });
@@ -127,6 +145,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |a, b| Ok(AmountOfMoneyValue {
                  value: a.value().value(),
                  unit: b.value().unit,
+                 precision: a.value().precision(),
+                 fuzzy_range: a.value().fuzzy_range(),
                  ..AmountOfMoneyValue::default()
              })
     );
@@ -156,6 +176,10 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"sek(?:unden?|\.?)|s\.|sec"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Second))
     );
+    b.rule_1_terminal("millisecond (unit-of-duration)",
+                      b.reg(r#"millisekunden?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Millisecond))
+    );
     b.rule_1_terminal("minute (unit-of-duration)",
                       b.reg(r#"min(?:uten?|\.?)"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Minute))
@@ -219,7 +243,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::from_integer(uod.value().grain, integer.value()))
     );
     b.rule_2("number.number hours",
              b.reg(r#"(\d+)\.(\d+)"#)?,
@@ -374,6 +398,32 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              duration_check!(|duration: &DurationValue| !duration.prefixed),
              |a, b| Ok(a.value() + b.value())
     );
+    b.rule_3("<duration> - <duration> (interval)",
+             duration_check!(|duration: &DurationValue| !duration.suffixed),
+             b.reg(r#"-|bis"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval(a.value(), b.value())
+    );
+    b.rule_4("between <duration> and <duration> (interval)",
+             b.reg(r#"zwischen|von"#)?,
+             duration_check!(|duration: &DurationValue| !duration.suffixed),
+             b.reg(r#"und|bis|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, a, _, b| helpers::compose_duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<integer> - <duration> (interval)",
+             integer_check_by_range!(0),
+             b.reg(r#"-|bis"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value())
+    );
+    b.rule_4("between <integer> and <duration> (interval)",
+             b.reg(r#"zwischen|von"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"und|bis|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value())
+    );
     Ok(())
 }
 
@@ -448,6 +498,10 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                         }
                     }
     );
+    b.rule_1_terminal("semester (cycle)",
+                      b.reg(r#"halbjahr(?:e?s)?|semester"#)?,
+                      |_| CycleValue::new(Grain::Semester)
+    );
     b.rule_1_terminal("year (cycle)",
                       b.reg(r#"jah(ren|res|re|rs|r)"#)?,
                       |text_match|{ 
@@ -696,258 +750,38 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"dezembers?|dez\.?"#)?,
                       |_| helpers::month(12)
     );
-    b.rule_1_terminal("christmas",
-                      b.reg(r#"christtag|weih?nacht(?:en|s(?:feier)?tag)?"#)?,
-                      |_| Ok(helpers::month_day(12, 25)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("christmas days (24/12-26/12)",
-                      b.reg(r#"weihnachtsfest"#)?,
-                      |_| {
-                        Ok(helpers::month_day(12, 24)?
-                          .span_to(&helpers::month_day(12, 26)?, true)?
-                          .form(Form::Celebration))
-                      }
-    );
-    b.rule_1_terminal("christmas eve",
-                      b.reg(r#"christnacht|(?:heilig(?:e[r|n])?|weihnachts) ?abend"#)?,
-                      |_| Ok(helpers::month_day(12, 24)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("new year's eve",
-                      b.reg(r#"silvester|neujahrsabend"#)?,
-                      |_| Ok(helpers::month_day(12, 31)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("new year's day",
-                      b.reg(r#"neujahr(?:s?tag)?"#)?,
-                      |_| Ok(helpers::month_day(1, 1)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Epiphanias",
-                      b.reg(r#"heiligen? drei k[öo]nigen?"#)?,
-                      |_| Ok(helpers::month_day(1, 6)?.form(Form::Celebration).too_ambiguous())
-    );
-
-    b.rule_1_terminal("Candlemess",
-        b.reg(r#"lichtmess"#)?,
-        |_| Ok(helpers::month_day(2, 2)?.form(Form::Celebration).too_ambiguous())
-    );
-
-    b.rule_1_terminal("rosenmontag (Shrove Monday)",
-        b.reg(r#"rosenmontag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -48, &helpers::easter()?)?.form(Form::Celebration)),
-    );
-
-    b.rule_1_terminal("fastnachtsdienstag (Shrove Tuesday)",
-        b.reg(r#"fastnachtsdienstag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -47, &helpers::easter()?)?.form(Form::Celebration)),
-    );
-
-    b.rule_1_terminal("aschermittwoch (Ash Wednesday)",
-        b.reg(r#"aschermittwoch"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -46, &helpers::easter()?)?.form(Form::Celebration)),
-    );
-
-    b.rule_1_terminal("palmsonntag (Palm Sunday)",
-        b.reg(r#"palmsonntag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -7, &helpers::easter()?)?.form(Form::Celebration)),
-    );
-    b.rule_1_terminal("Holy Thursday",
-        b.reg(r#"gr[üu]ndonnerstag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -3, &helpers::easter()?)?
-                .form(Form::Celebration))
-    );
-    b.rule_1_terminal("Good Friday",
-        b.reg(r#"karfreitag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -2, &helpers::easter()?)?
-                .form(Form::Celebration))
-    );
-    b.rule_1_terminal("Lent",
-        b.reg(r#"(?:in|w[aä]hrend) der fastenzeit"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -47, &helpers::easter()?)?
-                          .span_to(&helpers::easter()?, false)?
-                          .form(Form::Celebration))
-    );
-
-    b.rule_1_terminal("fasnet",
-        b.reg(r#"fast?nacht|(?:in|w[aä]hrend) der fasnet"#)?,
-        |_| Ok(helpers::month_day(11, 11)?
-                          .span_to(&helpers::cycle_nth_after(Grain::Day, -47, &helpers::easter()?)?, false)?
-                          .form(Form::Celebration))
-    );
-    b.rule_1_terminal("Easter",
-        b.reg(r#"oster(?:n|sonntag)"#)?,
-        |_| Ok(helpers::easter()?
-                .form(Form::Celebration))
-    );
-    b.rule_1_terminal("Easter Monday",
-        b.reg(r#"ostermontag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, 1, &helpers::easter()?)?
-                .form(Form::Celebration))
-    );
-    b.rule_1_terminal("ascension",
-        b.reg(r#"himmelfahrt|auffahrt"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, 39, &helpers::easter()?)?
-                .form(Form::Celebration))
-
-    );
-    b.rule_1_terminal("Pencost",
-        b.reg(r#"pfingst(?:en|sonntag|feiertag(?:en)?)"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, 49, &helpers::easter()?)?
-                .form(Form::Celebration))
-    );
-
-    b.rule_1_terminal("Pencost Monday",
-        b.reg(r#"pfingstmontag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, 50, &helpers::easter()?)?
-                .form(Form::Celebration))
-    );
-
-    b.rule_1_terminal("valentine's day",
-                      b.reg(r#"valentin'?stag"#)?,
-                      |_| Ok(helpers::month_day(2, 14)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("labor day",
-                      b.reg(r#"tag der arbeit"#)?,
-                      |_| Ok(helpers::month_day(5, 1)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Schweizer Bundesfeiertag",
-                      b.reg(r#"schweiz(?:er)? (?:bundes)?feiertag|bundes feiertag"#)?,
-                      |_| Ok(helpers::month_day(8, 1)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Augsburg Celebration",
-                      b.reg(r#"augsburger hohe[smn] friedensfest"#)?,
-                      |_| Ok(helpers::month_day(8, 8)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("assumption day",
-                      b.reg(r#"mari[äa] himmelfahrt(?:stag)?"#)?,
-                      |_| Ok(helpers::month_day(8, 15)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("reformation day",
-                      b.reg(r#"reformations(?:tag|fest)?"#)?,
-                      |_| Ok(helpers::month_day(10, 31)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("All saint's day",
-                      b.reg(r#"allerheiligen(?:tag)?"#)?,
-                      |_| Ok(helpers::month_day(11, 1)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Holy Joseph",
-                      b.reg(r#"sankt josef"#)?,
-                      |_| Ok(helpers::month_day(3, 19)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Holy Florian",
-                      b.reg(r#"sankt florian"#)?,
-                      |_| Ok(helpers::month_day(5, 4)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Holy Rupert",
-                      b.reg(r#"sankt rupert"#)?,
-                      |_| Ok(helpers::month_day(9, 24)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("German national celebration",
-                      b.reg(r#"tag (?:der )?deutsc?hen? einheit"#)?,
-                      |_| Ok(helpers::month_day(10, 3)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Day of popular vote",
-                      b.reg(r#"tag der volksabtimmun"#)?,
-                      |_| Ok(helpers::month_day(10, 10)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Austrian national celebration",
-                      b.reg(r#"(?:[öo]sterreichischer? )?nationalfeiertag|national feiertag"#)?,
-                      |_| Ok(helpers::month_day(10, 26)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Armistice Celebration",
-                      b.reg(r#"waffenstillstandserkl[äa]rung"#)?,
-                      |_| Ok(helpers::month_day(11, 11)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Holy Martin",
-                      b.reg(r#"sankt martin|martinstag"#)?,
-                      |_| Ok(helpers::month_day(11, 11)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Holy Leopold",
-                      b.reg(r#"sankt leopold"#)?,
-                      |_| Ok(helpers::month_day(11, 15)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Holy Joseph",
-                      b.reg(r#"josefstag"#)?,
-                      |_| Ok(helpers::month_day(3, 19)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Switzerland national celebration",
-                      b.reg(r#"an der bundesfeier"#)?,
-                      |_| Ok(helpers::month_day(8, 1)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Berchtoldstag",
-        b.reg(r#"berchtoldstag"#)?,
-        |_| Ok(helpers::month_day(1, 2)?.form(Form::Celebration))
+    b.rule_1_terminal("now",
+                      b.reg(r#"(?:genau ?)?jetzt|aktuelle(?:r|n|s|m)?|gegenw[äa]rtige(?:r|n|s|m)?|(?:diesen|im|in diesem) (?:moment|augenblick)|nun|sofort|gerade (?:eben|jetzt)"#)?,
+                      |_| helpers::cycle_nth(Grain::Second, 0)
     );
-    b.rule_1_terminal("Immaculate conception",
-                      b.reg(r#"mari[äa] empf[äa]ngnis"#)?,
-                      |_| Ok(helpers::month_day(12, 8)?.form(Form::Celebration))
+    // Vague expressions
+    b.rule_1_terminal("in Kürze",
+                      b.reg(r#"in k[üu]rze|gleich|in einem (?:moment|augenblick)"#)?,
+                      |_| helpers::vague(Vagueness::Shortly)
     );
-    b.rule_1_terminal("Stephanie's day",
-                      b.reg(r#"stefanitag"#)?,
-                      |_| Ok(helpers::month_day(12, 26)?.form(Form::Celebration))
+    b.rule_1_terminal("bald",
+                      b.reg(r#"(?:sehr )?bald|demn[äa]chst"#)?,
+                      |_| helpers::vague(Vagueness::Soon)
     );
-    b.rule_1_terminal("Women's day",
-                      b.reg(r#"(?:internationale[rnm] )?frauentag"#)?,
-                      |_| Ok(helpers::month_day(3, 8)?.form(Form::Celebration))
+    b.rule_1_terminal("in einer Weile",
+                      b.reg(r#"(?:in|nach) einer (?:kleinen )?weile"#)?,
+                      |_| helpers::vague(Vagueness::InAWhile)
     );
-
-    b.rule_1("Father's Day",  // third Sunday of June
-        b.reg(r#"vatt?er(?: ?tag)?|(?:herren|m[äa]nner)tag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, 39, &helpers::easter()?)?
-                .form(Form::Celebration))
-    );
-    b.rule_1_terminal("Mother's Day",
-                      b.reg(r#"mutt?ertag|mutt?er (?:tag)?"#)?,
-                      |_| Ok(helpers::day_of_week(Weekday::Sun)?
-                          .intersect(&helpers::month(5)?)?
-                          .intersect(&helpers::cycle_nth_after(Grain::Week, 1, &helpers::month_day(5, 1)?)?)?
-                          .form(Form::Celebration))
-    );
-    b.rule_1_terminal("halloween day",
-                      b.reg(r#"hall?owe?en?"#)?,
-                      |_| Ok(helpers::month_day(10, 31)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Allerheiligen",
-                      b.reg(r#"allerheiligen?|aller heiligen?"#)?,
-                      |_| Ok(helpers::month_day(11, 1)?.form(Form::Celebration))
-    );
-    b.rule_1_terminal("Sunday of the dead (German protestant)",
-        b.reg(r#"totensonntag"#)?,
-        |_| Ok(helpers::day_of_week(Weekday::Sun)?
-                          .intersect(&helpers::cycle_nth_after(Grain::Week, 3, &helpers::month_day(11, 1)?)?)?
-                          .form(Form::Celebration))
+    b.rule_1_terminal("später heute",
+                      b.reg(r#"sp[äa]ter heute|heute sp[äa]ter"#)?,
+                      |_| helpers::vague(Vagueness::LaterToday)
     );
-
-    b.rule_1_terminal("Nikolaus",
-                      b.reg(r#"nikolaus(?: ?tag|abend)?|nikolo"#)?,
-                      |_| Ok(helpers::month_day(12, 6)?.form(Form::Celebration))
+    b.rule_1_terminal("kürzlich",
+                      b.reg(r#"k[üu]rzlich|neulich|vor kurzem|in letzter zeit"#)?,
+                      |_| helpers::vague(Vagueness::Recently)
     );
-
-    b.rule_2("<ordinal> advent sunday",
-        ordinal_check_by_range!(1, 4),
-        b.reg(r#"advents?"#)?,
-        |ordinal, _| {
-            let christmas = helpers::month_day(12, 25)?;
-            let offset = - (4 - ordinal.value().value + 1);
-            Ok(helpers::cycle_nth_after(Grain::Week, offset, &christmas)?
-                .intersect(&helpers::day_of_week(Weekday::Sun)?)?
-                .form(Form::Celebration))
-        }    
-    );
-    //Volkstrauertag
-
-    b.rule_1_terminal("memorial day",
-        b.reg(r#"volkstrauertag"#)?,
-        |_| {
-            let christmas = helpers::month_day(12, 25)?;
-            let offset = -6;
-            Ok(helpers::cycle_nth_after(Grain::Week, offset, &christmas)?
-                .intersect(&helpers::day_of_week(Weekday::Sun)?)?
-                .form(Form::Celebration))
-        }    
+    b.rule_1_terminal("in den nächsten Tagen",
+                      b.reg(r#"in den (?:n[äa]chsten|kommenden) tagen|in ein paar tagen"#)?,
+                      |_| helpers::vague(Vagueness::ComingDays)
     );
-
-    b.rule_1_terminal("now",
-                      b.reg(r#"(?:genau ?)?jetzt|aktuelle(?:r|n|s|m)?|gegenw[äa]rtige(?:r|n|s|m)?|(?:diesen|im|in diesem) (?:moment|augenblick)|nun|sofort|gerade (?:eben|jetzt)"#)?,
-                      |_| helpers::cycle_nth(Grain::Second, 0)
+    b.rule_1_terminal("vor ein paar Wochen",
+                      b.reg(r#"vor (?:ein paar|einigen|wenigen|mehreren) wochen"#)?,
+                      |_| helpers::vague(Vagueness::FewWeeksAgo)
     );
     b.rule_1_terminal("today",
                       b.reg(r#"heute?|(?:um diese |zu dieser |zur|der)zeit|um diesen zeitpunkt|zu diesem zeitpunkt|derzeitig|momentan"#)?,
@@ -1045,6 +879,52 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              cycle_check!(),
              |_, a, _, b| a.value().last_of(&helpers::cycle_nth(b.value().grain, 0)?)
     );
+    b.rule_4("nth last <cycle> of this <cycle>",
+             b.reg(r#"(?:de[rnms] )?(vor|zweit|dritt|viert|f[üu]nft)letzte[rns]?"#)?,
+             cycle_check!(),
+             b.reg(german_article_before_cycle())?,
+             cycle_check!(),
+             |text_match, a, _, b| {
+                 let n = match text_match.group(1).as_ref() {
+                     "vor" | "zweit" => 1,
+                     "dritt" => 2,
+                     "viert" => 3,
+                     "fünft" | "funft" => 4,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 a.value().nth_last_of(n, &helpers::cycle_nth(b.value().grain, 0)?)
+             }
+    );
+    b.rule_4("nth last <cycle> of <datetime>",
+             b.reg(r#"(?:de[rnms] )?(vor|zweit|dritt|viert|f[üu]nft)letzte[rns]?"#)?,
+             cycle_check!(),
+             b.reg(r#"um|im|in der|des"#)?,
+             datetime_check!(),
+             |text_match, cycle, _, datetime| {
+                 let n = match text_match.group(1).as_ref() {
+                     "vor" | "zweit" => 1,
+                     "dritt" => 2,
+                     "viert" => 3,
+                     "fünft" | "funft" => 4,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 cycle.value().nth_last_of(n, datetime.value())
+             }
+    );
+    b.rule_5("the <ordinal> day of this <cycle>",
+             b.reg(r#"de[rnms]|am"#)?,
+             ordinal_check_by_range!(1, 366),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Day),
+             b.reg(german_article_before_cycle())?,
+             cycle_check!(|cycle: &CycleValue| cycle.grain.is_greater_than_day()),
+             |_, ordinal, _, _, b| helpers::nth_day_of(ordinal.value().value - 1, &helpers::cycle_nth(b.value().grain, 0)?)
+    );
+    b.rule_3("day <integer> of the year",
+             b.reg(r#"tag"#)?,
+             integer_check_by_range!(1, 366),
+             b.reg(r#"des jahres"#)?,
+             |_, integer, _| helpers::nth_day_of(integer.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+    );
     b.rule_3("last <cycle> of <time-cycle>",
              b.reg(r#"letzte(?:r|n|s)?"#)?,
              cycle_check!(),
@@ -1091,6 +971,86 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              datetime_check!(form!(Form::Year(_))),
              |_, year| Ok(year.value().clone())
     );
+    b.rule_1_terminal("decade (90er, 1990er)",
+                      b.reg(r#"(?:die |den )?(\d0|\d{3}0)ern?(?: jahren?)?"#)?,
+                      |text_match| helpers::decade(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("decade (neunziger)",
+                      b.reg(r#"(?:die |den )?(zwanzig|drei(?:ß|ss)ig|vierzig|fünfzig|sechzig|siebzig|achtzig|neunzig)ern?(?: jahren?)?"#)?,
+                      |text_match| {
+                          let start = match text_match.group(1).as_ref() {
+                              "zwanzig" => 20,
+                              "dreißig" | "dreissig" => 30,
+                              "vierzig" => 40,
+                              "fünfzig" => 50,
+                              "sechzig" => 60,
+                              "siebzig" => 70,
+                              "achtzig" => 80,
+                              "neunzig" => 90,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::decade(start)
+                      }
+    );
+    b.rule_2("<ordinal> jahrhundert (century)",
+             ordinal_check!(),
+             b.reg(r#"jahrhunderts?"#)?,
+             |ordinal, _| helpers::century(ordinal.value().value as i32)
+    );
+    b.rule_2("anfang|mitte|ende <decade or century>",
+             b.reg(r#"(anfang|mitte|ende) de[rs]"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |text_match, datetime| {
+                 let position = match text_match.group(1).as_ref() {
+                     "anfang" => Position::Start,
+                     "mitte" => Position::Middle,
+                     "ende" => Position::End,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 datetime.value().part_of_years(position)
+             }
+    );
+    b.rule_2("in <decade or century>",
+             b.reg(r#"im|in den|während de[rs]"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |_, datetime| Ok(datetime.value().clone())
+    );
+    b.rule_2("KW <integer>",
+             b.reg(r#"(?:die |der |in der )?(?:kw|kalenderwoche|woche)(?: nr\.?| nummer)?"#)?,
+             integer_check_by_range!(1, 53),
+             |_, integer| helpers::iso_week(integer.value().value as u32)
+    );
+    b.rule_1_terminal("KW (KW17)",
+                      b.reg(r#"(?:die |der |in der )?kw(\d{1,2})"#)?,
+                      |text_match| helpers::iso_week(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("iso week (2024-W05)",
+                      b.reg(r#"(\d{4})-?w(\d{2})"#)?,
+                      |text_match| helpers::iso_year_week(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_2("<ordinal> Woche des Jahres",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"woche (?:des|dieses) jahres"#)?,
+             |ordinal, _| helpers::iso_week_of_this_year(ordinal.value().value as u32)
+    );
+    b.rule_3("die <ordinal> Woche des Jahres",
+             b.reg(r#"die|der|in der"#)?,
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"woche (?:des|dieses) jahres"#)?,
+             |_, ordinal, _| helpers::iso_week_of_this_year(ordinal.value().value as u32)
+    );
+    b.rule_2("<week> <year>",
+             datetime_check!(form!(Form::IsoWeek(_))),
+             datetime_check!(form!(Form::Year(_))),
+             |week, year| helpers::iso_year_week(year.value().form_year()?, week.value().form_iso_week()?)
+    );
+    b.rule_3("<week> im Jahr <year>",
+             datetime_check!(form!(Form::IsoWeek(_))),
+             b.reg(r#"im jahre?|von|/"#)?,
+             datetime_check!(form!(Form::Year(_))),
+             |week, _, year| helpers::iso_year_week(year.value().form_year()?, week.value().form_iso_week()?)
+    );
+    rustling_ontology_values::rules_semester::rules_semester(b, &SEMESTER_WORDS)?;
     // das Jahr
     b.rule_1("year",
              integer_check_by_range!(1900, 2100),
@@ -2069,17 +2029,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     b.rule_2("<temp> below",
              temperature_check!(),
              b.reg(r#"unter(?:m| de[mn])? (?:gefrierpunkt|null|0)"#)?,
-             |temp, _| {
-                 if temp.value().value >= 0.0 {
-                     Ok(TemperatureValue {
-                         value: -1.0 * temp.value().value,
-                         unit: temp.value().unit,
-                         latent: false,
-                     })
-                 } else {
-                     Ok(temp.value().clone())
-                 }
-             }
+             |temp, _| helpers::temperature_below_zero(temp.value())
     );
     b.rule_2("above <temp>",
              b.reg(r#"plus"#)?,
@@ -2156,6 +2106,19 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  latent: false,
              })
     );
+    b.rule_3("<temp> - <temp> (interval)",
+             temperature_check!(),
+             b.reg(r#"-|bis"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("between <temp> and <temp> (interval)",
+             b.reg(r#"zwischen|von"#)?,
+             temperature_check!(),
+             b.reg(r#"und|bis|-"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
     Ok(())
 }
 
@@ -2239,16 +2202,19 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("couple",
                       b.reg(r#"(?:ein )?paar"#)?,
-                      |_| IntegerValue::new(2)
+                      |_| IntegerValue::new_fuzzy(2, 2, 3)
     );
-    b.rule_1_terminal("few",
+    b.rule_1_terminal("several",
                       b.reg(r#"mehrere"#)?,
-                      |_| Ok(IntegerValue {
-                          value: 3,
-                          grain: Some(1),
-                          precision: Approximate,
-                          ..IntegerValue::default()
-                      })
+                      |_| IntegerValue::new_fuzzy(4, 3, 7)
+    );
+    b.rule_1_terminal("some",
+                      b.reg(r#"einige"#)?,
+                      |_| IntegerValue::new_fuzzy(3, 2, 5)
+    );
+    b.rule_1_terminal("dozens",
+                      b.reg(r#"dutzende"#)?,
+                      |_| IntegerValue::new_fuzzy(36, 24, 99)
     );
     b.rule_1_terminal("integer (20..90)",
                       b.reg(r#"(zwanzig|drei(?:ss|β|ß)ig|vierzig|f[üu]nfzig|sechzig|siebzig|achtzig|neunzig)"#)?,
@@ -2276,13 +2242,21 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check_by_range!(10, 90, |integer: &IntegerValue| integer.value % 10 == 0),
              |a, _, b| IntegerValue::new(a.value().value + b.value().value)
     );
+    b.rule_1_terminal("roman numeral",
+                      b.reg(r#"\b([ivxlc]{3,}|[mdclxvi]{4,})\b"#)?,
+                      |text_match| Ok(IntegerValue {
+                          value: helpers::roman_numeral(&text_match.group(1))?,
+                          latent: true,
+                          ..IntegerValue::default()
+                      })
+    );
     b.rule_1_terminal("integer (numeric)",
                       b.reg(r#"(\d{1,18})"#)?,
                       |text_match| IntegerValue::new(text_match.group(1).parse()?)
     );
-    b.rule_1_terminal("integer with thousands separator .",
-                      b.reg(r#"(\d{1,3}(\.\d\d\d){1,5})"#)?,
-                      |text_match| IntegerValue::new(text_match.group(1).replace(".", "").parse()?)
+    b.rule_1_terminal("number with digit grouping",
+                      b.reg(&NUMBER_FORMAT.regex())?,
+                      |text_match| NUMBER_FORMAT.parse(&text_match.group(1))
     );
 
     b.rule_2("number hundreds",
@@ -2351,33 +2325,44 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..FloatValue::default()
                  })
              });
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
-                      |text_match| FloatValue::new(text_match.group(1).replace(".", "").replace(",", ".").parse()?)
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+) ?/ ?(\d+)"#)?,
+                      |text_match| helpers::numeric_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_2("<integer> <denominator> (fraction)",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(halbe?|drittel|viertel|f[üu]nftel|sechstel|sieb(?:en)?tel|achtel|neuntel|zehntel)"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "halb" | "halbe" => 2,
+                     "drittel" => 3,
+                     "viertel" => 4,
+                     "fünftel" | "funftel" => 5,
+                     "sechstel" => 6,
+                     "siebtel" | "siebentel" => 7,
+                     "achtel" => 8,
+                     "neuntel" => 9,
+                     "zehntel" => 10,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::fraction(integer.value().value, denominator)
+             }
+    );
+    b.rule_2("<integer> <fraction> (mixed number)",
+             integer_check!(|integer: &IntegerValue| !integer.prefixed),
+             float_check!(|float: &FloatValue| float.fraction.is_some()),
+             |integer, fraction| helpers::compose_mixed_fraction(integer.value(), fraction.value())
+    );
+    b.rule_3("<integer> und <fraction> (mixed number)",
+             integer_check!(|integer: &IntegerValue| !integer.prefixed),
+             b.reg(r#"und"#)?,
+             float_check!(|float: &FloatValue| float.fraction.is_some()),
+             |integer, _, fraction| helpers::compose_mixed_fraction(integer.value(), fraction.value())
     );
     b.rule_2("numbers prefix with -, negative or minus",
-             b.reg(r#"-|minus|negativ"#)?,
+             b.reg(r#"-|−|minus|negativ"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
-             |_, a| -> RuleResult<NumberValue> {
-                 Ok(match a.value().clone() {
-                     NumberValue::Integer(integer) => {
-                         IntegerValue {
-                             value: integer.value * -1,
-                             prefixed: true,
-                             ..integer
-                         }
-                             .into()
-                     }
-                     NumberValue::Float(float) => {
-                         FloatValue {
-                             value: float.value * -1.0,
-                             prefixed: true,
-                             ..float
-                         }
-                             .into()
-                     }
-                 })
-             });
+             |_, a| helpers::negate_number(a.value()));
     b.rule_2("numbers prefix with +, positive",
              b.reg(r#"\+"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -2403,44 +2388,24 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_2("numbers suffixes (K, M, G)",
              number_check!(|number: &NumberValue| !number.suffixed()),
-             b.reg_neg_lh(r#"([kmg])"#, r#"^[^\W\$€]"#)?,
+             b.reg_neg_lh(r#"(tsd|mio|mrd|[kmg])"#, r#"^[^\W\$€]"#)?,
              |a, text_match| -> RuleResult<NumberValue> {
                  let multiplier = match text_match.group(0).as_ref() {
-                     "k" => 1000,
-                     "m" => 1000000,
-                     "g" => 1000000000,
+                     "k" | "tsd" => 1e3,
+                     "m" | "mio" => 1e6,
+                     "g" | "mrd" => 1e9,
                      _ => return Err(RuleError::Invalid.into()),
                  };
-                 Ok(match a.value().clone() {
-                     // checked
-                     NumberValue::Integer(integer) => {
-                         IntegerValue {
-                             value: integer.value * multiplier,
-                             suffixed: true,
-                             ..integer
-                         }
-                             .into()
-                     }
-                     NumberValue::Float(float) => {
-                         let product = float.value * (multiplier as f64);
-                         if product.floor() == product {
-                             IntegerValue {
-                                 value: product as i64,
-                                 suffixed: true,
-                                 ..IntegerValue::default()
-                             }
-                                 .into()
-                         } else {
-                             FloatValue {
-                                 value: product,
-                                 suffixed: true,
-                                 ..float
-                             }
-                                 .into()
-                         }
-                     }
-                 })
+                 helpers::compose_magnitude(a.value(), multiplier)
              });
+    b.rule_1_terminal("scientific notation (1e6)",
+                      b.reg(r#"(\d+(?:[.,]\d+)?)e([+-]?\d{1,2})"#)?,
+                      |text_match| helpers::compose_power(text_match.group(1).replace(",", ".").parse()?, 10.0, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("power (10^3)",
+                      b.reg(r#"(\d+) ?\^ ?(\d{1,2})"#)?,
+                      |text_match| helpers::compose_power(1.0, text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
     b.rule_1_terminal("ordinals (first..19th)",
                       b.reg(r#"(nullte|erste|zweite|dritte|vierte|f[üu]nfte|sechste|sieb(?:en)?te|achte|neunte|zehnte|elfte|zw[öo]lfte|dreizehnte|vierzehnte|f[üu]nfzehnte|sechzehnte|siebzehnte|achtzehnte|neunzehnte)[rsnm]?"#)?,
                       |text_match| {
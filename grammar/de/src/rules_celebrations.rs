@@ -0,0 +1,159 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+use rustling_ontology_values::rules_holidays::{Holiday, rules_holidays};
+use rustling_ontology_moment::{Weekday, Grain};
+
+const HOLIDAYS: &[(Holiday, &str)] = &[
+    (Holiday::Christmas, r#"christtag|weih?nacht(?:en|s(?:feier)?tag)?"#),
+    (Holiday::ChristmasEve, r#"christnacht|(?:heilig(?:e[r|n])?|weihnachts) ?abend"#),
+    (Holiday::NewYearsEve, r#"silvester|neujahrsabend"#),
+    (Holiday::NewYearsDay, r#"neujahr(?:s?tag)?"#),
+    (Holiday::ShroveMonday, r#"rosenmontag"#),
+    (Holiday::ShroveTuesday, r#"fastnachtsdienstag"#),
+    (Holiday::AshWednesday, r#"aschermittwoch"#),
+    (Holiday::PalmSunday, r#"palmsonntag"#),
+    (Holiday::MaundyThursday, r#"gr[üu]ndonnerstag"#),
+    (Holiday::GoodFriday, r#"karfreitag"#),
+    (Holiday::Easter, r#"oster(?:n|sonntag)"#),
+    (Holiday::EasterMonday, r#"ostermontag"#),
+    (Holiday::Ascension, r#"himmelfahrt|auffahrt"#),
+    (Holiday::Pentecost, r#"pfingst(?:en|sonntag|feiertag(?:en)?)"#),
+    (Holiday::WhitMonday, r#"pfingstmontag"#),
+    (Holiday::CorpusChristi, r#"fronleichnam(?:stag|sfest)?"#),
+    (Holiday::OrthodoxEaster, r#"orthodoxe[sn]? oster(?:n|sonntag|fest)"#),
+    (Holiday::CleanMonday, r#"reiner montag"#),
+    (Holiday::GreatLent, r#"(?:die )?(?:gro(?:ß|ss)e|orthodoxe) fastenzeit"#),
+    (Holiday::OrthodoxAscension, r#"orthodoxe[sn]? (?:christi )?himmelfahrt"#),
+    (Holiday::OrthodoxPentecost, r#"orthodoxe[sn]? pfingst(?:en|sonntag|fest)"#),
+    (Holiday::Passover, r#"pessach|passah|pas[cs]ha"#),
+    (Holiday::RoshHashanah, r#"rosch ha-?schana|j[üu]disch(?:es|en) neujahr(?:sfest)?"#),
+    (Holiday::YomKippur, r#"jom kippur|vers[öo]hnungstag"#),
+    (Holiday::Hanukkah, r#"c?hanukk?a"#),
+    (Holiday::ValentinesDay, r#"valentin'?stag"#),
+    (Holiday::InternationalWorkersDay, r#"tag der arbeit"#),
+    (Holiday::Assumption, r#"mari[äa] himmelfahrt(?:stag)?"#),
+    (Holiday::ReformationDay, r#"reformations(?:tag|fest)?"#),
+    (Holiday::AllSaintsDay, r#"allerheiligen(?:tag)?"#),
+    (Holiday::SaintJosephsDay, r#"sankt josef"#),
+    (Holiday::SaintJosephsDay, r#"josefstag"#),
+    (Holiday::GermanUnityDay, r#"tag (?:der )?deutsc?hen? einheit"#),
+    (Holiday::Armistice, r#"waffenstillstandserkl[äa]rung"#),
+    (Holiday::SaintMartinsDay, r#"sankt martin|martinstag"#),
+    (Holiday::ImmaculateConception, r#"mari[äa] empf[äa]ngnis"#),
+    (Holiday::SaintStephensDay, r#"stefanitag"#),
+    (Holiday::InternationalWomensDay, r#"(?:internationale[rnm] )?frauentag"#),
+    (Holiday::MothersDay, r#"mutt?ertag|mutt?er (?:tag)?"#),
+    (Holiday::Halloween, r#"hall?owe?en?"#),
+    (Holiday::AllSaintsDay, r#"allerheiligen?|aller heiligen?"#),
+    (Holiday::SaintNicholasDay, r#"nikolaus(?: ?tag|abend)?|nikolo"#),
+];
+
+pub fn rules_celebration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rules_holidays(b, HOLIDAYS)?;
+    b.rule_1_terminal("christmas days (24/12-26/12)",
+                      b.reg(r#"weihnachtsfest"#)?,
+                      |_| {
+                        Ok(helpers::month_day(12, 24)?
+                          .span_to(&helpers::month_day(12, 26)?, true)?
+                          .form(Form::Celebration))
+                      }
+    );
+    b.rule_1_terminal("Epiphanias",
+                      b.reg(r#"heiligen? drei k[öo]nigen?"#)?,
+                      |_| Ok(helpers::month_day(1, 6)?.form(Form::Celebration).too_ambiguous())
+    );
+
+    b.rule_1_terminal("Candlemess",
+        b.reg(r#"lichtmess"#)?,
+        |_| Ok(helpers::month_day(2, 2)?.form(Form::Celebration).too_ambiguous())
+    );
+
+    b.rule_1_terminal("Lent",
+        b.reg(r#"(?:in|w[aä]hrend) der fastenzeit"#)?,
+        |_| Ok(helpers::cycle_nth_after(Grain::Day, -47, &helpers::easter()?)?
+                          .span_to(&helpers::easter()?, false)?
+                          .form(Form::Celebration))
+    );
+
+    b.rule_1_terminal("fasnet",
+        b.reg(r#"fast?nacht|(?:in|w[aä]hrend) der fasnet"#)?,
+        |_| Ok(helpers::month_day(11, 11)?
+                          .span_to(&helpers::cycle_nth_after(Grain::Day, -47, &helpers::easter()?)?, false)?
+                          .form(Form::Celebration))
+    );
+
+    b.rule_1_terminal("Schweizer Bundesfeiertag",
+                      b.reg(r#"schweiz(?:er)? (?:bundes)?feiertag|bundes feiertag"#)?,
+                      |_| Ok(helpers::month_day(8, 1)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Augsburg Celebration",
+                      b.reg(r#"augsburger hohe[smn] friedensfest"#)?,
+                      |_| Ok(helpers::month_day(8, 8)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Holy Florian",
+                      b.reg(r#"sankt florian"#)?,
+                      |_| Ok(helpers::month_day(5, 4)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Holy Rupert",
+                      b.reg(r#"sankt rupert"#)?,
+                      |_| Ok(helpers::month_day(9, 24)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Day of popular vote",
+                      b.reg(r#"tag der volksabtimmun"#)?,
+                      |_| Ok(helpers::month_day(10, 10)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Austrian national celebration",
+                      b.reg(r#"(?:[öo]sterreichischer? )?nationalfeiertag|national feiertag"#)?,
+                      |_| Ok(helpers::month_day(10, 26)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Holy Leopold",
+                      b.reg(r#"sankt leopold"#)?,
+                      |_| Ok(helpers::month_day(11, 15)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Switzerland national celebration",
+                      b.reg(r#"an der bundesfeier"#)?,
+                      |_| Ok(helpers::month_day(8, 1)?.form(Form::Celebration))
+    );
+    b.rule_1_terminal("Berchtoldstag",
+        b.reg(r#"berchtoldstag"#)?,
+        |_| Ok(helpers::month_day(1, 2)?.form(Form::Celebration))
+    );
+
+    b.rule_1("Father's Day",  // third Sunday of June
+        b.reg(r#"vatt?er(?: ?tag)?|(?:herren|m[äa]nner)tag"#)?,
+        |_| Ok(helpers::cycle_nth_after(Grain::Day, 39, &helpers::easter()?)?
+                .form(Form::Celebration))
+    );
+    b.rule_1_terminal("Sunday of the dead (German protestant)",
+        b.reg(r#"totensonntag"#)?,
+        |_| Ok(helpers::day_of_week(Weekday::Sun)?
+                          .intersect(&helpers::cycle_nth_after(Grain::Week, 3, &helpers::month_day(11, 1)?)?)?
+                          .form(Form::Celebration))
+    );
+
+    b.rule_2("<ordinal> advent sunday",
+        ordinal_check_by_range!(1, 4),
+        b.reg(r#"advents?"#)?,
+        |ordinal, _| {
+            let christmas = helpers::month_day(12, 25)?;
+            let offset = - (4 - ordinal.value().value + 1);
+            Ok(helpers::cycle_nth_after(Grain::Week, offset, &christmas)?
+                .intersect(&helpers::day_of_week(Weekday::Sun)?)?
+                .form(Form::Celebration))
+        }    
+    );
+    //Volkstrauertag
+
+    b.rule_1_terminal("memorial day",
+        b.reg(r#"volkstrauertag"#)?,
+        |_| {
+            let christmas = helpers::month_day(12, 25)?;
+            let offset = -6;
+            Ok(helpers::cycle_nth_after(Grain::Week, offset, &christmas)?
+                .intersect(&helpers::day_of_week(Weekday::Sun)?)?
+                .form(Form::Celebration))
+        }    
+    );
+    Ok(())
+}
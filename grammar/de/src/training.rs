@@ -19,6 +19,13 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(78.0, Some("fahrenheit")), "78 fahrenheit", "78 Grad Fahrenheit", "achtundsiebzig Grad FAHRENHEIT", "78 f", "78°F");
     example!(v, check_temperature(-78.0, Some("fahrenheit")), "minus 78 fahrenheit", "-78 Grad Fahrenheit", "minus acht und siebzig Grad FAHRENHEIT", "- 78 f", "-78°F");
     example!(v, check_temperature(130.0, Some("kelvin")), "130 kelvin", "130 Grad Kelvin", "hundert dreißig Grad KELVIN", "130 k", "130°K");
+    example!(v, check_temperature(-7.0, Some("degree")), "-7 Grad unter null", "−7°");
+    example!(v, check_temperature_interval(20.0, 25.0, Some("celsius")), "zwischen 20 und 25 Grad Celsius", "20 bis 25 Grad Celsius", "20-25°C");
+}
+
+pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "zwischen zwei und drei stunden", "2 bis 3 stunden", "2-3 stunden");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 0, 30], [0, 0, 0, 0, 1]), "zwischen 30 minuten und 1 stunde", "von 30 minuten bis 1 stunde");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -73,6 +80,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(1047.0, Some("EUR"), Precision::Approximate), "ungefähr eintausend siebenundvierzig Euro");
     example!(v, check_finance(1001.0, Some("CHF"), Precision::Exact), "tausendeines Schweizer Frankens");
     example!(v, check_finance(500736.0, Some("EUR"), Precision::Exact), "fünfhunderttausendsiebenhundertsechsunddreißig euro");
+    example!(v, check_finance(2500000.0, Some("EUR"), Precision::Exact), "2,5 Mio €");
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -167,7 +175,7 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 7, 0, 0]), "in 2.5 stunden", "in zwei ein halb stunden");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 30]), "in einer stunde");
     example!(v, check_moment!(c, [2013, 2, 12, 6, 30]), "in zwei stunden");
-    example!(v, check_moment!(c, [2013, 2, 12, 6, 30]), "in ein paar stunden");
+    example!(v, check_moment_with_precision!(c, [2013, 2, 12, 6, 30], Precision::Approximate), "in ein paar stunden");
     example!(v, check_moment!(c, [2013, 2, 13, 4, 30]), "in 24 stunden");
     example!(v, check_moment!(c, [2013, 2, 13]), "morgen");
     example!(v, check_moment!(c, [2016, 2]), "in 3 Jahren");
@@ -190,6 +198,20 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2014, 6, 21], [2014, 9, 24]), "sommer 2014", "in sommer 2014");
     example!(v, check_moment_span!(c, [2014, 12, 21], [2015, 3, 21]), "winter 2014", "in winter 2014");
     example!(v, check_moment!(c, [2013, 12, 25]), "Weihnachten", "Weihnachtstag");
+    example!(v, check_moment!(c, [2013, 12, 24]), "Heiligabend", "Heiliger Abend");
+    example!(v, check_moment!(c, [2013, 3, 29]), "Karfreitag");
+    example!(v, check_moment!(c, [2013, 3, 31]), "Ostern", "Ostersonntag");
+    example!(v, check_moment!(c, [2013, 4, 1]), "Ostermontag");
+    example!(v, check_moment!(c, [2013, 5, 19]), "Pfingsten", "Pfingstsonntag");
+    example!(v, check_moment!(c, [2013, 5, 20]), "Pfingstmontag");
+    example!(v, check_moment!(c, [2013, 5, 30]), "Fronleichnam");
+    example!(v, check_moment!(c, [2013, 5, 5]), "orthodoxes Osterfest", "orthodoxe Ostern");
+    example!(v, check_moment_span!(c, [2013, 3, 18], [2013, 5, 5]), "die große Fastenzeit");
+    example!(v, check_moment!(c, [2013, 6, 13]), "orthodoxe Himmelfahrt");
+    example!(v, check_moment_span!(c, [2013, 3, 26], [2013, 4, 3]), "Pessach", "Passah");
+    example!(v, check_moment_span!(c, [2013, 9, 5], [2013, 9, 7]), "Rosch ha-Schana", "jüdisches Neujahr");
+    example!(v, check_moment!(c, [2013, 9, 14]), "Jom Kippur", "Versöhnungstag");
+    example!(v, check_moment_span!(c, [2013, 11, 28], [2013, 12, 6]), "Chanukka");
     example!(v, check_moment!(c, [2013, 12, 31]), "Silvester");
     example!(v, check_moment!(c, [2014, 1, 1]), "Neujahrstag", "Neujahr");
     example!(v, check_moment!(c, [2013, 2, 14]), "Valentinstag");
@@ -293,6 +315,26 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_with_direction!(c, [2013, 2, 14], Direction::Before), "vor Donnerstag");
     example!(v, check_moment_with_direction!(c, [2013, 2, 15], Direction::Before), "bis Donnerstag");
 
+    example!(v, check_moment_span!(c, [1990], [2000]), "die 90er", "die neunziger", "90er Jahre", "in den 90ern");
+    example!(v, check_moment_span!(c, [1900], [2000]), "im 20. Jahrhundert", "20. Jahrhundert");
+    example!(v, check_moment_span!(c, [1990], [1994]), "Anfang der 90er");
+    example!(v, check_moment_span!(c, [1870], [1900]), "Ende des 19. Jahrhunderts");
+    example!(v, check_moment!(c, [2013, 4, 22], Grain::Week), "KW 17", "KW17", "Kalenderwoche 17", "in der KW 17");
+    example!(v, check_moment!(c, [2013, 1, 14], Grain::Week), "die 3. Woche des Jahres");
+    example!(v, check_moment!(c, [2024, 1, 29], Grain::Week), "2024-W05", "KW 5 2024");
+    example!(v, check_moment!(c, [2012, 12, 31], Grain::Week), "die erste woche des jahres");
+    example!(v, check_moment!(c, [2013, 1, 1], Grain::Semester), "dieses halbjahr", "erstes halbjahr");
+    example!(v, check_moment!(c, [2024, 7, 1], Grain::Semester), "zweites halbjahr 2024", "h2 2024");
+    example!(v, check_moment!(c, [2013, 2, 27], Grain::Day), "der vorletzte tag des monats");
+    example!(v, check_moment!(c, [2013, 2, 26], Grain::Day), "drittletzter tag des monats");
+    example!(v, check_moment!(c, [2013, 9, 13], Grain::Day), "tag 256 des jahres", "der 256. tag des jahres");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 5, 0], Precision::Approximate), "in kürze");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 7, 30], Precision::Approximate), "bald", "demnächst");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 5, 0], [2013, 2, 12, 7, 30], Precision::Approximate), "in einer weile");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 5, 30], [2013, 2, 13, 0, 0], Precision::Approximate), "später heute");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 5], [2013, 2, 12, 4, 30], Precision::Approximate, Grain::Day), "kürzlich", "vor kurzem");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 13], [2013, 2, 18], Precision::Approximate), "in den nächsten tagen");
+    example!(v, check_moment_span_with_precision!(c, [2013, 1, 7], [2013, 2, 4], Precision::Approximate), "vor ein paar wochen");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -327,7 +369,23 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(0.5), "0,5", "0,50", "null komma fünf", "null komma fünfzig");
     //example!(v, check_float(32.75), "32,75", "zweiunddreißig komma fünfundsiebzig");
     example!(v, check_float(10.08), "10,08", "zehn komma null acht");
+    example!(v, check_fraction(3, 4), "drei viertel");
+    example!(v, check_fraction(2, 3), "zwei drittel");
+    example!(v, check_fraction(7, 4), "1 3/4", "eins und drei viertel");
     example!(v, check_float(1000000.0), "1.000.000,00");
     example!(v, check_ordinal(44), "der vierundvierzigste");
     example!(v, check_integer(455628), "vierhundertfünfundfünfzigtausendsechshundertachtundzwanzig");
+    example!(v, check_integer(2000000), "2 Mio", "2Mio");
+    example!(v, check_integer(3000000000), "3 Mrd");
+    example!(v, check_integer(1000000), "1e6", "10^6");
+    example!(v, check_float(1000000.5), "1.000.000,50");
+    example!(v, check_integer(1500), "1.500");
+    example!(v, check_integer(1000), "1'000", "1’000");
+    example!(v, check_float(1000.5), "1'000,50");
+    example!(v, check_integer(-5), "minus fünf", "negativ fünf", "−5");
+    example!(v, check_integer(14), "XIV");
+    example!(v, check_fuzzy_integer(2, 2, 3), "ein paar");
+    example!(v, check_fuzzy_integer(4, 3, 7), "mehrere");
+    example!(v, check_fuzzy_integer(3, 2, 5), "einige");
+    example!(v, check_fuzzy_integer(36, 24, 99), "dutzende");
 }
\ No newline at end of file
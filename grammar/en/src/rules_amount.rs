@@ -122,6 +122,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
                      unit: a.value().unit,
+                     precision: b.value().precision(),
+                     fuzzy_range: b.value().fuzzy_range(),
                      ..AmountOfMoneyValue::default()
                  })
              });
@@ -132,6 +134,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
                      unit: b.value().unit,
+                     precision: a.value().precision(),
+                     fuzzy_range: a.value().fuzzy_range(),
                      ..AmountOfMoneyValue::default()
                  })
              });
@@ -206,6 +210,23 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      latent: false,
                  })
              });
+    b.rule_2("<temp> below zero",
+             temperature_check!(),
+             b.reg(r#"below (?:zero|0)"#)?,
+             |a, _| helpers::temperature_below_zero(a.value()));
+    b.rule_3("<temp> - <temp> (interval)",
+             temperature_check!(),
+             b.reg(r#"-|to"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("between <temp> and <temp> (interval)",
+             b.reg(r#"between|from"#)?,
+             temperature_check!(),
+             b.reg(r#"and|to|-"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
     Ok(())
 }
 
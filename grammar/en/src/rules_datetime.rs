@@ -5,8 +5,17 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
 use rustling_ontology_moment::{Weekday, Grain};
+use rustling_ontology_values::rules_semester::SemesterWords;
 
 
+const SEMESTER_WORDS: SemesterWords = SemesterWords {
+    article: r#"the"#,
+    of_year: r#"of|in"#,
+    half_of_year: r#"half of (?:the|this) year"#,
+    half_article: r#"the"#,
+    abbreviation: r#"h"#,
+};
+
 pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     /* DATETIME - COMPLEX RULES */
@@ -374,6 +383,116 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  }
              }
     );
+    b.rule_1_terminal("decade (the 1990s)",
+                      b.reg(r#"(?:the )?(\d{3}0)['’]?s"#)?,
+                      |text_match| helpers::decade(text_match.group(1).parse()?)
+    );
+    // A bare "30s" is rather an age or a duration, the two-digit decade needs "the" or an apostrophe
+    b.rule_1_terminal("decade (the '90s)",
+                      b.reg(r#"(?:the ['’]?|['’])(\d0)['’]?s"#)?,
+                      |text_match| helpers::decade(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("decade (the nineties)",
+                      b.reg(r#"(?:the )?(twenties|thirties|forties|fifties|sixties|seventies|eighties|nineties)"#)?,
+                      |text_match| {
+                          let start = match text_match.group(1).as_ref() {
+                              "twenties" => 20,
+                              "thirties" => 30,
+                              "forties" => 40,
+                              "fifties" => 50,
+                              "sixties" => 60,
+                              "seventies" => 70,
+                              "eighties" => 80,
+                              "nineties" => 90,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::decade(start)
+                      }
+    );
+    b.rule_3("the <ordinal> century",
+             b.reg(r#"the"#)?,
+             ordinal_check!(),
+             b.reg(r#"century"#)?,
+             |_, ordinal, _| helpers::century(ordinal.value().value as i32)
+    );
+    b.rule_2("<ordinal> century",
+             ordinal_check!(),
+             b.reg(r#"century"#)?,
+             |ordinal, _| helpers::century(ordinal.value().value as i32)
+    );
+    b.rule_2("early|mid|late <decade or century>",
+             b.reg(r#"(?:the )?(early|mid|late)(?:-| in the)?"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |text_match, datetime| {
+                 let position = match text_match.group(1).as_ref() {
+                     "early" => Position::Start,
+                     "mid" => Position::Middle,
+                     "late" => Position::End,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 datetime.value().part_of_years(position)
+             }
+    );
+    b.rule_1_terminal("early|mid|late <two-digit decade> (mid-90s)",
+                      b.reg(r#"(?:the )?(early|mid|late)[- ]['’]?(\d0)['’]?s"#)?,
+                      |text_match| {
+                          let position = match text_match.group(1).as_ref() {
+                              "early" => Position::Start,
+                              "mid" => Position::Middle,
+                              "late" => Position::End,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::decade(text_match.group(2).parse()?)?.part_of_years(position)
+                      }
+    );
+    b.rule_2("in|during <decade or century>",
+             b.reg(r#"in|during"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |_, datetime| Ok(datetime.value().clone())
+    );
+    b.rule_2("week <integer>",
+             b.reg(r#"(?:the )?(?:calendar )?(?:week|wk)(?: number| no\.?)?"#)?,
+             integer_check_by_range!(1, 53),
+             |_, integer| helpers::iso_week(integer.value().value as u32)
+    );
+    b.rule_1_terminal("iso week (2024-W05)",
+                      b.reg(r#"(\d{4})-?w(\d{2})"#)?,
+                      |text_match| helpers::iso_year_week(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_2("<ordinal> week of the year",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"weeks? of (?:the|this) year"#)?,
+             |ordinal, _| helpers::iso_week_of_this_year(ordinal.value().value as u32)
+    );
+    b.rule_3("the <ordinal> week of the year",
+             b.reg(r#"the"#)?,
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"weeks? of (?:the|this) year"#)?,
+             |_, ordinal, _| helpers::iso_week_of_this_year(ordinal.value().value as u32)
+    );
+    b.rule_2("<week> <year>",
+             datetime_check!(form!(Form::IsoWeek(_))),
+             datetime_check!(form!(Form::Year(_))),
+             |week, year| helpers::iso_year_week(year.value().form_year()?, week.value().form_iso_week()?)
+    );
+    b.rule_3("<week> of <year>",
+             datetime_check!(form!(Form::IsoWeek(_))),
+             b.reg(r#"of|in"#)?,
+             datetime_check!(form!(Form::Year(_))),
+             |week, _, year| helpers::iso_year_week(year.value().form_year()?, week.value().form_iso_week()?)
+    );
+    rustling_ontology_values::rules_semester::rules_semester(b, &SEMESTER_WORDS)?;
+    b.rule_1_terminal("this|next|last half",
+                      b.reg_neg_lh(r#"(this|current|next|coming|last|previous|past) half"#, r#"^[- ](?:an?|hour|year)\b"#)?,
+                      |text_match| {
+                          let n = match text_match.group(1).as_ref() {
+                              "this" | "current" => 0,
+                              "next" | "coming" => 1,
+                              _ => -1,
+                          };
+                          helpers::cycle_nth(Grain::Semester, n)
+                      }
+    );
     /* END OF DATETIME - DATE - YEAR */
 
     /* DATETIME - DATE - DATES */
@@ -761,6 +880,35 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(?:just |right )?now|immediately|at (?:this time|(?:this very |the )moment|the present time)|for the moment"#)?,
                       |_| helpers::cycle_nth(Grain::Second, 0)
     );
+    // Vague expressions
+    b.rule_1_terminal("shortly",
+                      b.reg(r#"shortly|momentarily|in a moment"#)?,
+                      |_| helpers::vague(Vagueness::Shortly)
+    );
+    b.rule_1_terminal("soon",
+                      b.reg(r#"(?:very |pretty )?soon"#)?,
+                      |_| helpers::vague(Vagueness::Soon)
+    );
+    b.rule_1_terminal("in a while",
+                      b.reg(r#"(?:in|after) a (?:little )?while"#)?,
+                      |_| helpers::vague(Vagueness::InAWhile)
+    );
+    b.rule_1_terminal("later today",
+                      b.reg(r#"later (?:on )?today|later on"#)?,
+                      |_| helpers::vague(Vagueness::LaterToday)
+    );
+    b.rule_1_terminal("recently",
+                      b.reg(r#"recently|lately|in recent days"#)?,
+                      |_| helpers::vague(Vagueness::Recently)
+    );
+    b.rule_1_terminal("in the coming days",
+                      b.reg(r#"(?:in|over|during) the (?:coming|next (?:few|couple of)) days|in a (?:few|couple of) days"#)?,
+                      |_| helpers::vague(Vagueness::ComingDays)
+    );
+    b.rule_1_terminal("a few weeks ago",
+                      b.reg(r#"(?:a (?:few|couple of)|some|several) weeks ago"#)?,
+                      |_| helpers::vague(Vagueness::FewWeeksAgo)
+    );
 
     b.rule_1_terminal("morning",
                       b.reg(r#"morning"#)?,
@@ -1353,6 +1501,74 @@ pub fn rules_datetime_with_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingR
                  cycle.value().last_of(datetime.value())
              }
     );
+    b.rule_4("last <cycle> of the <cycle>",
+             b.reg(r#"(?:the )?last"#)?,
+             cycle_check!(),
+             b.reg(r#"of (?:the|this)"#)?,
+             cycle_check!(),
+             |_, cycle, _, outer| cycle.value().last_of(&helpers::cycle_nth(outer.value().grain, 0)?)
+    );
+    b.rule_5("<ordinal> last <cycle> of <datetime>",
+             ordinal_check_by_range!(1, 366),
+             b.reg(r#"(?:to )?last"#)?,
+             cycle_check!(),
+             b.reg(r#"of|in"#)?,
+             datetime_check!(),
+             |ordinal, _, cycle, _, datetime| cycle.value().nth_last_of(ordinal.value().value - 1, datetime.value())
+    );
+    b.rule_6("the <ordinal> last <cycle> of <datetime>",
+             b.reg(r#"the"#)?,
+             ordinal_check_by_range!(1, 366),
+             b.reg(r#"(?:to )?last"#)?,
+             cycle_check!(),
+             b.reg(r#"of|in"#)?,
+             datetime_check!(),
+             |_, ordinal, _, cycle, _, datetime| cycle.value().nth_last_of(ordinal.value().value - 1, datetime.value())
+    );
+    b.rule_5("<ordinal> last <cycle> of the <cycle>",
+             ordinal_check_by_range!(1, 366),
+             b.reg(r#"(?:to )?last"#)?,
+             cycle_check!(),
+             b.reg(r#"of (?:the|this)"#)?,
+             cycle_check!(),
+             |ordinal, _, cycle, _, outer| cycle.value().nth_last_of(ordinal.value().value - 1, &helpers::cycle_nth(outer.value().grain, 0)?)
+    );
+    b.rule_6("the <ordinal> last <cycle> of the <cycle>",
+             b.reg(r#"the"#)?,
+             ordinal_check_by_range!(1, 366),
+             b.reg(r#"(?:to )?last"#)?,
+             cycle_check!(),
+             b.reg(r#"of (?:the|this)"#)?,
+             cycle_check!(),
+             |_, ordinal, _, cycle, _, outer| cycle.value().nth_last_of(ordinal.value().value - 1, &helpers::cycle_nth(outer.value().grain, 0)?)
+    );
+    b.rule_4("<ordinal> day of the <cycle>",
+             ordinal_check_by_range!(1, 366),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Day),
+             b.reg(r#"of (?:the|this)"#)?,
+             cycle_check!(|cycle: &CycleValue| cycle.grain.is_greater_than_day()),
+             |ordinal, _, _, outer| helpers::nth_day_of(ordinal.value().value - 1, &helpers::cycle_nth(outer.value().grain, 0)?)
+    );
+    b.rule_5("the <ordinal> day of the <cycle>",
+             b.reg(r#"the"#)?,
+             ordinal_check_by_range!(1, 366),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Day),
+             b.reg(r#"of (?:the|this)"#)?,
+             cycle_check!(|cycle: &CycleValue| cycle.grain.is_greater_than_day()),
+             |_, ordinal, _, _, outer| helpers::nth_day_of(ordinal.value().value - 1, &helpers::cycle_nth(outer.value().grain, 0)?)
+    );
+    b.rule_2("day <integer> (day of year)",
+             b.reg(r#"day(?: number| no\.?)?"#)?,
+             integer_check_by_range!(1, 366),
+             |_, integer| helpers::nth_day_of(integer.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+    );
+    b.rule_4("day <integer> of <year>",
+             b.reg(r#"day(?: number| no\.?)?"#)?,
+             integer_check_by_range!(1, 366),
+             b.reg(r#"of|in"#)?,
+             datetime_check!(form!(Form::Year(_))),
+             |_, integer, _, year| helpers::nth_day_of(integer.value().value - 1, year.value())
+    );
     b.rule_4("nth <datetime> of <datetime>",
              ordinal_check!(), // the first
              datetime_check!(), // Thursday
@@ -1503,6 +1719,10 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(?:quarter|qtr)s?"#)?,
                       |_| CycleValue::new(Grain::Quarter)
     );
+    b.rule_1_terminal("semester (cycle)",
+                      b.reg(r#"semesters?|half[- ]years?"#)?,
+                      |_| CycleValue::new(Grain::Semester)
+    );
     b.rule_1_terminal("year (cycle)",
                       b.reg(r#"y(?:ea)?rs?"#)?,
                       |_| CycleValue::new(Grain::Year)
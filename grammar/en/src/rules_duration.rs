@@ -8,6 +8,10 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"sec(?:ond)?s?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Second))
     );
+    b.rule_1_terminal("millisecond (unit-of-duration)",
+                      b.reg(r#"msecs?|millisec(?:ond)?s?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Millisecond))
+    );
     b.rule_1_terminal("minute (unit-of-duration)",
                       b.reg(r#"min(?:ute)?s?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Minute))
@@ -55,13 +59,13 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::from_integer(uod.value().grain, integer.value()))
     );
     b.rule_3("<integer> more <unit-of-duration>",
              integer_check_by_range!(0),
              b.reg(r#"more"#)?,
              unit_of_duration_check!(),
-             |integer, _, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, _, uod| Ok(DurationValue::from_integer(uod.value().grain, integer.value()))
     );
     b.rule_2_terminal("number.number hours",
                       b.reg(r#"(\d+)\.(\d+)"#)?,
@@ -126,6 +130,14 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(DurationValue::new(hour_period + minute_period))
              }
     );
+    b.rule_2("half a <unit-of-duration>",
+             b.reg(r#"half an?"#)?,
+             unit_of_duration_check!(),
+             |_, uod| {
+                 let half_period = uod.value().grain.half_period().ok_or(RuleError::Invalid)?;
+                 Ok(DurationValue::new(half_period.into()))
+             }
+    );
     b.rule_2("a <unit-of-duration>",
              b.reg(r#"an?"#)?,
              unit_of_duration_check!(),
@@ -185,5 +197,35 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"exactly|precisely"#)?,
              |duration, _| Ok(duration.value().clone().precision(Precision::Exact))
     );
+
+    b.rule_3("<duration> - <duration> (interval)",
+             duration_check!(|duration: &DurationValue| !duration.suffixed),
+             b.reg(r#"-|to"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval(a.value(), b.value())
+    );
+
+    b.rule_4("between <duration> and <duration> (interval)",
+             b.reg(r#"between|from"#)?,
+             duration_check!(|duration: &DurationValue| !duration.suffixed),
+             b.reg(r#"and|to|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, a, _, b| helpers::compose_duration_interval(a.value(), b.value())
+    );
+
+    b.rule_3("<integer> - <duration> (interval)",
+             integer_check_by_range!(0),
+             b.reg(r#"-|to"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value())
+    );
+
+    b.rule_4("between <integer> and <duration> (interval)",
+             b.reg(r#"between|from"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"and|to|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value())
+    );
     Ok(())
 }
\ No newline at end of file
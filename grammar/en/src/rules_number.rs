@@ -2,8 +2,14 @@ use std::f64;
 
 use rustling::*;
 use rustling_ontology_values::dimension::*;
-use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::number_format::{Grouping, NumberFormat};
+
+const NUMBER_FORMAT: NumberFormat = NumberFormat {
+    decimal_separators: &['.'],
+    grouping_separators: &[',', '\u{a0}', '\u{2009}', '\u{202f}'],
+    groupings: &[Grouping::Thousands, Grouping::Indian],
+};
 
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_3("intersect (with and)",
@@ -58,28 +64,25 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("couple",
                       b.reg(r#"(?:a )?couple(?: of)?"#)?,
-                      |_| IntegerValue::new_with_grain(2, 1)
+                      |_| IntegerValue::new_fuzzy(2, 2, 3)
     );
     b.rule_1_terminal("some",
                       b.reg(r#"some"#)?,
-                      |_| IntegerValue::new_with_grain(3, 1)
+                      |_| IntegerValue::new_fuzzy(3, 2, 5)
     );
     b.rule_1_terminal("several",
                       b.reg(r#"several"#)?,
-                      |_| IntegerValue::new_with_grain(4, 1)
+                      |_| IntegerValue::new_fuzzy(4, 3, 7)
     );
     b.rule_1_terminal("bunch",
                       b.reg(r#"a bunch of"#)?,
-                      |_| IntegerValue::new_with_grain(10, 1)
+                      |_| IntegerValue::new_fuzzy(10, 5, 20)
+    );
+    b.rule_1("few", b.reg(r#"(?:a )?few"#)?, |_| IntegerValue::new_fuzzy(3, 2, 5));
+    b.rule_1_terminal("dozens",
+                      b.reg(r#"dozens(?: of)?"#)?,
+                      |_| IntegerValue::new_fuzzy(36, 24, 99)
     );
-    b.rule_1("few", b.reg(r#"(?:a )?few"#)?, |_| {
-        Ok(IntegerValue {
-            value: 3,
-            grain: Some(1),
-            precision: Approximate,
-            ..IntegerValue::default()
-        })
-    });
     b.rule_1_terminal("integer (20..90)",
                       b.reg(r#"(twenty|thirty|fou?rty|fifty|sixty|seventy|eighty|ninety)"#)?,
                       |text_match| {
@@ -106,16 +109,22 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"-"#)?,
              integer_check_by_range!(1, 9),
              |a, _, b| IntegerValue::new(a.value().value + b.value().value));
+    // Shorter numerals are words as often as numbers ("i", "cv", "mix"), they only count in context
+    b.rule_1_terminal("roman numeral",
+                      b.reg(r#"\b([ivxlc]{3,}|[mdclxvi]{4,})\b"#)?,
+                      |text_match| Ok(IntegerValue {
+                          value: helpers::roman_numeral(&text_match.group(1))?,
+                          latent: true,
+                          ..IntegerValue::default()
+                      })
+    );
     b.rule_1_terminal("integer (numeric)",
                       b.reg(r#"(\d{1,18})"#)?,
                       |text_match| IntegerValue::new(text_match.group(0).parse()?));
-    b.rule_1_terminal("integer with thousands separator ,",
-                      b.reg(r#"(\d{1,3}(,\d\d\d){1,5})"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(",", "");
-                          let value: i64 = reformatted_string.parse()?;
-                          IntegerValue::new(value)
-                      });
+    b.rule_1_terminal("number with digit grouping",
+                      b.reg(&NUMBER_FORMAT.regex())?,
+                      |text_match| NUMBER_FORMAT.parse(&text_match.group(1))
+    );
     b.rule_2("special composition for missing hundreds like in one twenty two",
              integer_check_by_range!(1, 9),
              integer_check_by_range!(10, 99),
@@ -149,9 +158,53 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      "billion" => (1_000_000_000, 9),
                      _ => return Err(RuleError::Invalid.into()),
                  };
+                 // "a few hundred" keeps the range of "a few"
+                 Ok(IntegerValue {
+                     precision: integer.value().precision,
+                     fuzzy_range: integer.value().fuzzy_range.map(|(min, max)| (min * value, max * value)),
+                     ..IntegerValue::new_with_grain(integer.value().value * value, grain)?
+                 })
+             }
+    );
+    b.rule_1_terminal("100_000, 10_000_000 (lakh, crore)",
+                      b.reg(r#"(lakh|lac|crore)s?"#)?,
+                      |text_match| {
+                          let (value, grain) = match text_match.group(1).as_ref() {
+                              "lakh" | "lac" => (100_000, 5),
+                              "crore" => (10_000_000, 7),
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          IntegerValue::new_with_grain(value, grain)
+                      }
+    );
+    b.rule_2("<integer> lakh, crore",
+             integer_check_by_range!(1),
+             b.reg(r#"(lakh|lac|crore)s?"#)?,
+             |integer, text_match| {
+                 let (value, grain) = match text_match.group(1).as_ref() {
+                     "lakh" | "lac" => (100_000, 5),
+                     "crore" => (10_000_000, 7),
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 // "1 lakh crore" is common, "1 crore lakh" is not
+                 if integer.value().value >= value {
+                     return Err(RuleError::Invalid.into());
+                 }
                  IntegerValue::new_with_grain(integer.value().value * value, grain)
              }
     );
+    b.rule_2("<decimal> lakh, crore",
+             float_check!(|float: &FloatValue| !float.suffixed),
+             b.reg(r#"(lakh|lac|crore)s?"#)?,
+             |float, text_match| {
+                 let multiplier = match text_match.group(1).as_ref() {
+                     "lakh" | "lac" => 1e5,
+                     "crore" => 1e7,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::compose_magnitude(&float.value().clone().into(), multiplier)
+             }
+    );
     b.rule_1_terminal("dozen",
                       b.reg(r#"dozen"#)?,
                       |_| Ok(IntegerValue {
@@ -185,12 +238,46 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> and a half",
              integer_check!(),
              b.reg(r#"and a half"#)?,
-             |integer, _| FloatValue::new(integer.value().value as f64 + 0.5)
+             |integer, _| helpers::compose_mixed_fraction(integer.value(), &helpers::fraction(1, 2)?)
     );
     b.rule_2("<integer> and a quarter",
              integer_check!(),
              b.reg(r#"and a quarter"#)?,
-             |integer, _| FloatValue::new(integer.value().value as f64 + 0.25)
+             |integer, _| helpers::compose_mixed_fraction(integer.value(), &helpers::fraction(1, 4)?)
+    );
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+) ?/ ?(\d+)"#)?,
+                      |text_match| helpers::numeric_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_2("<integer> <denominator> (fraction)",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(half|halves|thirds?|quarters?|fourths?|fifths?|sixths?|sevenths?|eighths?|ninths?|tenths?)"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "half" | "halves" => 2,
+                     "third" | "thirds" => 3,
+                     "quarter" | "quarters" | "fourth" | "fourths" => 4,
+                     "fifth" | "fifths" => 5,
+                     "sixth" | "sixths" => 6,
+                     "seventh" | "sevenths" => 7,
+                     "eighth" | "eighths" => 8,
+                     "ninth" | "ninths" => 9,
+                     "tenth" | "tenths" => 10,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::fraction(integer.value().value, denominator)
+             }
+    );
+    b.rule_2("<integer> <fraction> (mixed number)",
+             integer_check!(|integer: &IntegerValue| !integer.prefixed),
+             float_check!(|float: &FloatValue| float.fraction.is_some()),
+             |integer, fraction| helpers::compose_mixed_fraction(integer.value(), fraction.value())
+    );
+    b.rule_3("<integer> and <fraction> (mixed number)",
+             integer_check!(|integer: &IntegerValue| !integer.prefixed),
+             b.reg(r#"and"#)?,
+             float_check!(|float: &FloatValue| float.fraction.is_some()),
+             |integer, _, fraction| helpers::compose_mixed_fraction(integer.value(), fraction.value())
     );
     b.rule_3("number dot number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
@@ -217,40 +304,10 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  })
 
              });
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(,\d\d\d)+\.\d+)"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(",", "");
-                          let value: f64 = reformatted_string.parse()?;
-                          Ok(FloatValue {
-                              value: value,
-                              ..FloatValue::default()
-                          })
-                      });
     b.rule_2("numbers prefix with -, negative or minus",
-             b.reg(r#"-|minus\s?|negative\s?"#)?,
+             b.reg(r#"-|−|minus\s?|negative\s?"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
-             |_, a| -> RuleResult<NumberValue> {
-                 Ok(match a.value().clone() {
-                     // checked
-                     NumberValue::Integer(integer) => {
-                         IntegerValue {
-                             value: integer.value * -1,
-                             prefixed: true,
-                             ..integer
-                         }
-                             .into()
-                     }
-                     NumberValue::Float(float) => {
-                         FloatValue {
-                             value: float.value * -1.0,
-                             prefixed: true,
-                             ..float
-                         }
-                             .into()
-                     }
-                 })
-             });
+             |_, a| helpers::negate_number(a.value()));
     b.rule_2("numbers prefix with +, positive",
              b.reg(r#"\+"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -276,44 +333,24 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_2("numbers suffixes (K, M, G)",
              number_check!(|number: &NumberValue| !number.suffixed()),
-             b.reg_neg_lh(r#"([kmg])"#, r#"^[^\W\$€]"#)?,
+             b.reg_neg_lh(r#"(mn|bn|[kmg])"#, r#"^[^\W\$€]"#)?,
              |a, text_match| -> RuleResult<NumberValue> {
                  let multiplier = match text_match.group(0).as_ref() {
-                     "k" => 1000,
-                     "m" => 1000000,
-                     "g" => 1000000000,
+                     "k" => 1e3,
+                     "m" | "mn" => 1e6,
+                     "g" | "bn" => 1e9,
                      _ => return Err(RuleError::Invalid.into()),
                  };
-                 Ok(match a.value().clone() {
-                     // checked
-                     NumberValue::Integer(integer) => {
-                         IntegerValue {
-                             value: integer.value * multiplier,
-                             suffixed: true,
-                             ..integer
-                         }
-                             .into()
-                     }
-                     NumberValue::Float(float) => {
-                         let product = float.value * (multiplier as f64);
-                         if product.floor() == product {
-                             IntegerValue {
-                                 value: product as i64,
-                                 suffixed: true,
-                                 ..IntegerValue::default()
-                             }
-                                 .into()
-                         } else {
-                             FloatValue {
-                                 value: product,
-                                 suffixed: true,
-                                 ..float
-                             }
-                                 .into()
-                         }
-                     }
-                 })
+                 helpers::compose_magnitude(a.value(), multiplier)
              });
+    b.rule_1_terminal("scientific notation (1e6)",
+                      b.reg(r#"(\d+(?:\.\d+)?)e([+-]?\d{1,2})"#)?,
+                      |text_match| helpers::compose_power(text_match.group(1).parse()?, 10.0, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("power (10^3)",
+                      b.reg(r#"(\d+) ?\^ ?(\d{1,2})"#)?,
+                      |text_match| helpers::compose_power(1.0, text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
     b.rule_1_terminal("ordinals (first..19th)",
                       b.reg(r#"(zeroth|first|second|third|fourth|fifth|sixth|seventh|eighth|ninth|tenth|eleventh|twelfth|thirteenth|fourteenth|fifteenth|sixteenth|seventeenth|eighteenth|nineteenth)"#)?,
                       |text_match| {
@@ -17,6 +17,10 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(168.0, Some("fahrenheit")), "one hundred and sixty-eight fahrenheit", "168 F", "168f");
     example!(v, check_temperature(10.0, Some("kelvin")), "ten degrees kelvin", "10 °K", "10°k");
     example!(v, check_temperature(21.0, Some("kelvin")), "21 kelvin", "21 K", "21k");
+    example!(v, check_temperature_interval(20.0, 25.0, Some("celsius")), "between 20 and 25 degrees celsius", "20 to 25 degrees celsius", "20-25°C");
+    example!(v, check_temperature_interval(-5.0, 5.0, Some("fahrenheit")), "from minus five to five degrees fahrenheit", "-5°F to 5°F");
+    example!(v, check_temperature(-5.0, None), "five below zero", "5 below 0");
+    example!(v, check_temperature(-10.0, Some("degree")), "ten degrees below zero", "minus ten degrees", "−10°");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -42,12 +46,18 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(2.05, Some("EUR"), Precision::Exact), "2 euros and 5 cents", "two euros five centimes", "2.05€", "two point zero five euros");
     example!(v, check_finance(5.0, Some("cent"), Precision::Exact), "5 cents", "five centimes", "5¢");
     example!(v, check_finance(1.0, Some("cent"), Precision::Exact), "one cent", "1 centime", "1 ¢");
+    example!(v, check_finance(2500000.0, Some("EUR"), Precision::Exact), "€2.5m");
+    example!(v, check_finance(500000.0, Some("INR"), Precision::Exact), "5 lakh rupees", "rs 5 lakh", "rs 5,00,000");
+    example!(v, check_finance(25000000.0, Some("INR"), Precision::Exact), "2.5 crore rupees", "INR 2.5 crore");
+    example!(v, check_finance(36.0, Some("EUR"), Precision::Approximate), "dozens of euros");
 }
 
 
 // TODO: Sort out and split by datetime subtype
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let orthodox = c.with_computus(Computus::Orthodox);
+    let observed = c.with_holiday_date(HolidayDate::Observed);
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 0]), "now", "right now", "just now", "at this time");
     example!(v, check_moment!(c, [2013, 2, 12]), "today");
     example!(v, check_moment!(c, [2013, 2, 11]), "yesterday");
@@ -134,8 +144,8 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 5, 0, 0]), "in half an hour", "in 1/2h", "in 1/2 h", "in 1/2 hour");
     example!(v, check_moment!(c, [2013, 2, 12, 7, 0, 0]), "in 2.5 hours", "in 2 and a half hours");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 30]), "in one hour", "in 1h");
-    example!(v, check_moment!(c, [2013, 2, 12, 6, 30]), "in a couple hours", "in a couple of hours");
-    example!(v, check_moment!(c, [2013, 2, 12, 7, 30]), "in a few hours", "in few hours");
+    example!(v, check_moment_with_precision!(c, [2013, 2, 12, 6, 30], Precision::Approximate), "in a couple hours", "in a couple of hours");
+    example!(v, check_moment_with_precision!(c, [2013, 2, 12, 7, 30], Precision::Approximate), "in a few hours", "in few hours");
     example!(v, check_moment!(c, [2013, 2, 13, 4, 30]), "in 24 hours", "in 24hrs", "in 24 hrs");
     example!(v, check_moment!(c, [2013, 2, 13]), "in a day", "a day from now");
     example!(v, check_moment!(c, [2016, 2]), "3 years from today");
@@ -162,6 +172,33 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "this summer", "current summer");
     example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 21]), "this winter");
     example!(v, check_moment!(c, [2013, 12, 25]), "xmas", "christmas", "christmas day");
+    example!(v, check_moment!(c, [2016, 12, 25]), "christmas 2016");
+    example!(v, check_moment!(c, [2016, 12, 26]), "the christmas holiday 2016", "christmas day off 2016", "christmas observed 2016");
+    example!(v, check_moment!(observed, [2016, 12, 26]), "christmas 2016", "christmas day 2016");
+    example!(v, check_moment!(c, [2015, 7, 3]), "the 4th of july day off 2015", "independence day observed 2015");
+    example!(v, check_moment!(observed, [2015, 7, 3]), "independence day 2015");
+    example!(v, check_moment!(c, [2017, 1, 2]), "the new year's day holiday 2017");
+    example!(v, check_moment!(c, [2021, 12, 31]), "new year's day off 2022");
+    example!(v, check_moment!(observed, [2021, 12, 31]), "new year's day 2022");
+    example!(v, check_moment!(c, [2013, 6, 19]), "juneteenth");
+    example!(v, check_moment!(c, [2013, 11, 11]), "veterans day");
+    example!(v, check_moment!(observed, [2018, 11, 12]), "veterans day 2018");
+    example!(v, check_moment!(c, [2013, 3, 31]), "easter", "easter sunday");
+    example!(v, check_moment!(c, [2013, 5, 5]), "orthodox easter", "greek easter", "pascha");
+    example!(v, check_moment!(orthodox, [2013, 5, 5]), "easter", "easter sunday");
+    example!(v, check_moment!(c, [2013, 3, 18]), "clean monday");
+    example!(v, check_moment_span!(c, [2013, 3, 18], [2013, 5, 5]), "great lent", "the orthodox lent");
+    example!(v, check_moment!(c, [2013, 6, 13]), "orthodox ascension", "the greek ascension day");
+    example!(v, check_moment!(c, [2013, 6, 23]), "orthodox pentecost", "russian pentecost");
+    example!(v, check_moment!(orthodox, [2013, 6, 23]), "pentecost");
+    example!(v, check_moment_span!(c, [2013, 7, 9], [2013, 8, 8]), "ramadan", "during ramadan");
+    example!(v, check_moment!(c, [2013, 8, 8]), "eid al-fitr", "eid ul fitr", "eid");
+    example!(v, check_moment!(c, [2013, 10, 15]), "eid al-adha", "the feast of the sacrifice");
+    example!(v, check_moment_span!(c, [2013, 3, 26], [2013, 4, 3]), "passover", "pesach");
+    example!(v, check_moment_span!(c, [2013, 9, 5], [2013, 9, 7]), "rosh hashanah", "jewish new year");
+    example!(v, check_moment!(c, [2013, 9, 14]), "yom kippur", "the day of atonement");
+    example!(v, check_moment_span!(c, [2013, 11, 28], [2013, 12, 6]), "hanukkah", "chanukah");
+    example!(v, check_moment!(c, [2013, 11, 5]), "islamic new year", "hijri new year");
     example!(v, check_moment!(c, [2013, 12, 31]), "new year's eve", "new years eve");
     example!(v, check_moment!(c, [2014, 1, 1]), "new year's day", "new years day");
     example!(v, check_moment!(c, [2013, 2, 14]), "valentine's day", "valentine day");
@@ -230,6 +267,35 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 9, 9]), "at nine o nine", "at nine o nine am", "at nine o nine in the morning");
     example!(v, check_moment!(c, [2013, 2, 12, 8, 25]), "at eight twenty-five", "at eight twenty-five am", "at eight twenty-five in the morning");
     example!(v, check_moment_span!(c, [2017, 05, 12, 10, 32], [2017, 06, 7, 18, 23]), "friday the 12th of may, 10:32 am to wednesday the 7th of june, 6:22 pm");
+    example!(v, check_moment_span!(c, [1990], [2000]), "the 90s", "the nineties", "the 1990s", "the '90s", "in the 90s");
+    example!(v, check_moment_span!(c, [1930], [1940]), "the thirties", "the 30s");
+    example!(v, check_moment_span!(c, [1920], [1930]), "the 20s", "the twenties");
+    example!(v, check_moment_span!(c, [2000], [2004]), "early 2000s", "the early 2000s");
+    example!(v, check_moment_span!(c, [1994], [1997]), "mid-90s", "the mid 90s", "mid-'90s");
+    example!(v, check_moment_span!(c, [1800], [1900]), "the 19th century", "19th century");
+    example!(v, check_moment_span!(c, [1970], [2000]), "late 20th century");
+    example!(v, check_moment!(c, [2013, 10, 14], Grain::Week), "week 42", "the week 42", "week number 42", "wk 42");
+    example!(v, check_moment!(c, [2013, 1, 14], Grain::Week), "the 3rd week of the year", "third week of the year");
+    example!(v, check_moment!(c, [2012, 12, 31], Grain::Week), "the first week of the year");
+    example!(v, check_moment!(c, [2024, 1, 29], Grain::Week), "2024-W05", "week 5 of 2024", "week 5 2024");
+    example!(v, check_moment!(c, [2024, 12, 30], Grain::Week), "week 1 of 2025");
+    example!(v, check_moment!(c, [2019, 3, 4, 10, 0, 0]), "2019-03-04T10:00:00", "2019-03-04 10:00:00");
+    example!(v, check_moment!(c, [2013, 1, 1], Grain::Semester), "this semester", "this half", "first semester", "the first half of the year");
+    example!(v, check_moment!(c, [2013, 7, 1], Grain::Semester), "next semester", "next half-year", "next half", "second half of the year");
+    example!(v, check_moment!(c, [2024, 1, 1], Grain::Semester), "h1 2024", "first semester of 2024");
+    example!(v, check_moment!(c, [2012, 7, 1], Grain::Semester), "last half", "previous half");
+    example!(v, check_moment!(c, [2013, 4, 10], Grain::Day), "the 100th day of the year", "day 100");
+    example!(v, check_moment!(c, [2013, 9, 13], Grain::Day), "day 256", "day 256 of 2013");
+    example!(v, check_moment!(c, [2013, 12, 31], Grain::Day), "the last day of the year", "last day of this year");
+    example!(v, check_moment!(c, [2013, 2, 26], Grain::Day), "the 3rd last day of the month", "third to last day of the month");
+    example!(v, check_moment!(c, [2013, 2, 27], Grain::Day), "the second to last day of the month");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 5, 0], Precision::Approximate), "shortly", "in a moment");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 7, 30], Precision::Approximate), "soon", "very soon");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 5, 0], [2013, 2, 12, 7, 30], Precision::Approximate), "in a while");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 5, 30], [2013, 2, 13, 0, 0], Precision::Approximate), "later today");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 5], [2013, 2, 12, 4, 30], Precision::Approximate, Grain::Day), "recently", "lately");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 13], [2013, 2, 18], Precision::Approximate), "in the coming days", "over the next few days");
+    example!(v, check_moment_span_with_precision!(c, [2013, 1, 7], [2013, 2, 4], Precision::Approximate), "a few weeks ago");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -247,6 +313,15 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 1]), "during one hour", "for 1h");
     example!(v, check_duration!([0, 0, 2]), "for 2 weeks");
     example!(v, check_duration!([0, 0, 0, 2], Precision::Approximate), "around two days");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "2 to 3 hours", "between two and three hours", "2-3 hours");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 0, 30], [0, 0, 0, 0, 1]), "between 30 minutes and 1 hour", "from thirty minutes to an hour");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 250]), "250 ms", "250ms", "250 milliseconds");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 500]), "half a second", "0.5 seconds");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 1, 500]), "1.5 seconds", "one and a half seconds");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 1, 23, 450]), "1:23.45");
+    example!(v, check_duration!([0, 0, 0, 2], Precision::Approximate), "a couple of days");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 3], Precision::Approximate), "a few minutes");
+    example!(v, check_duration!([0, 0, 4], Precision::Approximate), "several weeks");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -307,4 +382,30 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(3), "the 3rd", "3rd", "third");
     example!(v, check_ordinal(2), "the 2nd", "2nd", "second");
     example!(v, check_ordinal(21), "the twenty first");
+    example!(v, check_fraction(3, 4), "three quarters", "3/4");
+    example!(v, check_fraction(2, 3), "two thirds", "2/3");
+    example!(v, check_fraction(1, 2), "one half", "1/2");
+    example!(v, check_fraction(3, 2), "1 1/2", "one and a half", "one and one half");
+    example!(v, check_fraction(11, 4), "two and three quarters", "2 3/4");
+    example!(v, check_integer(1500), "1.5k");
+    example!(v, check_integer(2000000), "2mn", "2M");
+    example!(v, check_integer(3000000000), "3bn");
+    example!(v, check_integer(1000000), "1e6", "10^6");
+    example!(v, check_integer(1000), "10^3");
+    example!(v, check_integer(1000000), "1\u{2009}000\u{2009}000", "1\u{a0}000\u{a0}000");
+    example!(v, check_integer(100000), "1,00,000");
+    example!(v, check_float(1234567.5), "1,234,567.50", "12,34,567.5");
+    example!(v, check_integer(1000000), "ten lakhs", "10 lakh", "10,00,000");
+    example!(v, check_integer(25000000), "2.5 crore", "two crore fifty lakh");
+    example!(v, check_integer(520000), "five lakh twenty thousand");
+    example!(v, check_integer(1000000000000), "1 lakh crore");
+    example!(v, check_integer(-5), "minus five", "negative five", "−5");
+    example!(v, check_float(-2.5), "minus 2.5", "minus two and a half");
+    example!(v, check_integer(14), "XIV");
+    example!(v, check_integer(57), "LVII");
+    example!(v, check_fuzzy_integer(2, 2, 3), "a couple of", "a couple");
+    example!(v, check_fuzzy_integer(3, 2, 5), "a few");
+    example!(v, check_fuzzy_integer(300, 200, 500), "a few hundred");
+    example!(v, check_fuzzy_integer(4, 3, 7), "several");
+    example!(v, check_fuzzy_integer(36, 24, 99), "dozens of");
 }
@@ -116,6 +116,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
                      unit: b.value().unit,
+                     precision: a.value().precision(),
+                     fuzzy_range: a.value().fuzzy_range(),
                      ..AmountOfMoneyValue::default()
                  })
              });
@@ -127,6 +129,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
                      unit: b.value().unit,
+                     precision: a.value().precision(),
+                     fuzzy_range: a.value().fuzzy_range(),
                      ..AmountOfMoneyValue::default()
                  })
              });
@@ -137,6 +141,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
                      unit: a.value().unit,
+                     precision: b.value().precision(),
+                     fuzzy_range: b.value().fuzzy_range(),
                      ..AmountOfMoneyValue::default()
                  })
              });
@@ -233,12 +239,19 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     b.rule_2("<latent temp> temp bajo cero",
              temperature_check!(),
              b.reg(r#"bajo cero"#)?,
-             |a, _| {
-                 Ok(TemperatureValue {
-                     value: -1.0 * a.value().value,
-                     latent: false,
-                     ..*a.value()
-                 })
-             });
+             |a, _| helpers::temperature_below_zero(a.value()));
+    b.rule_3("<temp> - <temp> (interval)",
+             temperature_check!(),
+             b.reg(r#"-|a"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("between <temp> and <temp> (interval)",
+             b.reg(r#"entre|de"#)?,
+             temperature_check!(),
+             b.reg(r#"y|a|-"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
     Ok(())
 }
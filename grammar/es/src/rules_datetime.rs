@@ -3,8 +3,17 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_moment::{Weekday, Grain};
+use rustling_ontology_values::rules_semester::SemesterWords;
 
 
+const SEMESTER_WORDS: SemesterWords = SemesterWords {
+    article: r#"el|en el|del"#,
+    of_year: r#"del?"#,
+    half_of_year: r#"mitad del? (?:este )?año"#,
+    half_article: r#"la|en la"#,
+    abbreviation: r#"s"#,
+};
+
 pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              datetime_check!(|datetime: &DatetimeValue| !datetime.latent),
@@ -116,6 +125,35 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(?:justo )?ahor(?:it)?a(?: mismo)?|ya|en\s?seguida|cuanto antes|en este preciso (?:istante|momento)|inmediatamente|a estas horas|actualmente"#)?,
                       |_| helpers::cycle_nth(Grain::Second, 0)
     );
+    // Vague expressions
+    b.rule_1_terminal("en breve",
+                      b.reg(r#"en breve|en un momento"#)?,
+                      |_| helpers::vague(Vagueness::Shortly)
+    );
+    b.rule_1_terminal("pronto",
+                      b.reg(r#"(?:muy )?pronto|dentro de poco"#)?,
+                      |_| helpers::vague(Vagueness::Soon)
+    );
+    b.rule_1_terminal("en un rato",
+                      b.reg(r#"(?:en|dentro de) un rato"#)?,
+                      |_| helpers::vague(Vagueness::InAWhile)
+    );
+    b.rule_1_terminal("hoy más tarde",
+                      b.reg(r#"hoy m[áa]s tarde|m[áa]s tarde hoy"#)?,
+                      |_| helpers::vague(Vagueness::LaterToday)
+    );
+    b.rule_1_terminal("recientemente",
+                      b.reg(r#"recientemente|[úu]ltimamente|hace poco"#)?,
+                      |_| helpers::vague(Vagueness::Recently)
+    );
+    b.rule_1_terminal("en los próximos días",
+                      b.reg(r#"en los (?:pr[óo]ximos d[íi]as|d[íi]as que vienen)|en unos d[íi]as"#)?,
+                      |_| helpers::vague(Vagueness::ComingDays)
+    );
+    b.rule_1_terminal("hace unas semanas",
+                      b.reg(r#"hace (?:unas|algunas|varias|un par de) semanas"#)?,
+                      |_| helpers::vague(Vagueness::FewWeeksAgo)
+    );
     b.rule_1_terminal("now / today",
                       b.reg(r#"hoy|(?:en este momento)|actualmente|en la actualidad|de momento"#)?,
                       |_| helpers::cycle_nth(Grain::Day, 0)
@@ -289,6 +327,90 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  }
              }
     );
+    b.rule_2("años <integer> (decade)",
+             b.reg(r#"(?:los )?años"#)?,
+             integer_check_by_range!(0, 2990, |integer: &IntegerValue| integer.value % 10 == 0 && (integer.value <= 90 || integer.value >= 1000)),
+             |_, integer| helpers::decade(integer.value().value as i32)
+    );
+    b.rule_2("década de los <integer> (decade)",
+             b.reg(r#"(?:la )?d[ée]cada de(?: los)?"#)?,
+             integer_check_by_range!(0, 2990, |integer: &IntegerValue| integer.value % 10 == 0 && (integer.value <= 90 || integer.value >= 1000)),
+             |_, integer| helpers::decade(integer.value().value as i32)
+    );
+    b.rule_2("los <integer> (decade)",
+             b.reg(r#"los"#)?,
+             integer_check_by_range!(10, 90, |integer: &IntegerValue| integer.value % 10 == 0),
+             |_, integer| Ok(helpers::decade(integer.value().value as i32)?.latent())
+    );
+    b.rule_2("siglo <integer> (century)",
+             b.reg(r#"(?:el )?siglo"#)?,
+             integer_check_by_range!(1, 30),
+             |_, integer| helpers::century(integer.value().value as i32)
+    );
+    b.rule_1_terminal("siglo <roman> (century)",
+                      b.reg(r#"(?:el )?siglo ([xvi]+)"#)?,
+                      |text_match| helpers::century(helpers::roman_numeral(text_match.group(1))? as i32)
+    );
+    b.rule_2("principios|mediados|finales de <decade or century>",
+             b.reg(r#"(?:a |en )?(principios|comienzos|mediados|finales|fines) del?"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |text_match, datetime| {
+                 let position = match text_match.group(1).as_ref() {
+                     "principios" | "comienzos" => Position::Start,
+                     "mediados" => Position::Middle,
+                     "finales" | "fines" => Position::End,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 datetime.value().part_of_years(position)
+             }
+    );
+    b.rule_2("en <decade or century>",
+             b.reg(r#"en|durante"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |_, datetime| Ok(datetime.value().clone().not_latent())
+    );
+    b.rule_2("semana <integer>",
+             b.reg(r#"(?:la )?semana(?: n[úu]mero)?"#)?,
+             integer_check_by_range!(1, 53),
+             |_, integer| helpers::iso_week(integer.value().value as u32)
+    );
+    b.rule_1_terminal("iso week (2024-W05)",
+                      b.reg(r#"(\d{4})-?w(\d{2})"#)?,
+                      |text_match| helpers::iso_year_week(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_2("<ordinal> semana del año",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"semana del (?:año|ano)"#)?,
+             |ordinal, _| {
+                 let week = ordinal.value().value as u32;
+                 Ok(helpers::iso_week(week)?
+                     .intersect(&helpers::cycle_nth(Grain::Year, 0)?)?
+                     .form(Form::IsoWeek(week)))
+             }
+    );
+    b.rule_3("la <ordinal> semana del año",
+             b.reg(r#"la"#)?,
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"semana del (?:año|ano)"#)?,
+             |_, ordinal, _| {
+                 let week = ordinal.value().value as u32;
+                 Ok(helpers::iso_week(week)?
+                     .intersect(&helpers::cycle_nth(Grain::Year, 0)?)?
+                     .form(Form::IsoWeek(week)))
+             }
+    );
+    b.rule_2("<week> <year>",
+             datetime_check!(form!(Form::IsoWeek(_))),
+             datetime_check!(form!(Form::Year(_))),
+             |week, year| helpers::iso_year_week(year.value().form_year()?, week.value().form_iso_week()?)
+    );
+    b.rule_3("<week> de <year>",
+             datetime_check!(form!(Form::IsoWeek(_))),
+             b.reg(r#"del?"#)?,
+             datetime_check!(form!(Form::Year(_))),
+             |week, _, year| helpers::iso_year_week(year.value().form_year()?, week.value().form_iso_week()?)
+    );
+    rustling_ontology_values::rules_semester::rules_semester(b, &SEMESTER_WORDS)?;
     b.rule_2("del <year>", //latin america mostly
              b.reg(r#"(?:d?el )?(?:a[ñn]o)?"#)?,
              integer_check_by_range!(1000, 2100),
@@ -375,6 +497,47 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              datetime_check!(),
              |_, cycle, _, datetime| helpers::cycle_nth_after_not_immediate(cycle.value().grain, -1, datetime.value())
     );
+    b.rule_4("ultimo <cycle> del <cycle>",
+             b.reg(r#"(?:el |la )?[úu]ltim[oa]"#)?,
+             cycle_check!(),
+             b.reg(r#"del|de (?:la|est[ea])"#)?,
+             cycle_check!(),
+             |_, cycle, _, outer| cycle.value().last_of(&helpers::cycle_nth(outer.value().grain, 0)?)
+    );
+    b.rule_4("penultimo <cycle> de <datetime>",
+             b.reg(r#"(?:el |la )?(antepen|pen)[úu]ltim[oa]"#)?,
+             cycle_check!(),
+             b.reg(r#"de|en"#)?,
+             datetime_check!(),
+             |text_match, cycle, _, datetime| {
+                 let n = if text_match.group(1) == "antepen" { 2 } else { 1 };
+                 cycle.value().nth_last_of(n, datetime.value())
+             }
+    );
+    b.rule_4("penultimo <cycle> del <cycle>",
+             b.reg(r#"(?:el |la )?(antepen|pen)[úu]ltim[oa]"#)?,
+             cycle_check!(),
+             b.reg(r#"del|de (?:la|est[ea])"#)?,
+             cycle_check!(),
+             |text_match, cycle, _, outer| {
+                 let n = if text_match.group(1) == "antepen" { 2 } else { 1 };
+                 cycle.value().nth_last_of(n, &helpers::cycle_nth(outer.value().grain, 0)?)
+             }
+    );
+    b.rule_5("el <ordinal> dia del <cycle>",
+             b.reg(r#"el"#)?,
+             ordinal_check_by_range!(1, 366),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Day),
+             b.reg(r#"del|de (?:la|est[ea])"#)?,
+             cycle_check!(|cycle: &CycleValue| cycle.grain.is_greater_than_day()),
+             |_, ordinal, _, _, outer| helpers::nth_day_of(ordinal.value().value - 1, &helpers::cycle_nth(outer.value().grain, 0)?)
+    );
+    b.rule_3("dia <integer> del año",
+             b.reg(r#"(?:el )?d[ií]a"#)?,
+             integer_check_by_range!(1, 366),
+             b.reg(r#"del año"#)?,
+             |_, integer, _| helpers::nth_day_of(integer.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+    );
     b.rule_4("nth <cycle> de <datetime>",
              ordinal_check!(),
              cycle_check!(),
@@ -1146,6 +1309,10 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"trimestres?"#)?,
                       |_| CycleValue::new(Grain::Quarter)
     );
+    b.rule_1_terminal("semester (cycle)",
+                      b.reg(r#"semestres?"#)?,
+                      |_| CycleValue::new(Grain::Semester)
+    );
     b.rule_1_terminal("year (cycle)",
                       b.reg(r#"a(?:n|ñ)os?"#)?,
                       |_| CycleValue::new(Grain::Year)
@@ -9,6 +9,10 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"seg(?:undo)?s?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Second))
     );
+    b.rule_1_terminal("millisecond (unit-of-duration)",
+                      b.reg(r#"milisegundos?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Millisecond))
+    );
     b.rule_1_terminal("minute (unit-of-duration)",
                       b.reg(r#"min(?:uto)?s?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Minute))
@@ -78,7 +82,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::from_integer(uod.value().grain, integer.value()))
     );
     b.rule_3("<integer> <unit-of-duration> and a half",
              integer_check_by_range!(0),
@@ -114,5 +118,35 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check_by_range!(0),
              |duration, integer| helpers::compose_duration_with_integer(duration.value(), integer.value())
     );
+
+    b.rule_3("<duration> - <duration> (interval)",
+             duration_check!(|duration: &DurationValue| !duration.suffixed),
+             b.reg(r#"-|a"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval(a.value(), b.value())
+    );
+
+    b.rule_4("between <duration> and <duration> (interval)",
+             b.reg(r#"entre|de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.suffixed),
+             b.reg(r#"y|a|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, a, _, b| helpers::compose_duration_interval(a.value(), b.value())
+    );
+
+    b.rule_3("<integer> - <duration> (interval)",
+             integer_check_by_range!(0),
+             b.reg(r#"-|a"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value())
+    );
+
+    b.rule_4("between <integer> and <duration> (interval)",
+             b.reg(r#"entre|de"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"y|a|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value())
+    );
     Ok(())
 }
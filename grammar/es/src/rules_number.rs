@@ -2,7 +2,13 @@ use std::f64;
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_values::dimension::Precision::*;
+use rustling_ontology_values::number_format::{Grouping, NumberFormat};
+
+const NUMBER_FORMAT: NumberFormat = NumberFormat {
+    decimal_separators: &[','],
+    grouping_separators: &['.', '\u{a0}', '\u{2009}', '\u{202f}'],
+    groupings: &[Grouping::Thousands],
+};
 
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
@@ -188,28 +194,38 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..IntegerValue::default()
                  })
              });
-    b.rule_1("few", b.reg(r#"un[oa]s"#)?, |_| {
-        Ok(IntegerValue {
-            value: 3,
-            grain: Some(1),
-            precision: Approximate,
-            ..IntegerValue::default()
-        })
-    });
+    b.rule_1("few", b.reg(r#"un[oa]s"#)?, |_| IntegerValue::new_fuzzy(3, 2, 5));
     b.rule_1_terminal("several",
-                      b.reg(r#"vario[ao]s"#)?,
-                      |_| IntegerValue::new_with_grain(4, 1)
+                      b.reg(r#"vari[ao]s"#)?,
+                      |_| IntegerValue::new_fuzzy(4, 3, 7)
+    );
+    b.rule_1_terminal("couple",
+                      b.reg(r#"un par de"#)?,
+                      |_| IntegerValue::new_fuzzy(2, 2, 3)
+    );
+    b.rule_1_terminal("some",
+                      b.reg(r#"algun[oa]s"#)?,
+                      |_| IntegerValue::new_fuzzy(3, 2, 5)
+    );
+    b.rule_1_terminal("dozens",
+                      b.reg(r#"(?:docenas|decenas) de"#)?,
+                      |_| IntegerValue::new_fuzzy(36, 24, 99)
+    );
+    b.rule_1_terminal("roman numeral",
+                      b.reg(r#"\b([ivxlc]{3,}|[mdclxvi]{4,})\b"#)?,
+                      |text_match| Ok(IntegerValue {
+                          value: helpers::roman_numeral(&text_match.group(1))?,
+                          latent: true,
+                          ..IntegerValue::default()
+                      })
     );
     b.rule_1_terminal("integer (numeric)",
                       b.reg(r#"(\d{1,18})"#)?,
                       |text_match| IntegerValue::new(text_match.group(0).parse()?));
-    b.rule_1_terminal("integer with thousands separator .",
-                      b.reg(r#"(\d{1,3}(\.\d\d\d){1,5})"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(".", "");
-                          let value: i64 = reformatted_string.parse()?;
-                          IntegerValue::new(value)
-                      });
+    b.rule_1_terminal("number with digit grouping",
+                      b.reg(&NUMBER_FORMAT.regex())?,
+                      |text_match| NUMBER_FORMAT.parse(&text_match.group(1))
+    );
     b.rule_1_terminal("decimal number",
                       b.reg(r#"(\d*,\d+)"#)?,
                       |text_match| {
@@ -241,37 +257,49 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..FloatValue::default()
                  })
              });
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(".", "").replace(",", ".");
-                          let value: f64 = reformatted_string.parse()?;
-                          FloatValue::new(value)
-                      });
+    b.rule_2("<integer> y medio",
+             integer_check!(|integer: &IntegerValue| !integer.prefixed),
+             b.reg(r#"y medi[oa]"#)?,
+             |integer, _| helpers::compose_mixed_fraction(integer.value(), &helpers::fraction(1, 2)?)
+    );
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+) ?/ ?(\d+)"#)?,
+                      |text_match| helpers::numeric_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_2("<integer> <denominator> (fraction)",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(medi|terci|cuart|quint|sext|s[eé]ptim|octav|noven|d[eé]cim)(?:[oa]s?)"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "medi" => 2,
+                     "terci" => 3,
+                     "cuart" => 4,
+                     "quint" => 5,
+                     "sext" => 6,
+                     "séptim" | "septim" => 7,
+                     "octav" => 8,
+                     "noven" => 9,
+                     "décim" | "decim" => 10,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::fraction(integer.value().value, denominator)
+             }
+    );
+    b.rule_2("<integer> <fraction> (mixed number)",
+             integer_check!(|integer: &IntegerValue| !integer.prefixed),
+             float_check!(|float: &FloatValue| float.fraction.is_some()),
+             |integer, fraction| helpers::compose_mixed_fraction(integer.value(), fraction.value())
+    );
+    b.rule_3("<integer> y <fraction> (mixed number)",
+             integer_check!(|integer: &IntegerValue| !integer.prefixed),
+             b.reg(r#"y"#)?,
+             float_check!(|float: &FloatValue| float.fraction.is_some()),
+             |integer, _, fraction| helpers::compose_mixed_fraction(integer.value(), fraction.value())
+    );
     b.rule_2("numbers prefix with -, negative or minus",
-             b.reg(r#"-|menos"#)?,
+             b.reg(r#"-|−|menos"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
-             |_, a| -> RuleResult<NumberValue> {
-                 Ok(match a.value().clone() {
-                     // checked
-                     NumberValue::Integer(integer) => {
-                         IntegerValue {
-                             value: integer.value * -1,
-                             prefixed: true,
-                             ..integer
-                         }
-                             .into()
-                     }
-                     NumberValue::Float(float) => {
-                         FloatValue {
-                             value: float.value * -1.0,
-                             prefixed: true,
-                             ..float
-                         }
-                             .into()
-                     }
-                 })
-             });
+             |_, a| helpers::negate_number(a.value()));
     b.rule_2("numbers prefix with +, positive",
              b.reg(r#"\+"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -297,44 +325,24 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_2("numbers suffixes (K, M, G)",
              number_check!(|number: &NumberValue| !number.suffixed()),
-             b.reg_neg_lh(r#"([kmg])"#, r#"^[\W\$€]"#)?,
+             b.reg_neg_lh(r#"(mm|[kmg])"#, r#"^[\W\$€]"#)?,
              |a, text_match| -> RuleResult<NumberValue> {
                  let multiplier = match text_match.group(0).as_ref() {
-                     "k" => 1000,
-                     "m" => 1000000,
-                     "g" => 1000000000,
+                     "k" => 1e3,
+                     "m" | "mm" => 1e6,
+                     "g" => 1e9,
                      _ => return Err(RuleError::Invalid.into()),
                  };
-                 Ok(match a.value().clone() {
-                     // checked
-                     NumberValue::Integer(integer) => {
-                         IntegerValue {
-                             value: integer.value * multiplier,
-                             suffixed: true,
-                             ..integer
-                         }
-                             .into()
-                     }
-                     NumberValue::Float(float) => {
-                         let product = float.value * (multiplier as f64);
-                         if product.floor() == product {
-                             IntegerValue {
-                                 value: product as i64,
-                                 suffixed: true,
-                                 ..IntegerValue::default()
-                             }
-                                 .into()
-                         } else {
-                             FloatValue {
-                                 value: product,
-                                 suffixed: true,
-                                 ..float
-                             }
-                                 .into()
-                         }
-                     }
-                 })
+                 helpers::compose_magnitude(a.value(), multiplier)
              });
+    b.rule_1_terminal("scientific notation (1e6)",
+                      b.reg(r#"(\d+(?:[.,]\d+)?)e([+-]?\d{1,2})"#)?,
+                      |text_match| helpers::compose_power(text_match.group(1).replace(",", ".").parse()?, 10.0, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("power (10^3)",
+                      b.reg(r#"(\d+) ?\^ ?(\d{1,2})"#)?,
+                      |text_match| helpers::compose_power(1.0, text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
     // TODO: Add approximate numbers/quantities
     b.rule_1_terminal("ordinals 1 and 3",
     b.reg(r#"(prim|terc)er"#)?,
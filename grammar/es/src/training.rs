@@ -136,10 +136,19 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     // Holidays
     // TODO
     example!(v, check_moment!(c, [2013, 12, 25]), "Navidad");
+    example!(v, check_moment_span!(c, [2013, 3, 26], [2013, 4, 3]), "Pésaj", "la pascua judía");
+    example!(v, check_moment_span!(c, [2013, 9, 5], [2013, 9, 7]), "Rosh Hashaná", "año nuevo judío");
+    example!(v, check_moment!(c, [2013, 9, 14]), "Yom Kipur", "día del perdón");
+    example!(v, check_moment_span!(c, [2013, 11, 28], [2013, 12, 6]), "Janucá");
     // holiday_example!(v, check_moment_span!(c, [2013, 12, 24, 18], [2013, 12, 25, 00]), "la sera di natale", "la notte di Natale");
     // "il primo gennaio & co. works already"
     example!(v, check_moment!(c, [2014, 1, 1]), "año nuevo", "el primero de enero");
     example!(v, check_moment!(c, [2013, 12, 31]), "en nochevieja");
+    example!(v, check_moment!(c, [2013, 3, 8]), "el día de la mujer");
+    example!(v, check_moment!(c, [2013, 3, 19]), "el día del padre");
+    example!(v, check_moment!(c, [2013, 10, 12]), "el día de la hispanidad");
+    example!(v, check_moment!(c, [2013, 11, 1]), "el día de todos los santos");
+    example!(v, check_moment!(c, [2013, 12, 6]), "el día de la constitución");
     // holiday_example!(v, check_moment!(c, [2013, 11, 1]), "tutti i santi", "il giorno di tutti i santi", "ognissanti", "il giorno di ognissanti", "il giorno d'ognissanti");
     // "il primo maggio & co. works already"
     // holiday_example!(v, check_moment!(c, [2013, 05, 1]), "festa del lavoro", "la festa dei lavoratori", "il primo maggio");
@@ -249,6 +258,26 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 5, 0, 0]), "dentro de media hora", "dentro de 1/2 hora");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 15, 0]), "dentro de tres cuartos de hora", "dentro de 3/4 de hora");
     example!(v, check_moment!(c, [2016, 12, 15]), "15.12.2016", "15.12.16", "15/12/2016");
+    example!(v, check_moment_span!(c, [1980], [1990]), "los años 80", "los años ochenta", "la década de los 80");
+    example!(v, check_moment_span!(c, [1900], [2000]), "el siglo XX", "siglo 20");
+    example!(v, check_moment_span!(c, [1990], [1994]), "principios de los 90", "a principios de los años 90");
+    example!(v, check_moment_span!(c, [1970], [2000]), "finales del siglo XX");
+    example!(v, check_moment!(c, [2013, 3, 18], Grain::Week), "semana 12", "la semana 12", "semana número 12");
+    example!(v, check_moment!(c, [2013, 1, 14], Grain::Week), "la tercera semana del año");
+    example!(v, check_moment!(c, [2024, 1, 29], Grain::Week), "2024-W05", "semana 5 de 2024");
+    example!(v, check_moment!(c, [2013, 1, 1], Grain::Semester), "el primer semestre", "este semestre");
+    example!(v, check_moment!(c, [2024, 7, 1], Grain::Semester), "segundo semestre de 2024", "s2 2024");
+    example!(v, check_moment!(c, [2013, 12, 31], Grain::Day), "el último día del año");
+    example!(v, check_moment!(c, [2013, 2, 27], Grain::Day), "el penúltimo día del mes");
+    example!(v, check_moment!(c, [2013, 2, 26], Grain::Day), "el antepenúltimo día del mes");
+    example!(v, check_moment!(c, [2013, 9, 13], Grain::Day), "día 256 del año");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 5, 0], Precision::Approximate), "en breve");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 7, 30], Precision::Approximate), "pronto", "dentro de poco");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 5, 0], [2013, 2, 12, 7, 30], Precision::Approximate), "en un rato");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 5, 30], [2013, 2, 13, 0, 0], Precision::Approximate), "hoy más tarde");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 5], [2013, 2, 12, 4, 30], Precision::Approximate, Grain::Day), "recientemente", "hace poco");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 13], [2013, 2, 18], Precision::Approximate), "en los próximos días");
+    example!(v, check_moment_span_with_precision!(c, [2013, 1, 7], [2013, 2, 4], Precision::Approximate), "hace unas semanas");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -263,6 +292,12 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "durante un cuarto de hora más o menos", "durante un cuarto de hora aproximadamente");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durante una hora", "por una hora");
     example!(v, check_duration!([0, 0, 2]), "durante 2 semanas", "por dos semanas");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "entre dos y tres horas", "2-3 horas");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 0, 30], [0, 0, 0, 0, 1]), "entre 30 minutos y una hora");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 250]), "250 ms", "250 milisegundos");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 500]), "0,5 s", "0,5 segundos");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 1, 23, 450]), "1:23.45");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 3], Precision::Approximate), "algunos minutos", "unos minutos");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -300,6 +335,18 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(3), "3o", "3o", "3a", "3º", "3ª", "tercero", "tercera");
     example!(v, check_ordinal(2), "segundo");
     example!(v, check_ordinal(5), "quintos");
+    example!(v, check_fraction(1, 3), "un tercio", "1/3");
+    example!(v, check_fraction(3, 4), "tres cuartos", "3/4");
+    example!(v, check_fraction(2, 3), "dos tercios", "2/3");
+    example!(v, check_fraction(3, 2), "1 1/2", "uno y medio", "uno y un medio");
+    example!(v, check_integer(2000000), "2MM");
+    example!(v, check_integer(1000000), "1e6", "10^6");
+    example!(v, check_float(1000000.5), "1.000.000,50");
+    example!(v, check_integer(1500), "1.500", "1\u{2009}500");
+    example!(v, check_integer(-2), "menos dos", "−2");
+    example!(v, check_integer(21), "XXI");
+    example!(v, check_fuzzy_integer(4, 3, 7), "varios", "varias");
+    example!(v, check_fuzzy_integer(2, 2, 3), "un par de");
 }
 
 pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -316,6 +363,9 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(-5.0, Some("fahrenheit")), "menos cinco grados fahrenheit", "cinco grados fahrenheit bajo cero", "-5 °F", "-5°F", "-5°f", "-5° f", "- 5°f");
     example!(v, check_temperature(168.0, Some("fahrenheit")), "ciento sesenta y ocho fahrenheit", "ciento sesenta y ocho grados fahrenheit", "168 grados fahrenheit", "168° F", "168 F", "168f");
     example!(v, check_temperature(10.0, Some("kelvin")), "diez kelvin", "10°K", "10° K", "10 °K", "10°k", "diez grados kelvin");
+    example!(v, check_temperature_interval(20.0, 25.0, Some("celsius")), "entre 20 y 25 grados celsius", "de 20 a 25 grados celsius", "20-25°C");
+    example!(v, check_temperature(-2.0, Some("degree")), "menos dos grados", "dos grados bajo cero", "-2 grados bajo cero");
+    example!(v, check_temperature(-2.0, None), "dos bajo cero");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -121,6 +121,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
                      unit: b.value().unit,
+                     precision: a.value().precision(),
+                     fuzzy_range: a.value().fuzzy_range(),
                      ..AmountOfMoneyValue::default()
                  })
              });
@@ -230,14 +232,21 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  })
              });
     b.rule_2("<temp> en dessous de zero",
-             temperature_check!(|temp: &TemperatureValue| !temp.latent),
-             b.reg(r#"en dessous de (?:0|z[ée]ro)"#)?,
-             |a, _| {
-                 Ok(TemperatureValue {
-                     value: -1.0 * a.value().value,
-                     latent: false,
-                     ..*a.value()
-                 })
-             });
+             temperature_check!(),
+             b.reg(r#"(?:en dessous de|sous) (?:0|z[ée]ro)"#)?,
+             |a, _| helpers::temperature_below_zero(a.value()));
+    b.rule_3("<temp> - <temp> (interval)",
+             temperature_check!(),
+             b.reg(r#"-|à"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("between <temp> and <temp> (interval)",
+             b.reg(r#"entre|de"#)?,
+             temperature_check!(),
+             b.reg(r#"et|à|-"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
     Ok(())
 }
@@ -2,6 +2,15 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
 use rustling_ontology_moment::{Weekday, Grain};
+use rustling_ontology_values::rules_semester::SemesterWords;
+
+const SEMESTER_WORDS: SemesterWords = SemesterWords {
+    article: r#"le|au|du"#,
+    of_year: r#"de|en|du"#,
+    half_of_year: r#"moiti[ée] de l['’ ]?ann[ée]e"#,
+    half_article: r#"la|à la|a la"#,
+    abbreviation: r#"s"#,
+};
 
 pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
@@ -111,6 +120,35 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"maintenant|tout de suite|en ce moment"#)?,
                       |_| helpers::cycle_nth(Grain::Second, 0)
     );
+    // Vague expressions
+    b.rule_1_terminal("sous peu",
+                      b.reg(r#"sous peu|dans un (?:instant|moment)"#)?,
+                      |_| helpers::vague(Vagueness::Shortly)
+    );
+    b.rule_1_terminal("bientôt",
+                      b.reg(r#"(?:tr[èe]s )?bient[ôo]t|prochainement|d'ici peu"#)?,
+                      |_| helpers::vague(Vagueness::Soon)
+    );
+    b.rule_1_terminal("dans quelque temps",
+                      b.reg(r#"dans (?:quelque|un petit) temps"#)?,
+                      |_| helpers::vague(Vagueness::InAWhile)
+    );
+    b.rule_1_terminal("plus tard dans la journée",
+                      b.reg(r#"plus tard (?:dans la journ[ée]e|aujourd'hui)"#)?,
+                      |_| helpers::vague(Vagueness::LaterToday)
+    );
+    b.rule_1_terminal("récemment",
+                      b.reg(r#"r[ée]cemment|derni[èe]rement|ces derniers jours"#)?,
+                      |_| helpers::vague(Vagueness::Recently)
+    );
+    b.rule_1_terminal("dans les prochains jours",
+                      b.reg(r#"dans les (?:prochains jours|jours (?:qui viennent|[àa] venir))|dans quelques jours"#)?,
+                      |_| helpers::vague(Vagueness::ComingDays)
+    );
+    b.rule_1_terminal("il y a quelques semaines",
+                      b.reg(r#"il y a (?:quelques|plusieurs) semaines"#)?,
+                      |_| helpers::vague(Vagueness::FewWeeksAgo)
+    );
     b.rule_1_terminal("aujourd'hui",
                       b.reg(r#"(?:aujourd'? ?hui)|(?:ce jour)|(?:dans la journ[ée]e?)"#)?,
                       |_| helpers::cycle_nth(Grain::Day, 0)
@@ -254,6 +292,47 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              datetime_check!(),
              |_, cycle, _, datetime| cycle.value().last_of(datetime.value())
     );
+    b.rule_4("dernier <cycle> de l'<cycle>",
+             b.reg(r#"(?:l[ae] )?derni[eéè]re?"#)?,
+             cycle_check!(),
+             b.reg(r#"d[eu] ?(?:l[a'’]|cet(?:te)?|ce)?"#)?,
+             cycle_check!(),
+             |_, cycle, _, outer| cycle.value().last_of(&helpers::cycle_nth(outer.value().grain, 0)?)
+    );
+    b.rule_4("avant-dernier <cycle> de <datetime>",
+             b.reg(r#"(?:l['’ ]?)?(ant[eé]p[eé]nulti[eè]me|p[eé]nulti[eè]me|avant[- ]derni[eè]re?)"#)?,
+             cycle_check!(),
+             b.reg(r#"d[e']"#)?,
+             datetime_check!(),
+             |text_match, cycle, _, datetime| {
+                 let n = if text_match.group(1).starts_with("ant") { 2 } else { 1 };
+                 cycle.value().nth_last_of(n, datetime.value())
+             }
+    );
+    b.rule_4("avant-dernier <cycle> de l'<cycle>",
+             b.reg(r#"(?:l['’ ]?)?(ant[eé]p[eé]nulti[eè]me|p[eé]nulti[eè]me|avant[- ]derni[eè]re?)"#)?,
+             cycle_check!(),
+             b.reg(r#"d[eu] ?(?:l[a'’]|cet(?:te)?|ce)?"#)?,
+             cycle_check!(),
+             |text_match, cycle, _, outer| {
+                 let n = if text_match.group(1).starts_with("ant") { 2 } else { 1 };
+                 cycle.value().nth_last_of(n, &helpers::cycle_nth(outer.value().grain, 0)?)
+             }
+    );
+    b.rule_5("le <ordinal> jour de l'<cycle>",
+             b.reg(r#"l[ea]"#)?,
+             ordinal_check_by_range!(1, 366),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Day),
+             b.reg(r#"d[eu] ?(?:l[a'’]|cet(?:te)?|ce)?"#)?,
+             cycle_check!(|cycle: &CycleValue| cycle.grain.is_greater_than_day()),
+             |_, ordinal, _, _, outer| helpers::nth_day_of(ordinal.value().value - 1, &helpers::cycle_nth(outer.value().grain, 0)?)
+    );
+    b.rule_3("jour <integer> de l'année",
+             b.reg(r#"(?:le )?jour"#)?,
+             integer_check_by_range!(1, 366),
+             b.reg(r#"de l['’ ]?ann[ée]e"#)?,
+             |_, integer, _| helpers::nth_day_of(integer.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+    );
     b.rule_4("<ordinal> <datetime> de <datetime>",
              ordinal_check!(), // the first
              datetime_check!(), // Thursday
@@ -322,6 +401,86 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  }
              }
     );
+    b.rule_2("années <integer> (decade)",
+             b.reg(r#"(?:les |des )?ann[ée]es"#)?,
+             integer_check_by_range!(0, 2990, |integer: &IntegerValue| integer.value % 10 == 0 && (integer.value <= 90 || integer.value >= 1000)),
+             |_, integer| helpers::decade(integer.value().value as i32)
+    );
+    b.rule_1_terminal("<roman or digits>e siècle (century)",
+                      b.reg(r#"(\d{1,2}|[mdclxvi]+) ?(?:e|er|[eè]me) si[eè]cle"#)?,
+                      |text_match| {
+                          let nth = match text_match.group(1).parse::<i32>() {
+                              Ok(nth) => nth,
+                              Err(_) => helpers::roman_numeral(text_match.group(1))? as i32,
+                          };
+                          helpers::century(nth)
+                      }
+    );
+    b.rule_2("<ordinal> siècle (century)",
+             ordinal_check!(),
+             b.reg(r#"si[eè]cle"#)?,
+             |ordinal, _| helpers::century(ordinal.value().value as i32)
+    );
+    b.rule_2("début|milieu|fin <decade or century>",
+             b.reg(r#"(?:au |en |à la )?(d[ée]but|milieu|fin) (?:des|du)"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |text_match, datetime| {
+                 let position = match text_match.group(1).as_ref() {
+                     "début" | "debut" => Position::Start,
+                     "milieu" => Position::Middle,
+                     "fin" => Position::End,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 datetime.value().part_of_years(position)
+             }
+    );
+    b.rule_2("dans|au <decade or century>",
+             b.reg(r#"dans|pendant|durant|au|le|du"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |_, datetime| Ok(datetime.value().clone())
+    );
+    b.rule_2("semaine <integer>",
+             b.reg(r#"(?:la |en )?semaine(?: n°| no\.?| num[ée]ro)?"#)?,
+             integer_check_by_range!(1, 53),
+             |_, integer| helpers::iso_week(integer.value().value as u32)
+    );
+    b.rule_1_terminal("iso week (2024-W05)",
+                      b.reg(r#"(\d{4})-?w(\d{2})"#)?,
+                      |text_match| helpers::iso_year_week(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_2("<ordinal> semaine de l'année",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"semaine de l['’ ]?ann[ée]e"#)?,
+             |ordinal, _| {
+                 let week = ordinal.value().value as u32;
+                 Ok(helpers::iso_week(week)?
+                     .intersect(&helpers::cycle_nth(Grain::Year, 0)?)?
+                     .form(Form::IsoWeek(week)))
+             }
+    );
+    b.rule_3("la <ordinal> semaine de l'année",
+             b.reg(r#"la"#)?,
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"semaine de l['’ ]?ann[ée]e"#)?,
+             |_, ordinal, _| {
+                 let week = ordinal.value().value as u32;
+                 Ok(helpers::iso_week(week)?
+                     .intersect(&helpers::cycle_nth(Grain::Year, 0)?)?
+                     .form(Form::IsoWeek(week)))
+             }
+    );
+    b.rule_2("<week> <year>",
+             datetime_check!(form!(Form::IsoWeek(_))),
+             datetime_check!(form!(Form::Year(_))),
+             |week, year| helpers::iso_year_week(year.value().form_year()?, week.value().form_iso_week()?)
+    );
+    b.rule_3("<week> de <year>",
+             datetime_check!(form!(Form::IsoWeek(_))),
+             b.reg(r#"de|en"#)?,
+             datetime_check!(form!(Form::Year(_))),
+             |week, _, year| helpers::iso_year_week(year.value().form_year()?, week.value().form_iso_week()?)
+    );
+    rustling_ontology_values::rules_semester::rules_semester(b, &SEMESTER_WORDS)?;
     b.rule_1_terminal("day of month (premier)",
                       b.reg(r#"premier|prem\.?|1er|1 er"#)?,
                       |_| helpers::day_of_month(1)
@@ -1778,6 +1937,10 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.reg(r#"trimestres?"#)?,
     |_| CycleValue::new(Grain::Quarter)
     );
+    b.rule_1_terminal("semestre (cycle)",
+                      b.reg(r#"semestres?"#)?,
+                      |_| CycleValue::new(Grain::Semester)
+    );
     b.rule_1("année (cycle)",
     b.reg(r#"an(?:n[ée]e?)?s?"#)?,
     |_| CycleValue::new(Grain::Year)
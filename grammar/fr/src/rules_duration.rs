@@ -8,6 +8,10 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"sec(?:onde)?s?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Second))
     );
+    b.rule_1_terminal("millisecond (unit-of-duration)",
+                      b.reg(r#"millisecondes?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Millisecond))
+    );
     b.rule_1_terminal("minute (unit-of-duration)",
                       b.reg(r#"min(?:ute)?s?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Minute))
@@ -51,13 +55,13 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, unit| Ok(DurationValue::new(PeriodComp::new(unit.value().grain, integer.value().value).into()))
+             |integer, unit| Ok(DurationValue::from_integer(unit.value().grain, integer.value()))
     );
     b.rule_3("<integer> de <unit-of-duration>",
              integer_check!(|integer: &IntegerValue| integer.value >= 0 && integer.group),
              b.reg(r#"d[e']"#)?,
              unit_of_duration_check!(),
-             |integer, _, unit| Ok(DurationValue::new(PeriodComp::new(unit.value().grain, integer.value().value).into()))
+             |integer, _, unit| Ok(DurationValue::from_integer(unit.value().grain, integer.value()))
     );
     b.rule_4("<number> h <number>",
              integer_check_by_range!(0),
@@ -137,6 +141,36 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             duration_check!(),
             |_, duration| Ok(duration.value().clone().prefixed())
     );
+
+    b.rule_3("<duration> - <duration> (interval)",
+             duration_check!(|duration: &DurationValue| !duration.suffixed),
+             b.reg(r#"-|à"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval(a.value(), b.value())
+    );
+
+    b.rule_4("between <duration> and <duration> (interval)",
+             b.reg(r#"entre|de"#)?,
+             duration_check!(|duration: &DurationValue| !duration.suffixed),
+             b.reg(r#"et|à|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, a, _, b| helpers::compose_duration_interval(a.value(), b.value())
+    );
+
+    b.rule_3("<integer> - <duration> (interval)",
+             integer_check_by_range!(0),
+             b.reg(r#"-|à"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value())
+    );
+
+    b.rule_4("between <integer> and <duration> (interval)",
+             b.reg(r#"entre|de"#)?,
+             integer_check_by_range!(0),
+             b.reg(r#"et|à|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |_, a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value())
+    );
     Ok(())
 }
 
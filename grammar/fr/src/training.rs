@@ -17,6 +17,9 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(168.0, Some("fahrenheit")), "cent soixante-huit fahrenheit", "168 F", "168f");
     example!(v, check_temperature(10.0, Some("kelvin")), "dix degrés kelvin", "10 °K", "10°k");
     example!(v, check_temperature(21.0, Some("kelvin")), "21 kelvin", "21 K", "21k");
+    example!(v, check_temperature_interval(20.0, 25.0, Some("celsius")), "entre 20 et 25 degrés celsius", "de 20 à 25 degrés celsius", "20-25°C");
+    example!(v, check_temperature(-10.0, None), "dix en dessous de zéro", "10 sous zéro");
+    example!(v, check_temperature(-3.0, Some("degree")), "moins trois degrés", "3 degrés sous zéro", "-3 degrés sous zéro");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -161,6 +164,21 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 10, 01], [2014, 01, 01]), "en fin d'année");
     example!(v, check_moment_span!(c, [2013, 01, 01], [2013, 03, 01]), "en début d'année");
     example!(v, check_moment!(c, [2013, 12, 25]), "Noel", "noël", "jour de noel");
+    example!(v, check_moment!(c, [2013, 3, 31]), "pâques", "le dimanche de pâques");
+    example!(v, check_moment!(c, [2013, 5, 5]), "pâques orthodoxe", "la pâque orthodoxe");
+    example!(v, check_moment!(c, [2013, 3, 18]), "lundi pur");
+    example!(v, check_moment_span!(c, [2013, 3, 18], [2013, 5, 5]), "le grand carême", "carême orthodoxe");
+    example!(v, check_moment!(c, [2013, 5, 19]), "la pentecôte", "la fête de la pentecôte");
+    example!(v, check_moment!(c, [2013, 5, 20]), "le lundi de pentecôte");
+    example!(v, check_moment!(c, [2013, 6, 23]), "la pentecôte orthodoxe");
+    example!(v, check_moment_span!(c, [2013, 7, 9], [2013, 8, 8]), "ramadan", "le ramadan");
+    example!(v, check_moment!(c, [2013, 8, 8]), "aïd el-fitr", "l'aid el fitr");
+    example!(v, check_moment!(c, [2013, 10, 15]), "aïd el-kébir", "l'aïd al-adha", "la fête du sacrifice");
+    example!(v, check_moment_span!(c, [2013, 3, 26], [2013, 4, 3]), "pessa'h", "la pâque juive");
+    example!(v, check_moment_span!(c, [2013, 9, 5], [2013, 9, 7]), "roch hachana", "le nouvel an juif");
+    example!(v, check_moment!(c, [2013, 9, 14]), "yom kippour", "le grand pardon");
+    example!(v, check_moment_span!(c, [2013, 11, 28], [2013, 12, 6]), "hanoukka", "la fête de hanoukka");
+    example!(v, check_moment!(c, [2013, 11, 5]), "le nouvel an islamique", "nouvel an musulman");
     example!(v, check_moment_span!(c, [2013, 12, 24, 18], [2013, 12, 25, 00]), "le soir de noël");
     example!(v, check_moment!(c, [2014, 1, 1]), "jour de l'an", "nouvel an", "premier janvier");
     example!(v, check_moment!(c, [2013, 12, 31]), "le réveillon de la saint sylvestre", "pour la saint-sylvestre");
@@ -263,12 +281,33 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2014, 6, 21], [2014, 9, 24]), "été 2014", "pour l'été 2014");
     example!(v, check_moment_span!(c, [2014, 12, 21], [2015, 3, 21]), "hiver 2014", "pour l'hiver 2014");
 
+    example!(v, check_moment_span!(c, [1980], [1990]), "les années 80", "les années quatre-vingt", "années 1980", "dans les années 80");
+    example!(v, check_moment_span!(c, [1940], [1950]), "les années 40", "les années quarante");
+    example!(v, check_moment_span!(c, [1900], [2000]), "le XXe siècle", "le 20e siècle", "au vingtième siècle");
+    example!(v, check_moment_span!(c, [1980], [1984]), "début des années 80");
+    example!(v, check_moment_span!(c, [1870], [1900]), "fin du XIXe siècle");
+    example!(v, check_moment!(c, [2013, 3, 18], Grain::Week), "semaine 12", "la semaine 12", "semaine n° 12", "en semaine 12");
+    example!(v, check_moment!(c, [2013, 1, 14], Grain::Week), "la troisième semaine de l'année");
+    example!(v, check_moment!(c, [2024, 1, 29], Grain::Week), "2024-W05", "semaine 5 de 2024");
+    example!(v, check_moment!(c, [2013, 1, 1], Grain::Semester), "le premier semestre", "ce semestre");
+    example!(v, check_moment!(c, [2013, 7, 1], Grain::Semester), "le semestre prochain", "la seconde moitié de l'année");
+    example!(v, check_moment!(c, [2024, 1, 1], Grain::Semester), "s1 2024", "premier semestre 2024");
+    example!(v, check_moment!(c, [2013, 12, 31], Grain::Day), "le dernier jour de l'année");
+    example!(v, check_moment!(c, [2013, 2, 27], Grain::Day), "l'avant-dernier jour du mois", "pénultième jour du mois");
+    example!(v, check_moment!(c, [2013, 9, 13], Grain::Day), "jour 256 de l'année", "le 256ème jour de l'année");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 5, 0], Precision::Approximate), "sous peu", "dans un instant");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 4, 30], [2013, 2, 12, 7, 30], Precision::Approximate), "bientôt", "prochainement");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 5, 0], [2013, 2, 12, 7, 30], Precision::Approximate), "dans quelque temps");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 12, 5, 30], [2013, 2, 13, 0, 0], Precision::Approximate), "plus tard dans la journée");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 5], [2013, 2, 12, 4, 30], Precision::Approximate, Grain::Day), "récemment");
+    example!(v, check_moment_span_with_precision!(c, [2013, 2, 13], [2013, 2, 18], Precision::Approximate), "dans les prochains jours");
+    example!(v, check_moment_span_with_precision!(c, [2013, 1, 7], [2013, 2, 4], Precision::Approximate), "il y a quelques semaines");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 2]), "pendant deux heures", "durant deux heures", "pour une durée de deux heures", "une durée de deux heures");
     example!(v, check_duration!([0, 0, 0, 1]), "pendant un jour", "une journée");
-    example!(v, check_duration!([0, 0, 0, 3]), "pour une durée de quelques jours", "quelques jours");
+    example!(v, check_duration!([0, 0, 0, 3], Precision::Approximate), "pour une durée de quelques jours", "quelques jours");
     example!(v, check_duration!([0, 1, 0]), "durant un mois");
     example!(v, check_duration!([1]), "durant une année");
     example!(v, check_duration!([0, 0, 0, 0, 0, 1, 3]), "pendant une minute et trois secondes");
@@ -276,6 +315,13 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "pendant environ un quart d'heure", "environ 1/4h");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durant une heure", "pendant exactement une heure");
     example!(v, check_duration!([0, 0, 2]), "pendant 2 semaines");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "entre deux et trois heures", "2-3 heures");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 0, 30], [0, 0, 0, 0, 1]), "entre 30 minutes et une heure");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 250]), "250 ms", "250 millisecondes");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 500]), "0,5 s", "0,5 seconde");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 1, 23, 450]), "1:23,45");
+    example!(v, check_duration!([0, 0, 4], Precision::Approximate), "plusieurs semaines");
+    example!(v, check_duration!([0, 0, 0, 10], Precision::Approximate), "une dizaine de jours");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -353,4 +399,18 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              "3ème",
              "troisième",
              "troisieme");
+    example!(v, check_fraction(1, 3), "un tiers", "1/3");
+    example!(v, check_fraction(3, 4), "trois quarts", "3/4");
+    example!(v, check_fraction(2, 3), "deux tiers", "2/3");
+    example!(v, check_fraction(3, 2), "1 1/2", "un et demi", "un et un demi");
+    example!(v, check_integer(3000000000), "3Md", "3mrds");
+    example!(v, check_integer(1000000), "1e6", "10^6");
+    example!(v, check_integer(1000000), "1 000 000", "1\u{202f}000\u{202f}000");
+    example!(v, check_float(1000000.5), "1.000.000,50", "1 000 000,50");
+    example!(v, check_integer(1500), "1.500", "1'500");
+    example!(v, check_integer(-10), "moins 10", "moins dix", "−10");
+    example!(v, check_integer(14), "XIV");
+    example!(v, check_integer(1999), "MCMXCIX");
+    example!(v, check_fuzzy_integer(3, 2, 5), "quelques");
+    example!(v, check_fuzzy_integer(4, 3, 7), "plusieurs");
 }
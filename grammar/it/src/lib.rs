@@ -22,10 +22,12 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
     rules_amount::rules_percentage(&mut b)?;
     rules_datetime::rules_cycle(&mut b)?;
     rules_datetime::rules_datetime(&mut b)?;
+    rustling_ontology_values::rules_timestamp::rules_timestamp(&mut b)?;
     rules_datetime::rules_datetime_with_duration(&mut b)?;
     rules_datetime::rules_datetime_with_nth_cycle(&mut b)?;
     rules_celebrations::rules_celebration(&mut b)?;
     rules_duration::rules_duration(&mut b)?;
+    rustling_ontology_values::rules_stopwatch::rules_stopwatch(&mut b)?;
     Ok(b.build())
 }
 
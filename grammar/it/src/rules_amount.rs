@@ -128,6 +128,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(AmountOfMoneyValue {
                      value: a.value().value(),
                      unit: b.value().unit,
+                     precision: a.value().precision(),
+                     fuzzy_range: a.value().fuzzy_range(),
                      ..AmountOfMoneyValue::default()
                  })
              });
@@ -138,6 +140,8 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(AmountOfMoneyValue {
                      value: b.value().value(),
                      unit: a.value().unit,
+                     precision: b.value().precision(),
+                     fuzzy_range: b.value().fuzzy_range(),
                      ..AmountOfMoneyValue::default()
                  })
              });
@@ -245,14 +249,21 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  })
              });
     b.rule_2("<temp> temp below zero",
-             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             temperature_check!(),
              b.reg(r#"sotto (?:lo )?zero"#)?,
-             |a, _| {
-                 Ok(TemperatureValue {
-                     value: -1.0 * a.value().value,
-                     latent: false,
-                     ..*a.value()
-                 })
-             });
+             |a, _| helpers::temperature_below_zero(a.value()));
+    b.rule_3("<temp> - <temp> (interval)",
+             temperature_check!(),
+             b.reg(r#"-|a"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("between <temp> and <temp> (interval)",
+             b.reg(r#"tra|fra|da"#)?,
+             temperature_check!(),
+             b.reg(r#"e|a|-"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
     Ok(())
 }
\ No newline at end of file
@@ -2,8 +2,17 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
 use rustling_ontology_moment::{Weekday, Grain};
+use rustling_ontology_values::rules_semester::SemesterWords;
 
 
+const SEMESTER_WORDS: SemesterWords = SemesterWords {
+    article: r#"il|nel|del"#,
+    of_year: r#"del|di|nel"#,
+    half_of_year: r#"met[àa] dell['’ ]?anno"#,
+    half_article: r#"la|nella"#,
+    abbreviation: r#"s"#,
+};
+
 pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     // Basic
     b.rule_2("intersect",
@@ -355,6 +364,98 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  }
              }
     );
+    b.rule_1_terminal("anni '90 (decade)",
+                      b.reg(r#"(?:gli )?anni ['’]?(\d0)"#)?,
+                      |text_match| helpers::decade(text_match.group(1).parse()?)
+    );
+    b.rule_2("anni <integer> (decade)",
+             b.reg(r#"(?:gli )?anni"#)?,
+             integer_check_by_range!(0, 2990, |integer: &IntegerValue| integer.value % 10 == 0 && (integer.value <= 90 || integer.value >= 1000)),
+             |_, integer| helpers::decade(integer.value().value as i32)
+    );
+    b.rule_2("<integer> secolo (century)",
+             integer_check_by_range!(1, 30),
+             b.reg(r#"secolo"#)?,
+             |integer, _| helpers::century(integer.value().value as i32)
+    );
+    b.rule_2("secolo <integer> (century)",
+             b.reg(r#"secolo"#)?,
+             integer_check_by_range!(1, 30),
+             |_, integer| helpers::century(integer.value().value as i32)
+    );
+    b.rule_1_terminal("<roman> secolo (century)",
+                      b.reg(r#"([xvi]+) secolo"#)?,
+                      |text_match| helpers::century(helpers::roman_numeral(text_match.group(1))? as i32)
+    );
+    b.rule_1_terminal("secolo <roman> (century)",
+                      b.reg(r#"secolo ([xvi]+)"#)?,
+                      |text_match| helpers::century(helpers::roman_numeral(text_match.group(1))? as i32)
+    );
+    b.rule_2("<ordinal> secolo (century)",
+             ordinal_check!(),
+             b.reg(r#"secolo"#)?,
+             |ordinal, _| helpers::century(ordinal.value().value as i32)
+    );
+    b.rule_2("inizio|metà|fine <decade or century>",
+             b.reg(r#"(?:all['’]|alla |a )?(inizio|met[àa]|fine) (?:degli|del)"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |text_match, datetime| {
+                 let position = match text_match.group(1).as_ref() {
+                     "inizio" => Position::Start,
+                     "metà" | "meta" => Position::Middle,
+                     "fine" => Position::End,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 datetime.value().part_of_years(position)
+             }
+    );
+    b.rule_2("nel <decade or century>",
+             b.reg(r#"negli|nel|il|durante (?:il|gli)"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |_, datetime| Ok(datetime.value().clone())
+    );
+    b.rule_2("settimana <integer>",
+             b.reg(r#"(?:la )?settimana(?: numero| n\.?)?"#)?,
+             integer_check_by_range!(1, 53),
+             |_, integer| helpers::iso_week(integer.value().value as u32)
+    );
+    b.rule_1_terminal("iso week (2024-W05)",
+                      b.reg(r#"(\d{4})-?w(\d{2})"#)?,
+                      |text_match| helpers::iso_year_week(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_2("<ordinal> settimana dell'anno",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"settimana dell['’ ]?anno"#)?,
+             |ordinal, _| {
+                 let week = ordinal.value().value as u32;
+                 Ok(helpers::iso_week(week)?
+                     .intersect(&helpers::cycle_nth(Grain::Year, 0)?)?
+                     .form(Form::IsoWeek(week)))
+             }
+    );
+    b.rule_3("la <ordinal> settimana dell'anno",
+             b.reg(r#"la"#)?,
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"settimana dell['’ ]?anno"#)?,
+             |_, ordinal, _| {
+                 let week = ordinal.value().value as u32;
+                 Ok(helpers::iso_week(week)?
+                     .intersect(&helpers::cycle_nth(Grain::Year, 0)?)?
+                     .form(Form::IsoWeek(week)))
+             }
+    );
+    b.rule_2("<week> <year>",
+             datetime_check!(form!(Form::IsoWeek(_))),
+             datetime_check!(form!(Form::Year(_))),
+             |week, year| helpers::iso_year_week(year.value().form_year()?, week.value().form_iso_week()?)
+    );
+    b.rule_3("<week> del <year>",
+             datetime_check!(form!(Form::IsoWeek(_))),
+             b.reg(r#"del"#)?,
+             datetime_check!(form!(Form::Year(_))),
+             |week, _, year| helpers::iso_year_week(year.value().form_year()?, week.value().form_iso_week()?)
+    );
+    rustling_ontology_values::rules_semester::rules_semester(b, &SEMESTER_WORDS)?;
     // Days of the month
     b.rule_1_terminal("first of the month",
                       b.reg(r#"(?:il )?(?:1|prim)[o°](?: di)?"#)?,
@@ -1510,6 +1611,10 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"trimestre"#)?,
                       |_| CycleValue::new(Grain::Quarter)
     );
+    b.rule_1_terminal("semester (cycle)",
+                      b.reg(r#"semestr[ei]"#)?,
+                      |_| CycleValue::new(Grain::Semester)
+    );
     b.rule_1_terminal("year (cycle)",
                       b.reg(r#"ann[oi]"#)?,
                       |_| CycleValue::new(Grain::Year)
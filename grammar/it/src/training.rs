@@ -255,6 +255,15 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 5, 0, 0]), "tra mezzora", "tra una mezzora", "fra 1/2 ora");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 15, 0]), "tra tre quarti d'ora", "fra 3/4 d'ora");
     example!(v, check_moment!(c, [2016, 12, 15]), "15.12.2016", "15.12.16");
+    example!(v, check_moment_span!(c, [1980], [1990]), "gli anni 80", "anni '80", "gli anni ottanta");
+    example!(v, check_moment_span!(c, [1900], [2000]), "il XX secolo", "il secolo XX", "ventesimo secolo");
+    example!(v, check_moment_span!(c, [1980], [1984]), "inizio degli anni 80");
+    example!(v, check_moment_span!(c, [1870], [1900]), "fine del XIX secolo");
+    example!(v, check_moment!(c, [2013, 3, 18], Grain::Week), "settimana 12", "la settimana 12", "settimana numero 12");
+    example!(v, check_moment!(c, [2013, 1, 14], Grain::Week), "la terza settimana dell'anno");
+    example!(v, check_moment!(c, [2024, 1, 29], Grain::Week), "2024-W05", "settimana 5 del 2024");
+    example!(v, check_moment!(c, [2013, 1, 1], Grain::Semester), "il primo semestre", "questo semestre");
+    example!(v, check_moment!(c, [2024, 7, 1], Grain::Semester), "secondo semestre del 2024", "s2 2024");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -267,6 +276,12 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "durante un quarto d'ora all'incirca", "durante circa un quarto d'ora", "per più o meno 1/4 d'ora");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durante un'ora", "per un'ora");
     example!(v, check_duration!([0, 0, 2]), "durante 2 settimane", "per due settimane");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "tra due e tre ore", "2-3 ore");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 0, 30], [0, 0, 0, 0, 1]), "tra 30 minuti e un'ora");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 250]), "250 ms", "250 millisecondi");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 500]), "0,5 s", "0,5 secondi");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 1, 23, 450]), "1:23.45");
+    example!(v, check_duration!([0, 0, 0, 2], Precision::Approximate), "un paio di giorni");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -301,6 +316,19 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(10.08), "10,08", "dieci punto zero otto");//, "dieci virgola zero otto");
     example!(v, check_ordinal(1), "1o", "1a", "il 1o", "la 1a", "1°");
     example!(v, check_ordinal(3), "3o", "il 3o", "3a", "la 3a", "3°", "terzo", "terza");
+    example!(v, check_fraction(1, 3), "un terzo", "1/3");
+    example!(v, check_fraction(3, 4), "tre quarti", "3/4");
+    example!(v, check_fraction(2, 3), "due terzi", "2/3");
+    example!(v, check_fraction(3, 2), "1 1/2", "uno e mezzo", "uno e un mezzo");
+    example!(v, check_integer(2000000), "2mln");
+    example!(v, check_integer(3000000000), "3mld");
+    example!(v, check_integer(1000000), "1e6", "10^6");
+    example!(v, check_float(1000000.5), "1.000.000,50");
+    example!(v, check_integer(1500), "1.500", "1'500");
+    example!(v, check_integer(-4), "meno quattro", "−4");
+    example!(v, check_integer(16), "XVI");
+    example!(v, check_fuzzy_integer(3, 2, 5), "alcuni", "qualche");
+    example!(v, check_fuzzy_integer(2, 2, 3), "un paio di");
 }
 
 pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -317,6 +345,9 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(168.0, Some("fahrenheit")), "cento sessant otto fahrenheit", "cento sessant otto gradi fahrenheit", "168 gradi fahrenheit", "168° F", "168 F", "168f");
     example!(v, check_temperature(10.0, Some("kelvin")), "dieci gradi kelvin", "dieci kelvin", "10°K", "10° K", "10 °K", "10°k");
     example!(v, check_temperature(21.0, Some("kelvin")), "21 kelvin", "vent uno gradi kelvin", "21°K", "21° K", "21 °K", "21°k", "21 K", "21k");
+    example!(v, check_temperature_interval(20.0, 25.0, Some("celsius")), "tra 20 e 25 gradi celsius", "da 20 a 25 gradi celsius", "20-25°C");
+    example!(v, check_temperature(-4.0, None), "quattro sotto zero");
+    example!(v, check_temperature(-4.0, Some("degree")), "meno quattro gradi", "4 gradi sotto lo zero", "-4 gradi sotto zero");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
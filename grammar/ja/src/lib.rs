@@ -14,8 +14,10 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
                     ::rustling::BoundariesChecker::no_check());
     rules::rules_numbers(&mut b)?;
     rules::rules_datetime(&mut b)?;
+    rustling_ontology_values::rules_timestamp::rules_timestamp(&mut b)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
+    rustling_ontology_values::rules_stopwatch::rules_stopwatch(&mut b)?;
     rules::rules_temperature(&mut b)?;            
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
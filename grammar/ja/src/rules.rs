@@ -2,7 +2,16 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::number_format::{Grouping, NumberFormat};
 use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_moment::japanese_era::JapaneseEra;
+
+// A comma followed by three digits groups thousands, otherwise it is a decimal mark as in "0,8"
+const NUMBER_FORMAT: NumberFormat = NumberFormat {
+    decimal_separators: &['.', ',', '，'],
+    grouping_separators: &[',', '，'],
+    groupings: &[Grouping::Thousands],
+};
 
 fn ja_quantifier_regex() -> &'static str {
     r#"(?:尾|台|名|枚|話|部|面|両|問|拍子|条|段|輪|門|倍|番|畳|合|膳|錠|ページ|頁|例|字|文|行|語|把|羽|頭|つ|人|個|冊|匹|回|曲|本|杯|点|種類|種|等|足|階|カ国|ヶ国|クラス|丁|件|体|勝|区|口|坪|基|局|席|式|振|挺|敗|束|校|株|機|歩|滴|発|社|票|組|艦|行|通り|通|隻|首|客|戸|着|箱|脚|軒|切れ|品|斤|粒|貫|句|巻|画|稿|筆|言|級)"#
@@ -222,6 +231,19 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         }
     );
 
+    b.rule_2("<decimal> 万, 億 (magnitude)",
+        float_check!(|float: &FloatValue| !float.suffixed),
+        b.reg(r#"(万|億)"#)?,
+        |a, text_match| {
+            let multiplier = match text_match.group(1).as_ref() {
+                "万" => 1e4,
+                "億" => 1e8,
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            helpers::compose_magnitude(&a.value().clone().into(), multiplier)
+        }
+    );
+
     b.rule_3("ordinal number",
             integer_check_by_range!(0),
             b.reg(ja_quantifier_regex())?,
@@ -234,7 +256,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_1("float number", 
-        b.reg(r#"((?:\d|〇)*[、,，\.](?:\d|〇)+)"#)?, |text_match| {
+        b.reg(r#"((?:\d|〇)*[、\.](?:\d|〇)+)"#)?, |text_match| {
           let res = text_match.group(1).replace_japanese_digit().replace_comma();
           let value: f64 = res.parse()?;
           Ok(FloatValue {
@@ -242,9 +264,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
               ..FloatValue::default()
           })
     });
+    b.rule_1_terminal("number with digit grouping",
+        b.reg(&NUMBER_FORMAT.regex())?,
+        |text_match| NUMBER_FORMAT.parse(&text_match.group(1))
+    );
     b.rule_3("number dot number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
-             b.reg(r#"てん|テン|[、,，\.]|点"#)?,
+             b.reg(r#"てん|テン|[、\.]|点"#)?,
              integer_check!(|integer: &IntegerValue| !integer.suffixed),
              |a, _, b| {
                  let value: f64 = format!("{}.{}", a.value().value, b.value().value).parse()?;
@@ -255,7 +281,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              });
     b.rule_3("number dot number",
          number_check!(|number: &NumberValue| !number.prefixed()),
-         b.reg(r#"てん|テン|[、,，\.]|点"#)?,
+         b.reg(r#"てん|テン|[、\.]|点"#)?,
          b.reg(r#"((?:零|一|二|三|四|五|六|七|八|九|ゼロ)+)"#)?,
          |a, _, decimals| {
               fn number_mapping(c: char) -> Option<char> {
@@ -284,30 +310,28 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  ..FloatValue::default()
               })
     });
+    b.rule_1_terminal("fraction (numeric)",
+        b.reg(r#"(\d+) ?/ ?(\d+)"#)?,
+        |text_match| helpers::numeric_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_3("<denominator> 分の <numerator> (fraction)",
+        integer_check!(|integer: &IntegerValue| !integer.prefixed),
+        b.reg(r#"分の"#)?,
+        integer_check!(|integer: &IntegerValue| !integer.suffixed),
+        |denominator, _, numerator| helpers::fraction(numerator.value().value, denominator.value().value)
+    );
+    b.rule_1_terminal("scientific notation (1e6)",
+        b.reg(r#"(\d+(?:\.\d+)?)e([+-]?\d{1,2})"#)?,
+        |text_match| helpers::compose_power(text_match.group(1).parse()?, 10.0, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("power (10^3)",
+        b.reg(r#"(\d+) ?\^ ?(\d{1,2})"#)?,
+        |text_match| helpers::compose_power(1.0, text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
     b.rule_2("numbers prefix with -, negative or minus",
-             b.reg(r#"-|マイナス|零下|れいか"#)?,
+             b.reg(r#"-|−|マイナス|零下|れいか"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
-             |_, a| -> RuleResult<NumberValue> {
-                 Ok(match a.value().clone() {
-                     // checked
-                     NumberValue::Integer(integer) => {
-                         IntegerValue {
-                             value: integer.value * -1,
-                             prefixed: true,
-                             ..integer
-                         }
-                             .into()
-                     }
-                     NumberValue::Float(float) => {
-                         FloatValue {
-                             value: float.value * -1.0,
-                             prefixed: true,
-                             ..float
-                         }
-                             .into()
-                     }
-                 })
-             });
+             |_, a| helpers::negate_number(a.value()));
     b.rule_2("numbers prefix with +, positive",
              b.reg(r#"\+"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -577,18 +601,42 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
         }
     );
     b.rule_2("<latent temp> below zero",
-             b.reg(r#"マイナス|零下|れいか|-"#)?,
+             b.reg(r#"マイナス|零下|れいか|-|−"#)?,
              temperature_check!(),
-             |_, a| {
-                 Ok(TemperatureValue {
-                     value: -1.0 * a.value().value,
-                     latent: false,
-                     ..*a.value()
-                 })
-             });
+             |_, a| helpers::temperature_below_zero(a.value()));
+    b.rule_3("<temp> - <temp> (interval)",
+             temperature_check!(),
+             b.reg(r#"から|〜|~|-"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <temp> (interval)",
+             number_check!(),
+             b.reg(r#"から|〜|~|-"#)?,
+             temperature_check!(),
+             |a, _, b| {
+                 let min = TemperatureValue {
+                     value: a.value().value(),
+                     unit: None,
+                     latent: true,
+                 };
+                 helpers::compose_temperature_interval(&min, b.value())
+             }
+    );
     Ok(())
 }
 
+fn japanese_era(name: &str) -> RuleResult<JapaneseEra> {
+    match name {
+        "明治" | "m" | "M" => Ok(JapaneseEra::Meiji),
+        "大正" | "t" | "T" => Ok(JapaneseEra::Taisho),
+        "昭和" | "s" | "S" => Ok(JapaneseEra::Showa),
+        "平成" | "h" | "H" => Ok(JapaneseEra::Heisei),
+        "令和" | "r" | "R" => Ok(JapaneseEra::Reiwa),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
 pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect <datetime>",
              datetime_check!(|datetime: &DatetimeValue| !datetime.latent && excluding_form!(Form::PartOfDay(_))(datetime)),
@@ -1285,6 +1333,29 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                     .intersect(&helpers::day_of_week(Weekday::Thu)?
                     .span_to(&helpers::day_of_week(Weekday::Fri)?, true)?)
     );
+    b.rule_2("<integer> 年代 (decade)",
+             integer_check_by_range!(0, 2990, |integer: &IntegerValue| integer.value % 10 == 0 && (integer.value <= 90 || integer.value >= 1000)),
+             b.reg(r#"年代"#)?,
+             |integer, _| helpers::decade(integer.value().value as i32)
+    );
+    b.rule_2("<integer> 世紀 (century)",
+             integer_check_by_range!(1, 30),
+             b.reg(r#"世紀"#)?,
+             |integer, _| helpers::century(integer.value().value as i32)
+    );
+    b.rule_2("<decade or century> 前半|半ば|後半",
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             b.reg(r#"(前半|初め|初頭|半ば|中頃|後半|終わり|末)"#)?,
+             |datetime, text_match| {
+                 let position = match text_match.group(1).as_ref() {
+                     "前半" | "初め" | "初頭" => Position::Start,
+                     "半ば" | "中頃" => Position::Middle,
+                     "後半" | "終わり" | "末" => Position::End,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 datetime.value().part_of_years(position)
+             }
+    );
     b.rule_2("year",
              integer_check_by_range!(1_900, 2_100),
              b.reg(r#"年"#)?,
@@ -1304,6 +1375,46 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(helpers::year(integer.value().value as i32)?.latent())
              }
     );
+    b.rule_3("<era> <year>",
+             b.reg(r#"(明治|大正|昭和|平成|令和)"#)?,
+             integer_check_by_range!(1, 99),
+             b.reg(r#"年"#)?,
+             |text_match, integer, _| {
+                 helpers::japanese_era_year(japanese_era(text_match.group(1).as_ref())?, integer.value().value as i32)
+             }
+    );
+    b.rule_1_terminal("<era> first year",
+             b.reg(r#"(明治|大正|昭和|平成|令和)元年"#)?,
+             |text_match| {
+                 helpers::japanese_era_year(japanese_era(text_match.group(1).as_ref())?, 1)
+             }
+    );
+    // A bare initial and year, e.g. "s3" or "h1", is rather a size or a heading, it is only a date
+    // followed by "年" or as a full era date
+    b.rule_1_terminal("<era initial> <year>年",
+             b.reg(r#"([mtshr])(元|\d{1,2})年"#)?,
+             |text_match| {
+                 let year = match text_match.group(2).as_ref() {
+                     "元" => 1,
+                     digits => digits.parse()?,
+                 };
+                 helpers::japanese_era_year(japanese_era(text_match.group(1).as_ref())?, year)
+             }
+    );
+    b.rule_1_terminal("<era initial> <year>",
+             b.reg_neg_lh(r#"([mtshr])(\d{1,2})"#, r#"^(?:\d|[/\.]\d)"#)?,
+             |text_match| {
+                 Ok(helpers::japanese_era_year(japanese_era(text_match.group(1).as_ref())?, text_match.group(2).parse()?)?.latent())
+             }
+    );
+    b.rule_1_terminal("<era initial> yy.mm.dd",
+             b.reg_neg_lh(r#"([mtshr])(\d{1,2})[/\.](0?[1-9]|1[0-2])[/\.](3[01]|[12]\d|0?[1-9])"#, r#"^\d"#)?,
+             |text_match| {
+                 let year = helpers::japanese_era_year(japanese_era(text_match.group(1).as_ref())?, text_match.group(2).parse()?)?;
+                 Ok(year.intersect(&helpers::month_day(text_match.group(3).parse()?, text_match.group(4).parse()?)?)?
+                     .form(Form::YearMonthDay(None)))
+             }
+    );
 
     b.rule_1("the first day of month",
              b.reg(r#"初日"#)?,
@@ -2076,6 +2187,10 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"秒間?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Second))
     );
+    b.rule_1_terminal("millisecond (unit-of-duration)",
+                      b.reg(r#"ミリ秒間?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Millisecond))
+    );
     b.rule_1_terminal("minute (unit-of-duration)",
                       b.reg(r#"分間?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Minute))
@@ -2115,19 +2230,19 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::from_integer(uod.value().grain, integer.value()))
     );
     b.rule_3("<integer> more <unit-of-duration>",
              b.reg(r#"もう|後|あと"#)?,
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |_, integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |_, integer, uod| Ok(DurationValue::from_integer(uod.value().grain, integer.value()))
     );
     b.rule_3("<integer> less <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
              b.reg(r#"短く"#)?,
-             |integer, uod, _| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod, _| Ok(DurationValue::from_integer(uod.value().grain, integer.value()))
     );
     // b.rule_2_terminal("number.number hours",
     //                   b.reg(r#"(\d+)\.(\d+)"#)?,
@@ -2295,6 +2410,18 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"まるまる"#)?,
              |duration, _| Ok(duration.value().clone().precision(Precision::Exact))
     );
+    b.rule_3("<duration> - <duration> (interval)",
+             duration_check!(|duration: &DurationValue| !duration.suffixed),
+             b.reg(r#"から|〜|~|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<integer> - <duration> (interval)",
+             integer_check_by_range!(0),
+             b.reg(r#"から|〜|~|-"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value())
+    );
 
     Ok(())
 }
@@ -2328,6 +2455,10 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"四半期"#)?,
                       |_| CycleValue::new(Grain::Quarter)
     );
+    b.rule_1_terminal("semester (cycle)",
+                      b.reg(r#"半期"#)?,
+                      |_| CycleValue::new(Grain::Semester)
+    );
     b.rule_1_terminal("year (cycle)",
                       b.reg(r#"年"#)?,
                       |_| CycleValue::new(Grain::Year)
@@ -2397,5 +2528,20 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
              |datetime, _, integer, _| helpers::cycle_nth_after(Grain::Quarter, integer.value().value - 1, datetime.value())
     );
+    b.rule_1_terminal("first/second half of the year",
+                      b.reg(r#"(上|下)半期"#)?,
+                      |text_match| {
+                          let n = if text_match.group(1) == "上" { 0 } else { 1 };
+                          helpers::cycle_nth_after(Grain::Semester, n, &helpers::cycle_nth(Grain::Year, 0)?)
+                      }
+    );
+    b.rule_2("<year> first/second half",
+             datetime_check!(form!(Form::Year(_))),
+             b.reg(r#"(?:の)?(上|下)半期"#)?,
+             |datetime, text_match| {
+                 let n = if text_match.group(1) == "上" { 0 } else { 1 };
+                 helpers::cycle_nth_after(Grain::Semester, n, datetime.value())
+             }
+    );
     Ok(())
 }
\ No newline at end of file
@@ -24,15 +24,22 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(340), "三百四十");
     example!(v, check_integer(1096), "千九十六");
     example!(v, check_integer(40020), "四万二十");
+    example!(v, check_integer(1000000), "1,000,000", "1，000，000");
+    example!(v, check_float(1200.5), "1,200.5");
 
-    example!(v, check_float(0.8), "0.8", "+0.8", "0点８", "零点八", "〇点８", "〇.８");
+    example!(v, check_float(0.8), "0.8", "+0.8", "0点８", "零点八", "〇点８", "〇.８", "0,8");
     example!(v, check_float(0.3), "0.3", "+0.3", "0点３", "零点三", "〇点３", "〇.３");
+    example!(v, check_fraction(1, 3), "3分の1", "三分の一");
+    example!(v, check_fraction(3, 4), "四分の三");
 
     example!(v, check_ordinal(1), "最初", "一番目", "一行目", "一錠目", "一匹目");
     example!(v, check_ordinal(7), "七番目", "七体目", "七問目", "七拍子目", "七種目", "七種類目");
     example!(v, check_ordinal(11), "十一番目", "十一句目", "十一面目", "十一ページ目", "十一口目");
     example!(v, check_ordinal(91), "九十一番目", "九十一滴目", "九十一段目", "九十一拍子目", "九十一切れ目");
     example!(v, check_ordinal(40020), "四万二十坪目", "四万二十つ目", "四万二十カ国目", "四万二十字目", "四万二十拍子目");
+    example!(v, check_integer(15000), "1.5万");
+    example!(v, check_integer(1000000), "1e6", "10^6");
+    example!(v, check_integer(-5), "−5");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -83,6 +90,8 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(-10.0, Some("degree")), "マイナス十度", "零下十度", "れいか十ど",  "れいか10ど", "-10°");
     example!(v, check_temperature(-7.0, Some("celsius")), "マイナス七°C", "零下7℃", "摂氏マイナス七度", "摂氏零下7ど", "摂氏れいか7度");
     example!(v, check_temperature(-3.0, Some("fahrenheit")), "マイナス3°F", "零下三℉", "華氏マイナス3度", "華氏零下三度", "カ氏れいか三ど");
+    example!(v, check_temperature(-10.0, Some("degree")), "−10°");
+    example!(v, check_temperature_interval(20.0, 25.0, Some("celsius")), "20℃から25℃", "20から25℃", "20〜25℃");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -93,6 +102,10 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 10]), "十ヶ月間", "十カ月間");
     example!(v, check_duration!([0, 0, 0, 0, 2], Precision::Approximate), "およそ二時間", "二時間程", "二時間位");
 
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 250]), "250ms", "250ミリ秒");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 500]), "0.5秒");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 1, 23, 450]), "1:23.45");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "二時間から三時間", "2から3時間", "2〜3時間");
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -123,6 +136,15 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2012]), "去年", "昨年", "前年", "前の年");
     example!(v, check_moment!(c, [2013]), "今年", "当年");
     example!(v, check_moment!(c, [2014]), "来年", "新年");
+    example!(v, check_moment!(c, [2023]), "令和5年", "令和五年", "R5", "R5年");
+    example!(v, check_moment!(c, [2018]), "平成30年", "平成三十年", "H30", "H30年");
+    example!(v, check_moment!(c, [1975]), "昭和50年", "S50");
+    example!(v, check_moment_span!(c, [2019, 5, 1], [2020, 1, 1]), "令和元年", "R元年");
+    example!(v, check_moment_span!(c, [1989, 1, 8], [1990, 1, 1]), "平成元年");
+    example!(v, check_moment_span!(c, [2019, 1, 1], [2019, 5, 1]), "平成31年", "H31");
+    example!(v, check_moment!(c, [2019, 4, 30]), "平成31年四月三十日", "H31.4.30");
+    example!(v, check_moment!(c, [2019, 5, 1]), "令和元年五月一日", "R1.5.1");
+    example!(v, check_moment!(c, [2018, 4, 1]), "H30.4.1", "H30/4/1");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4], [2013, 2, 12, 12]), "朝", "午前", "今朝");
     example!(v, check_moment!(c, [2013, 12, 25]), "クリスマス", "次のクリスマス",  "二千十三年十二月二十五日", "十二月二十五日");
     example!(v, check_moment_span!(c, [2013, 2, 11, 18], [2013, 2, 12, 3]), "昨晚");
@@ -187,4 +209,9 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 3, 1], [2013, 3, 6]), "一日から五日", "一日から五日まで");
     example!(v, check_moment_span!(c, [2013, 2, 28], [2013, 3, 4]), "二十八日から三日", "二十八日から三日まで");
     example!(v, check_moment!(c, [2013, 2, 10, 14, 57]), "一昨日の午後三時三分前");
+    example!(v, check_moment_span!(c, [1990], [2000]), "90年代", "1990年代");
+    example!(v, check_moment_span!(c, [1800], [1900]), "19世紀");
+    example!(v, check_moment_span!(c, [1990], [1994]), "90年代前半");
+    example!(v, check_moment!(c, [2013, 1, 1], Grain::Semester), "上半期");
+    example!(v, check_moment!(c, [2024, 7, 1], Grain::Semester), "2024年下半期");
 }
\ No newline at end of file
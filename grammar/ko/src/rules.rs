@@ -3,6 +3,13 @@ use rustling::{RuleError, RuleResult, RuleSetBuilder, RustlingResult};
 use rustling_ontology_moment::{Grain, PeriodComp, Weekday};
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::number_format::{Grouping, NumberFormat};
+
+const NUMBER_FORMAT: NumberFormat = NumberFormat {
+    decimal_separators: &['.'],
+    grouping_separators: &[','],
+    groupings: &[Grouping::Thousands],
+};
 
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2(
@@ -98,6 +105,16 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2(
+        "<number> per cent",
+        number_check!(),
+        b.reg(r#"퍼센트|프로|%|％"#)?,
+        |number, _| Ok(PercentageValue(number.value().value())),
+    );
+    Ok(())
+}
+
 pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1("number as temp", number_check!(), |a| {
         Ok(TemperatureValue {
@@ -109,18 +126,8 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     b.rule_2(
         "below <temp>",
         b.reg(r#"영하"#)?,
-        temperature_check!(|temp: &TemperatureValue| !temp.latent),
-        |_, temp| {
-            if temp.value().value >= 0.0 {
-                Ok(TemperatureValue {
-                    value: -1.0 * temp.value().value,
-                    unit: temp.value().unit,
-                    latent: false,
-                })
-            } else {
-                Ok(temp.value().clone())
-            }
-        },
+        temperature_check!(),
+        |_, temp| helpers::temperature_below_zero(temp.value()),
     );
     b.rule_2(
         "above <temp>",
@@ -199,6 +206,13 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             })
         },
     );
+    b.rule_3(
+        "<temp> - <temp> (interval)",
+        temperature_check!(),
+        b.reg(r#"에서|~|-"#)?,
+        temperature_check!(),
+        |a, _, b| helpers::compose_temperature_interval(a.value(), b.value()),
+    );
     Ok(())
 }
 
@@ -341,128 +355,12 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             helpers::day_of_month(dom)
         },
     );
-    b.rule_1_terminal("New Year's Day", b.reg(r#"신정|새해 첫 날"#)?, |_| {
-        helpers::month_day(1, 1)
-    });
-
-    // b.rule_1("Korean New Year",
-    //     b.reg(r#"설날|설|원일|구정"#)?,
-    //     based on the lunear calendar which is not supported yet
-    // );
-
-    // b.rule_1("Buddha’s Birthday",
-    //     b.reg(r#"부처님 오신 날|석존성탄절|석가탄신일|석탄일|석탄절"#)?,
-    //     based on the lunear calendar which is not supported yet
-    // );
-
-    // b.rule_1("Chuseok",
-    //     b.reg(r#"추석|한가위"#)?,
-    //     based on the lunear calendar which is not supported yet
-    // );
-
-    // b.rule_1("Chuseok Holidays",
-    //     b.reg(r#"추석연휴"#),
-    //     based on the lunear calendar which is not supported yet
-    // );
-
-    b.rule_1_terminal("Independence Movement Day", b.reg(r#"삼일절"#)?, |_| {
-        helpers::month_day(3, 1)
-    });
-    b.rule_1_terminal("Children's Day", b.reg(r#"어린이날"#)?, |_| {
-        helpers::month_day(5, 5)
-    });
-    b.rule_1_terminal("Memorial Day", b.reg(r#"현충일"#)?, |_| {
-        helpers::month_day(6, 6)
-    });
-    b.rule_1_terminal("Constitution Day", b.reg(r#"제헌절"#)?, |_| {
-        helpers::month_day(6, 17)
-    });
-    b.rule_1_terminal("Liberation Day", b.reg(r#"광복절"#)?, |_| {
-        helpers::month_day(8, 15)
-    });
-    b.rule_1_terminal("National Foundation Day", b.reg(r#"개천절"#)?, |_| {
-        helpers::month_day(10, 3)
-    });
-    b.rule_1_terminal("Hangul Day", b.reg(r#"한글날"#)?, |_| {
-        helpers::month_day(10, 9)
-    });
-    b.rule_1_terminal(
-        "christmas eve",
-        b.reg(r#"(크리스마스)?이브"#)?,
-        |_| helpers::month_day(12, 24),
-    );
-    b.rule_1_terminal("christmas", b.reg(r#"크리스마스|성탄절"#)?, |_| {
-        helpers::month_day(12, 25)
-    });
     b.rule_2(
         "absorption of , after named day",
         datetime_check!(form!(Form::DayOfWeek{..})),
         b.reg(r#","#)?,
         |dow, _| Ok(dow.value().clone()),
     );
-    b.rule_1_terminal("Father's day", b.reg(r#"아버지\s?날"#)?, |_| {
-        let sundays_of_june = helpers::month(6)?.intersect(&helpers::day_of_week(Weekday::Sun)?)?;
-        let second_week_of_june =
-            helpers::cycle_nth_after(Grain::Week, 2, &helpers::month_day(6, 1)?)?;
-        sundays_of_june.intersect(&second_week_of_june) // third sunday of June
-    });
-    b.rule_1_terminal("Mother's day", b.reg(r#"어머니\s?날"#)?, |_| {
-        let sundays_of_may = helpers::month(5)?.intersect(&helpers::day_of_week(Weekday::Sun)?)?;
-        let first_week_of_may =
-            helpers::cycle_nth_after(Grain::Week, 1, &helpers::month_day(5, 1)?)?;
-        sundays_of_may.intersect(&first_week_of_may) // second sunday of May
-    });
-    b.rule_1_terminal("Parents day", b.reg(r"어버이\s?날")?, |_| {
-        helpers::month_day(5, 8)
-    });
-    b.rule_1_terminal("Teachers' day", b.reg(r#"스승의\s?날"#)?, |_| {
-        helpers::month_day(5, 15)
-    });
-    b.rule_1_terminal(
-        "Labor Day",
-        b.reg(r#"노동절|노동일|근로자의\s?날"#)?,
-        |_| helpers::month_day(5, 1),
-    );
-    b.rule_1_terminal(
-        "Valentine’s Day",
-        b.reg(r#"발렌타인\s?데이"#)?,
-        |_| helpers::month_day(2, 14),
-    );
-    b.rule_1_terminal("White Day", b.reg(r#"화이트\s?데이"#)?, |_| {
-        helpers::month_day(3, 14)
-    });
-    b.rule_1_terminal("Coming-of-Age Day", b.reg(r#"성년의\s?날"#)?, |_| {
-        let mondays_of_may = helpers::month(5)?.intersect(&helpers::day_of_week(Weekday::Mon)?)?;
-        let third_week_of_may =
-            helpers::cycle_nth_after(Grain::Week, 3, &helpers::month_day(5, 1)?)?;
-        mondays_of_may.intersect(&third_week_of_may) // third monday of May
-    });
-    b.rule_1_terminal("First Dog Days", b.reg(r#"초복"#)?, |_| {
-        helpers::month_day(7, 13)
-    });
-    b.rule_1_terminal("Second Dog Days", b.reg(r#"중복"#)?, |_| {
-        helpers::month_day(7, 23)
-    });
-    b.rule_1_terminal("Last Dog Days", b.reg(r#"말복"#)?, |_| {
-        helpers::month_day(8, 12)
-    });
-    b.rule_1_terminal(
-        "Halloween",
-        b.reg(r#"핼러윈\s?데이|핼러윈"#)?,
-        |_| helpers::month_day(10, 31),
-    );
-    b.rule_1_terminal("Armed Forces Day", b.reg(r#"국군의\s?날"#)?, |_| {
-        helpers::month_day(10, 1)
-    });
-    b.rule_1_terminal("Couple’s Day", b.reg(r#"부부의\s?날"#)?, |_| {
-        helpers::month_day(5, 21)
-    });
-    b.rule_1_terminal("Elderly Day", b.reg(r#"노인의\s?날"#)?, |_| {
-        helpers::month_day(10, 2)
-    });
-    b.rule_1_terminal("Dokdo Day", b.reg(r#"독도의\s?날"#)?, |_| {
-        helpers::month_day(10, 25)
-    });
     b.rule_1_terminal("now", b.reg(r#"방금|지금|방금|막|이제"#)?, |_| {
         helpers::cycle_nth(Grain::Second, 0)
     });
@@ -668,6 +566,32 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             )
         },
     );
+    b.rule_2(
+        "<integer> 년대 (decade)",
+        integer_check_by_range!(0, 2990, |integer: &IntegerValue| integer.value % 10 == 0 && (integer.value <= 90 || integer.value >= 1000)),
+        b.reg(r#"년대"#)?,
+        |integer, _| helpers::decade(integer.value().value as i32),
+    );
+    b.rule_2(
+        "<integer> 세기 (century)",
+        integer_check_by_range!(1, 30),
+        b.reg(r#"세기"#)?,
+        |integer, _| helpers::century(integer.value().value as i32),
+    );
+    b.rule_2(
+        "<decade or century> 초반|중반|후반",
+        datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+        b.reg(r#"(초반|중반|후반|초|말)"#)?,
+        |datetime, text_match| {
+            let position = match text_match.group(1).as_ref() {
+                "초반" | "초" => Position::Start,
+                "중반" => Position::Middle,
+                "후반" | "말" => Position::End,
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            datetime.value().part_of_years(position)
+        },
+    );
     b.rule_2(
         "year",
         integer_check_by_range!(1),
@@ -1200,6 +1124,9 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("second (unit-of-duration)", b.reg(r#"초"#)?, |_| {
         Ok(UnitOfDurationValue::new(Grain::Second))
     });
+    b.rule_1_terminal("millisecond (unit-of-duration)", b.reg(r#"밀리초"#)?, |_| {
+        Ok(UnitOfDurationValue::new(Grain::Millisecond))
+    });
     b.rule_1_terminal("minute (unit-of-duration)", b.reg(r#"분"#)?, |_| {
         Ok(UnitOfDurationValue::new(Grain::Minute))
     });
@@ -1335,6 +1262,20 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             ))
         },
     );
+    b.rule_3(
+        "<duration> - <duration> (interval)",
+        duration_check!(|duration: &DurationValue| !duration.suffixed),
+        b.reg(r#"에서|~|-"#)?,
+        duration_check!(|duration: &DurationValue| !duration.prefixed),
+        |a, _, b| helpers::compose_duration_interval(a.value(), b.value()),
+    );
+    b.rule_3(
+        "<integer> - <duration> (interval)",
+        integer_check_by_range!(0),
+        b.reg(r#"에서|~|-"#)?,
+        duration_check!(|duration: &DurationValue| !duration.prefixed),
+        |a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value()),
+    );
     Ok(())
 }
 
@@ -1360,6 +1301,9 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("quarter (cycle)", b.reg(r#"분기"#)?, |_| {
         CycleValue::new(Grain::Quarter)
     });
+    b.rule_1_terminal("semester (cycle)", b.reg(r#"반기"#)?, |_| {
+        CycleValue::new(Grain::Semester)
+    });
     b.rule_1_terminal("year (cycle)", b.reg(r#"해|(?:연|년)간?"#)?, |_| {
         CycleValue::new(Grain::Year)
     });
@@ -1450,6 +1394,19 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             helpers::cycle_nth_after(Grain::Quarter, integer.value().value - 1, datetime.value())
         },
     );
+    b.rule_1_terminal("first/second half of the year", b.reg(r#"(상|하)반기"#)?, |text_match| {
+        let n = if text_match.group(1) == "상" { 0 } else { 1 };
+        helpers::cycle_nth_after(Grain::Semester, n, &helpers::cycle_nth(Grain::Year, 0)?)
+    });
+    b.rule_2(
+        "<year> first/second half",
+        datetime_check!(form!(Form::Year(_))),
+        b.reg(r#"(상|하)반기"#)?,
+        |datetime, text_match| {
+            let n = if text_match.group(1) == "상" { 0 } else { 1 };
+            helpers::cycle_nth_after(Grain::Semester, n, datetime.value())
+        },
+    );
     Ok(())
 }
 
@@ -1465,13 +1422,9 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         IntegerValue::new(value)
     });
     b.rule_1_terminal(
-        "integer with thousands separator ,",
-        b.reg(r#"(\d{1,3}(,\d\d\d){1,5})"#)?,
-        |text_match| {
-            let reformatted_string = text_match.group(1).replace(",", "");
-            let value: i64 = reformatted_string.parse()?;
-            IntegerValue::new(value)
-        },
+        "number with digit grouping",
+        b.reg(&NUMBER_FORMAT.regex())?,
+        |text_match| NUMBER_FORMAT.parse(&text_match.group(1)),
     );
     b.rule_1_terminal("integer 0", b.reg(r#"영|공|빵"#)?, |_| {
         IntegerValue::new(0)
@@ -1716,6 +1669,43 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("decimal number", b.reg(r#"(\d*\.\d+)"#)?, |text_match| {
         FloatValue::new(text_match.group(1).parse()?)
     });
+    b.rule_1_terminal(
+        "scientific notation (1e6)",
+        b.reg(r#"(\d+(?:\.\d+)?)e([+-]?\d{1,2})"#)?,
+        |text_match| {
+            helpers::compose_power(
+                text_match.group(1).parse()?,
+                10.0,
+                text_match.group(2).parse()?,
+            )
+        },
+    );
+    b.rule_1_terminal(
+        "power (10^3)",
+        b.reg(r#"(\d+) ?\^ ?(\d{1,2})"#)?,
+        |text_match| {
+            helpers::compose_power(
+                1.0,
+                text_match.group(1).parse()?,
+                text_match.group(2).parse()?,
+            )
+        },
+    );
+    // Integers compose with 천, 만 and 억 through "integer - TYPE 1" and "number thousands"
+    b.rule_2(
+        "<decimal> 천, 만, 억 (magnitude)",
+        float_check!(|float: &FloatValue| !float.suffixed),
+        b.reg(r#"(천|만|억)"#)?,
+        |a, text_match| {
+            let multiplier = match text_match.group(1).as_ref() {
+                "천" => 1e3,
+                "만" => 1e4,
+                "억" => 1e8,
+                _ => return Err(RuleError::Invalid.into()),
+            };
+            helpers::compose_magnitude(&a.value().clone().into(), multiplier)
+        },
+    );
 
     b.rule_2(
         "number dot number - 삼점사",
@@ -1749,31 +1739,11 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         },
     );
 
-    b.rule_1_terminal(
-        "decimal with thousands separator",
-        b.reg(r#"(\d+(,\d\d\d)+\.\d+)"#)?,
-        |text_match| FloatValue::new(text_match.group(1).replace(",", "").parse()?),
-    );
     b.rule_2(
         "numbers prefix with -, 마이너스, or 마이나스",
-        b.reg(r#"-|마이너스\s?|마이나스\s?"#)?,
+        b.reg(r#"-|−|마이너스\s?|마이나스\s?"#)?,
         number_check!(|number: &NumberValue| !number.prefixed()),
-        |_, a| -> RuleResult<NumberValue> {
-            Ok(match a.value().clone() {
-                NumberValue::Integer(integer) => IntegerValue {
-                    value: integer.value * -1,
-                    prefixed: true,
-                    ..integer
-                }
-                .into(),
-                NumberValue::Float(float) => FloatValue {
-                    value: float.value * -1.0,
-                    prefixed: true,
-                    ..float
-                }
-                .into(),
-            })
-        },
+        |_, a| helpers::negate_number(a.value()),
     );
     b.rule_2(
         "numbers prefix with +, positive",
@@ -1806,14 +1776,24 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         number_check!(|number: &NumberValue| !number.prefixed()),
         b.reg(r#"분(의|에)"#)?,
         number_check!(|number: &NumberValue| !number.suffixed()),
-        |a, _, b| FloatValue::new(b.value().value() / a.value().value()),
+        |a, _, b| match (a.value(), b.value()) {
+            (NumberValue::Integer(denominator), NumberValue::Integer(numerator)) => {
+                helpers::fraction(numerator.value, denominator.value)
+            }
+            _ => FloatValue::new(b.value().value() / a.value().value()),
+        },
     );
     b.rule_3(
         "fraction",
         number_check!(|number: &NumberValue| !number.prefixed()),
         b.reg(r#"/"#)?,
         number_check!(|number: &NumberValue| !number.suffixed()),
-        |a, _, b| FloatValue::new(a.value().value() / b.value().value()),
+        |a, _, b| match (a.value(), b.value()) {
+            (NumberValue::Integer(numerator), NumberValue::Integer(denominator)) => {
+                helpers::numeric_fraction(numerator.value, denominator.value)
+            }
+            _ => FloatValue::new(a.value().value() / b.value().value()),
+        },
     );
     Ok(())
 }
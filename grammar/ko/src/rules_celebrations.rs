@@ -0,0 +1,99 @@
+use rustling::{RuleSetBuilder, RustlingResult};
+use rustling_ontology_moment::{Grain, Weekday};
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+use rustling_ontology_values::rules_holidays::{rules_holidays, Holiday};
+
+const HOLIDAYS: &[(Holiday, &str)] = &[
+    (Holiday::NewYearsDay, r#"신정|새해 첫 날"#),
+    (Holiday::LunarNewYear, r#"설날|원일|구정"#),
+    (Holiday::LanternFestival, r#"(?:정월\s?)?대보름"#),
+    (
+        Holiday::BuddhasBirthday,
+        r#"부처님\s?오신\s?날|석존성탄절|석가탄신일|석탄일|석탄절"#,
+    ),
+    (Holiday::DragonBoatFestival, r#"단오"#),
+    (Holiday::MidAutumnFestival, r#"추석|한가위"#),
+    (Holiday::ValentinesDay, r#"발렌타인\s?데이"#),
+    (
+        Holiday::InternationalWorkersDay,
+        r#"노동절|노동일|근로자의\s?날"#,
+    ),
+    (Holiday::GoodFriday, r#"성\s?금요일"#),
+    (Holiday::Easter, r#"부활절"#),
+    (Holiday::Ascension, r#"(?:예수\s?)?승천일"#),
+    (Holiday::Halloween, r#"핼러윈\s?데이|핼러윈"#),
+    (Holiday::Thanksgiving, r#"추수\s?감사절"#),
+    (Holiday::ChristmasEve, r#"(크리스마스)?이브"#),
+    (Holiday::Christmas, r#"크리스마스|성탄절"#),
+    (Holiday::KoreanParentsDay, r#"어버이\s?날|어머니\s?날|아버지\s?날"#),
+];
+
+pub fn rules_celebration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rules_holidays(b, HOLIDAYS)?;
+
+    b.rule_1_terminal("Chuseok Holidays", b.reg(r#"추석\s?연휴"#)?, |_| {
+        Ok(helpers::lunar_month_day(8, 14)?
+            .span_to(&helpers::lunar_month_day(8, 16)?, true)?
+            .form(Form::Celebration))
+    });
+
+    b.rule_1_terminal("Independence Movement Day", b.reg(r#"삼일절"#)?, |_| {
+        Ok(helpers::month_day(3, 1)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Children's Day", b.reg(r#"어린이\s?날"#)?, |_| {
+        Ok(helpers::month_day(5, 5)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Memorial Day", b.reg(r#"현충일"#)?, |_| {
+        Ok(helpers::month_day(6, 6)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Constitution Day", b.reg(r#"제헌절"#)?, |_| {
+        Ok(helpers::month_day(6, 17)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Liberation Day", b.reg(r#"광복절"#)?, |_| {
+        Ok(helpers::month_day(8, 15)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("National Foundation Day", b.reg(r#"개천절"#)?, |_| {
+        Ok(helpers::month_day(10, 3)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Hangul Day", b.reg(r#"한글날"#)?, |_| {
+        Ok(helpers::month_day(10, 9)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Teachers' day", b.reg(r#"스승의\s?날"#)?, |_| {
+        Ok(helpers::month_day(5, 15)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("White Day", b.reg(r#"화이트\s?데이"#)?, |_| {
+        Ok(helpers::month_day(3, 14)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Coming-of-Age Day", b.reg(r#"성년의\s?날"#)?, |_| {
+        let mondays_of_may = helpers::month(5)?.intersect(&helpers::day_of_week(Weekday::Mon)?)?;
+        let third_week_of_may =
+            helpers::cycle_nth_after(Grain::Week, 3, &helpers::month_day(5, 1)?)?;
+        // third monday of May
+        Ok(mondays_of_may
+            .intersect(&third_week_of_may)?
+            .form(Form::Celebration))
+    });
+    b.rule_1_terminal("First Dog Days", b.reg(r#"초복"#)?, |_| {
+        Ok(helpers::month_day(7, 13)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Second Dog Days", b.reg(r#"중복"#)?, |_| {
+        Ok(helpers::month_day(7, 23)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Last Dog Days", b.reg(r#"말복"#)?, |_| {
+        Ok(helpers::month_day(8, 12)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Armed Forces Day", b.reg(r#"국군의\s?날"#)?, |_| {
+        Ok(helpers::month_day(10, 1)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Couple’s Day", b.reg(r#"부부의\s?날"#)?, |_| {
+        Ok(helpers::month_day(5, 21)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Elderly Day", b.reg(r#"노인의\s?날"#)?, |_| {
+        Ok(helpers::month_day(10, 2)?.form(Form::Celebration))
+    });
+    b.rule_1_terminal("Dokdo Day", b.reg(r#"독도의\s?날"#)?, |_| {
+        Ok(helpers::month_day(10, 25)?.form(Form::Celebration))
+    });
+    Ok(())
+}
@@ -16,6 +16,11 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(10.0, Some("£"), Precision::Exact), "딱 10파운드");
 }
 
+pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_percentage(20.0), "이십 퍼센트", "이십퍼센트", "20%", "20 퍼센트", "20프로");
+    example!(v, check_percentage(3.5), "3.5%", "삼점오 퍼센트");
+}
+
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_temperature(37.0, Some("celsius")), "+37°C", "37°C", "섭씨37°", "섭씨37도");
     example!(v, check_temperature(70.0, Some("fahrenheit")), "70°F", "화씨70°", "화씨70도");
@@ -26,6 +31,12 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(3.0, Some("degree")), "영상 삼도");
     example!(v, check_temperature(6.0, Some("celsius")), "섭씨 6도");
     example!(v, check_temperature(32.0, Some("fahrenheit")), "화씨 32도");
+    example!(v, check_temperature(-15.0, None), "영하 15");
+    example!(v, check_temperature_interval(20.0, 25.0, Some("celsius")), "섭씨 20도에서 25도", "20~25°C", "20에서 25°C");
+}
+
+pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "2시간에서 3시간", "2~3시간", "2에서 3시간");
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -149,6 +160,11 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 12, 25]), "크리스마스");
     example!(v, check_moment!(c, [2013, 12, 24]), "크리스마스이브");
     example!(v, check_moment!(c, [2014, 1, 1]), "신정");
+    example!(v, check_moment!(c, [2014, 1, 31]), "설날", "구정");
+    example!(v, check_moment!(c, [2013, 2, 24]), "정월대보름", "대보름");
+    example!(v, check_moment!(c, [2013, 5, 17]), "부처님 오신 날", "석가탄신일");
+    example!(v, check_moment!(c, [2013, 9, 19]), "추석", "한가위");
+    example!(v, check_moment_span!(c, [2013, 9, 18], [2013, 9, 21]), "추석연휴", "추석 연휴");
     example!(v, check_moment!(c, [2013, 3, 1]), "삼일절");
     example!(v, check_moment!(c, [2013, 5, 5]), "어린이날");
     example!(v, check_moment!(c, [2013, 6, 6]), "현충일");
@@ -156,6 +172,9 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 8, 15]), "광복절");
     example!(v, check_moment!(c, [2013, 10, 3]), "개천절");
     example!(v, check_moment!(c, [2013, 10, 9]), "한글날");
+    example!(v, check_moment!(c, [2013, 3, 29]), "성금요일");
+    example!(v, check_moment!(c, [2013, 3, 31]), "부활절");
+    example!(v, check_moment!(c, [2013, 11, 28]), "추수감사절");
     example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 00]), "오늘저녁");
     example!(v, check_moment_span!(c, [2013, 2, 12, 19], [2013, 2, 13, 00]), "오늘밤");
     example!(v, check_moment_span!(c, [2013, 2, 8, 18], [2013, 2, 11, 00]), "저번주말", "지난주말");
@@ -256,9 +275,7 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2015]), "후년", "재명년", "내명년");
 
     // Holidays
-    example!(v, check_moment!(c, [2013, 6, 16]), "아버지날");
-    example!(v, check_moment!(c, [2013, 5, 12]), "어머니날");
-    example!(v, check_moment!(c, [2013, 5, 8]), "어버이날");
+    example!(v, check_moment!(c, [2013, 5, 8]), "어버이날", "어머니날", "아버지날");
     example!(v, check_moment!(c, [2013, 5, 15]), "스승의 날");
     example!(v, check_moment!(c, [2013, 5, 1]), "노동절", "노동일", "근로자의날");
     example!(v, check_moment!(c, [2013, 2, 14]), "발렌타인 데이");
@@ -272,6 +289,11 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 10, 1]), "국군의날");
     example!(v, check_moment!(c, [2013, 10, 2]), "노인의날");
     example!(v, check_moment!(c, [2013, 10, 25]), "독도의 날");
+    example!(v, check_moment_span!(c, [1990], [2000]), "90년대");
+    example!(v, check_moment_span!(c, [1800], [1900]), "19세기");
+    example!(v, check_moment_span!(c, [1990], [1994]), "90년대 초반");
+    example!(v, check_moment!(c, [2013, 1, 1], Grain::Semester), "상반기");
+    example!(v, check_moment!(c, [2024, 7, 1], Grain::Semester), "2024년 하반기");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -311,6 +333,12 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(1.23), "일점이삼");
     example!(v, check_integer(-3), "-3", "마이너스3", "마이너스삼", "마이너스 3", "마이나스3", "마이나스 3");
     example!(v, check_float(3.0 / 4.0), "3/4", "사분의삼");
+    example!(v, check_fraction(1, 3), "1/3", "삼분의 일", "삼분의일");
     example!(v, check_ordinal(25), "스물다섯번째", "이십오번째");
     example!(v, check_ordinal(1), "첫번째", "첫째", "첫번", "첫");
+    example!(v, check_integer(15000), "1.5만");
+    example!(v, check_integer(1000000), "1,000,000");
+    example!(v, check_float(1200.5), "1,200.5");
+    example!(v, check_integer(1000000), "1e6", "10^6");
+    example!(v, check_integer(-5), "마이너스 5", "−5");
 }
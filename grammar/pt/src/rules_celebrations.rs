@@ -1,18 +1,17 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::rules_holidays::{Holiday, rules_holidays};
+
+const HOLIDAYS: &[(Holiday, &str)] = &[
+    (Holiday::Christmas, r#"(?:dia de )?natal"#),
+    (Holiday::NewYearsDay, r#"ano novo|primeiro dia do ano"#),
+    (Holiday::AllSaintsDay, r#"(?:(?:no )?dia de )?(?:todos os santos|finados)"#),
+    (Holiday::InternationalWorkersDay, r#"dia do (?:trabalho|trabalhador)"#),
+];
 
 pub fn rules_celebration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
-    // Date HOLIDAY
-    b.rule_1_terminal("Christmas day",
-         b.reg(r#"(?:dia de )?natal"#)?,
-         |_| Ok(helpers::month_day(12, 25)?.form(Form::Celebration))
-    );
-    // Date HOLIDAY
-    b.rule_1_terminal("New year's eve",
-        b.reg(r#"ano novo|primeiro dia do ano"#)?,
-        |_| Ok(helpers::month_day(1, 1)?.form(Form::Celebration))
-    );
+    rules_holidays(b, HOLIDAYS)?;
     // Date HOLIDAY
     b.rule_1_terminal("Christmas'eve",
         b.reg(r#"(?:véspera |(?:a )?noite )de natal"#)?,
@@ -23,15 +22,5 @@ pub fn rules_celebration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  .form(Form::Celebration))
         }
     );
-    // Date HOLIDAY
-    b.rule_1_terminal("All saint's day",
-        b.reg(r#"(?:(?:no )?dia de )?(?:todos os santos|finados)"#)?,
-        |_| Ok(helpers::month_day(11, 1)?.form(Form::Celebration))
-    );
-    // Date HOLIDAY
-    b.rule_1_terminal("1st of May",
-        b.reg(r#"dia do (?:trabalho|trabalhador)"#)?,
-        |_| Ok(helpers::month_day(5, 1)?.form(Form::Celebration))
-    );
     Ok(())
 }
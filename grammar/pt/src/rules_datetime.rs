@@ -4,8 +4,17 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
 use rustling_ontology_moment::{Weekday, Grain};
+use rustling_ontology_values::rules_semester::SemesterWords;
 
 
+const SEMESTER_WORDS: SemesterWords = SemesterWords {
+    article: r#"o|no|do"#,
+    of_year: r#"d[eo]"#,
+    half_of_year: r#"metade do ano"#,
+    half_article: r#"a|na"#,
+    abbreviation: r#"s"#,
+};
+
 pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              datetime_check!(|datetime: &DatetimeValue| !datetime.latent),
@@ -289,6 +298,43 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  }
              }
     );
+    b.rule_2("anos <integer> (decade)",
+             b.reg(r#"(?:os )?anos"#)?,
+             integer_check_by_range!(0, 2990, |integer: &IntegerValue| integer.value % 10 == 0 && (integer.value <= 90 || integer.value >= 1000)),
+             |_, integer| helpers::decade(integer.value().value as i32)
+    );
+    b.rule_2("década de <integer> (decade)",
+             b.reg(r#"(?:a )?d[ée]cada de"#)?,
+             integer_check_by_range!(0, 2990, |integer: &IntegerValue| integer.value % 10 == 0 && (integer.value <= 90 || integer.value >= 1000)),
+             |_, integer| helpers::decade(integer.value().value as i32)
+    );
+    b.rule_2("século <integer> (century)",
+             b.reg(r#"(?:o )?s[ée]culo"#)?,
+             integer_check_by_range!(1, 30),
+             |_, integer| helpers::century(integer.value().value as i32)
+    );
+    b.rule_1_terminal("século <roman> (century)",
+                      b.reg(r#"(?:o )?s[ée]culo ([xvi]+)"#)?,
+                      |text_match| helpers::century(helpers::roman_numeral(text_match.group(1))? as i32)
+    );
+    b.rule_2("início|meados|final de <decade or century>",
+             b.reg(r#"(?:no |em )?(in[íi]cio|meados|final|fim) d[oa]s?"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |text_match, datetime| {
+                 let position = match text_match.group(1).as_ref() {
+                     "início" | "inicio" => Position::Start,
+                     "meados" => Position::Middle,
+                     "final" | "fim" => Position::End,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 datetime.value().part_of_years(position)
+             }
+    );
+    b.rule_2("em <decade or century>",
+             b.reg(r#"n[oa]s?|durante(?: [oa]s?)?"#)?,
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             |_, datetime| Ok(datetime.value().clone())
+    );
     b.rule_2("in year",
              b.reg(r#"em|[dn]?o ano(?: de)?"#)?,
              integer_check_by_range!(1000, 2100),
@@ -301,6 +347,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              datetime_check!(form!(Form::Year(_))),
              |a, _, b| a.value().intersect(b.value())
     );
+    rustling_ontology_values::rules_semester::rules_semester(b, &SEMESTER_WORDS)?;
     b.rule_2("o ordinal (<day-of-month>)",
              b.reg(r#"o"#)?,
              ordinal_check_by_range!(1, 31),
@@ -1156,6 +1203,10 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"anos?"#)?,
                       |_| CycleValue::new(Grain::Year)
     );
+    b.rule_1_terminal("semester (cycle)",
+                      b.reg(r#"semestres?"#)?,
+                      |_| CycleValue::new(Grain::Semester)
+    );
     b.rule_1_terminal("trimester (cycle)",
                           b.reg(r#"trimestres?"#)?,
                           |_| CycleValue::new(Grain::Year)
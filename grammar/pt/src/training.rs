@@ -38,6 +38,17 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(3), "3o", "3a", "3º", "3ª", "terceiro", "terceira");
     example!(v, check_ordinal(2), "segundo", "2º", "2o");
     example!(v, check_ordinal(5), "quinto", "5º", "5o");
+    example!(v, check_fraction(1, 3), "um terço", "1/3");
+    example!(v, check_fraction(3, 4), "três quartos", "3/4");
+    example!(v, check_fraction(2, 3), "dois terços", "2/3");
+    example!(v, check_fraction(3, 2), "1 1/2", "um e meio", "um e um meio");
+    example!(v, check_integer(2000000), "2mi");
+    example!(v, check_integer(1000000), "1e6", "10^6");
+    example!(v, check_float(1000000.5), "1.000.000,50");
+    example!(v, check_integer(1500), "1.500", "1\u{2009}500");
+    example!(v, check_integer(-2), "menos dois", "−2");
+    example!(v, check_integer(21), "XXI");
+    example!(v, check_fuzzy_integer(4, 3, 7), "vários", "várias");
 }
 
 pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -54,6 +65,9 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(-5.0, Some("fahrenheit")), "menos cinco graus fahrenheit", "cinco graus fahrenheit abaixo de zero", "-5°F", "-5°f");
     example!(v, check_temperature(168.0, Some("fahrenheit")), "cento e sessenta e oito fahrenheit", "cento e sessenta e oito graus fahrenheit", "168 graus fahrenheit", "168°F", "168°f");
     example!(v, check_temperature(10.0, Some("kelvin")), "dez kelvin", "10K", "10k", "dez graus kelvin");
+    example!(v, check_temperature_interval(20.0, 25.0, Some("celsius")), "entre 20 e 25 graus celsius", "de 20 a 25 graus celsius", "20-25°C");
+    example!(v, check_temperature(-2.0, None), "dois abaixo de zero");
+    example!(v, check_temperature(-2.0, Some("degree")), "-2 graus abaixo de zero", "menos dois graus");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -333,6 +347,11 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 5, 0, 0]), "dentro de meia hora", "dentro de trinta minutos");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 15, 0]), "dentro de quarenta e cinco minutos");
     example!(v, check_moment!(c, [2016, 12, 15]), "15.12.2016", "15.12.16", "15/12/2016");
+    example!(v, check_moment_span!(c, [1980], [1990]), "os anos 80", "anos 1980", "década de 80");
+    example!(v, check_moment_span!(c, [1900], [2000]), "o século XX", "século 20");
+    example!(v, check_moment_span!(c, [1980], [1984]), "início dos anos 80");
+    example!(v, check_moment!(c, [2013, 1, 1], Grain::Semester), "o primeiro semestre", "este semestre");
+    example!(v, check_moment!(c, [2024, 7, 1], Grain::Semester), "segundo semestre de 2024", "s2 2024");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -346,4 +365,10 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "durante um quarto de hora mais o menos", "aproximadamente durante um quarto de hora");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durante uma hora", "por uma hora");
     example!(v, check_duration!([0, 0, 2]), "durante 2 semanas", "por duas semanas");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "entre duas e três horas", "2-3 horas");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 0, 30], [0, 0, 0, 0, 1]), "entre 30 minutos e uma hora");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 250]), "250 ms", "250 milissegundos");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 500]), "0,5 s", "0,5 segundos");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 1, 23, 450]), "1:23.45");
+    example!(v, check_duration!([0, 0, 0, 3], Precision::Approximate), "alguns dias");
 }
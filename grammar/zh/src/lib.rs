@@ -4,6 +4,7 @@ extern crate rustling_ontology_values;
 extern crate rustling_ontology_moment;
 
 pub mod rules;
+pub mod rules_celebrations;
 pub mod training;
 
 use rustling_ontology_values::DimensionKind::*;
@@ -14,14 +15,19 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
                     ::rustling::BoundariesChecker::no_check());
     rules::rules_numbers(&mut b)?;
     rules::rules_datetime(&mut b)?;
+    rules_celebrations::rules_celebration(&mut b)?;
+    rustling_ontology_values::rules_timestamp::rules_timestamp(&mut b)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
+    rustling_ontology_values::rules_stopwatch::rules_stopwatch(&mut b)?;
     rules::rules_temperature(&mut b)?;
+    rules::rules_finance(&mut b)?;
+    rules::rules_percentage(&mut b)?;
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Datetime, Duration, Temperature];
+    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, Percentage];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
@@ -30,6 +36,8 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     training::examples_datetime(&mut v);
     training::examples_durations(&mut v);
     training::examples_temperature(&mut v);
+    training::examples_finance(&mut v);
+    training::examples_percentage(&mut v);
     v
 }
 
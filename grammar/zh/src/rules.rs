@@ -1,7 +1,14 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
+use rustling_ontology_values::number_format::{Grouping, NumberFormat};
+use rustling_ontology_moment::{Weekday, Grain};
+
+const NUMBER_FORMAT: NumberFormat = NumberFormat {
+    decimal_separators: &['.'],
+    grouping_separators: &[',', '，'],
+    groupings: &[Grouping::Thousands],
+};
 
 
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
@@ -9,6 +16,10 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"秒(?:钟|鐘)?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Second))
     );
+    b.rule_1_terminal("millisecond (unit-of-duration)",
+                      b.reg(r#"毫秒(?:钟|鐘)?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Millisecond))
+    );
 
     b.rule_1_terminal("minute (unit-of-duration)",
                       b.reg(r#"分(?:钟|鐘)?"#)?,
@@ -43,7 +54,21 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::from_integer(uod.value().grain, integer.value()))
+    );
+
+    b.rule_3("<duration> - <duration> (interval)",
+             duration_check!(|duration: &DurationValue| !duration.suffixed),
+             b.reg(r#"到|至|-|~"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval(a.value(), b.value())
+    );
+
+    b.rule_3("<integer> - <duration> (interval)",
+             integer_check_by_range!(0),
+             b.reg(r#"到|至|-|~"#)?,
+             duration_check!(|duration: &DurationValue| !duration.prefixed),
+             |a, _, b| helpers::compose_duration_interval_with_integer(a.value(), b.value())
     );
 
     Ok(())
@@ -189,11 +214,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| helpers::cycle_nth(Grain::Day, 2)
     );
 
-    b.rule_1_terminal("valentine's day",
-                      b.reg(r#"情人(?:节|節)"#)?,
-                      |_| helpers::month_day(2, 14)
-    );
-
     b.rule_1_terminal("hh:.mm (time-of-day)",
                       b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:.]([0-5]\d)"#)?,
                       |text_match| {
@@ -203,16 +223,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       }
     );
 
-    b.rule_1_terminal("new year's day",
-                      b.reg(r#"元旦(?:节|節)?"#)?,
-                      |_| helpers::month_day(1, 1)
-    );
-
-    b.rule_1_terminal("christmas",
-                      b.reg(r#"(?:圣诞|聖誕)(?:节|節)?"#)?,
-                      |_| helpers::month_day(12, 25)
-    );
-
     b.rule_1_terminal("now",
                       b.reg(r#"现在|此时|此刻|当前|現在|此時|當前|宜家|而家|依家"#)?,
                       |_| helpers::cycle_nth(Grain::Second, 0)
@@ -295,11 +305,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       }
     );
 
-    b.rule_1_terminal("army's day",
-                      b.reg(r#"建(?:军节|軍節)"#)?,
-                      |_| helpers::month_day(8, 1)
-    );
-
     b.rule_1_terminal("tonight",
                       b.reg(r#"今晚|今天晚上"#)?,
                       |_| {
@@ -320,22 +325,12 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       }
     );
 
-    b.rule_1_terminal("army's day",
-                      b.reg(r#"(?:儿|兒)童(?:节|節)"#)?,
-                      |_| helpers::month_day(6, 1)
-    );
-
 
     b.rule_1_terminal("this year",
                       b.reg(r#"今年"#)?,
                       |_| helpers::cycle_nth(Grain::Year, 0)
     );
 
-    b.rule_1_terminal("women's day",
-                      b.reg(r#"(?:妇|婦)女(?:节|節)"#)?,
-                      |_| helpers::month_day(3, 8)
-    );
-
     b.rule_1_terminal("evening|night",
                       b.reg(r#"晚上|晚间"#)?,
                       |_| {
@@ -445,11 +440,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
 
-    b.rule_1_terminal("national day",
-                      b.reg(r#"(?:国庆|國慶)(?:节|節)?"#)?,
-                      |_| helpers::month_day(10, 1)
-    );
-
     b.rule_4("the <cycle> after <datetime>",
              b.reg(r#"那"#)?,
              cycle_check!(),
@@ -489,11 +479,6 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| helpers::cycle_nth(Grain::Day, -2)
     );
 
-    b.rule_1_terminal("labor day",
-                      b.reg(r#"劳动节|勞動節"#)?,
-                      |_| helpers::month_day(5, 1)
-    );
-
     b.rule_2("next <cycle>",
              b.reg(r#"下(?:个|個)?"#)?,
              cycle_check!(),
@@ -546,6 +531,49 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |integer, _| helpers::year(integer.value().value as i32)
     );
 
+    b.rule_2("<integer> 年代 (decade)",
+             integer_check_by_range!(0, 2990, |integer: &IntegerValue| integer.value % 10 == 0 && (integer.value <= 90 || integer.value >= 1000)),
+             b.reg(r#"年代"#)?,
+             |integer, _| helpers::decade(integer.value().value as i32)
+    );
+
+    b.rule_2("<integer> 世纪 (century)",
+             integer_check_by_range!(1, 30),
+             b.reg(r#"世纪|世紀"#)?,
+             |integer, _| helpers::century(integer.value().value as i32)
+    );
+
+    b.rule_1_terminal("first/second half of the year",
+                      b.reg(r#"(上|下)半年"#)?,
+                      |text_match| {
+                          let n = if text_match.group(1) == "上" { 0 } else { 1 };
+                          helpers::cycle_nth_after(Grain::Semester, n, &helpers::cycle_nth(Grain::Year, 0)?)
+                      }
+    );
+
+    b.rule_2("<year> first/second half",
+             datetime_check!(form!(Form::Year(_))),
+             b.reg(r#"(上|下)半年"#)?,
+             |datetime, text_match| {
+                 let n = if text_match.group(1) == "上" { 0 } else { 1 };
+                 helpers::cycle_nth_after(Grain::Semester, n, datetime.value())
+             }
+    );
+
+    b.rule_2("<decade or century> 初|中期|末",
+             datetime_check!(|datetime: &DatetimeValue| form!(Form::Decade(_))(datetime) || form!(Form::Century(_))(datetime)),
+             b.reg(r#"(初期|初|中期|中叶|中葉|末期|末)"#)?,
+             |datetime, text_match| {
+                 let position = match text_match.group(1).as_ref() {
+                     "初期" | "初" => Position::Start,
+                     "中期" | "中叶" | "中葉" => Position::Middle,
+                     "末期" | "末" => Position::End,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 datetime.value().part_of_years(position)
+             }
+    );
+
     b.rule_2("<duration> ago",
              duration_check!(),
              b.reg(r#"之?前"#)?,
@@ -686,6 +714,11 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  })
              });
 
+    b.rule_2("below zero <temp>",
+             b.reg(r#"零下"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_below_zero(a.value()));
+
     b.rule_2("<latent temp> degrees",
              temperature_check!(),
              b.reg(r#"度|°"#)?,
@@ -745,6 +778,279 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              }
     );
 
+    b.rule_3("<temp> - <temp> (interval)",
+             temperature_check!(),
+             b.reg(r#"到|至|-|~"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::compose_temperature_interval(a.value(), b.value())
+    );
+
+    Ok(())
+}
+
+
+pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> per cent",
+             number_check!(),
+             b.reg(r#"%|％"#)?,
+             |number, _| Ok(PercentageValue(number.value().value()))
+    );
+    b.rule_2("per cent <number>",
+             b.reg(r#"百分之"#)?,
+             number_check!(),
+             |_, number| Ok(PercentageValue(number.value().value()))
+    );
+    Ok(())
+}
+
+
+fn chinese_digit(digit: &str) -> RuleResult<i64> {
+    match digit {
+        "一" => Ok(1),
+        "二" | "两" | "兩" => Ok(2),
+        "三" => Ok(3),
+        "四" => Ok(4),
+        "五" => Ok(5),
+        "六" => Ok(6),
+        "七" => Ok(7),
+        "八" => Ok(8),
+        "九" => Ok(9),
+        _ => Ok(digit.parse()?),
+    }
+}
+
+// Whether a number of jiao (tenths of a yuan) has no fen left
+fn is_whole_jiao(jiao: f64) -> bool {
+    (jiao - jiao.round()).abs() < 1e-6
+}
+
+pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    // Fractions of a yuan are kept as an integer number of fen (cents) until the yuan and the fen
+    // are composed, so that amounts like "三块二毛五" come out as the exact decimal value.
+    // "分" and "毛" alone are rather minutes or hair, they only count as money after a yuan or
+    // jiao amount or before "钱", and so does a bare digit after an amount ("五块五").
+    b.rule_2("<integer> jiao",
+             integer_check_by_range!(1, 9),
+             b.reg(r#"角"#)?,
+             |a, _| {
+                 Ok(AmountOfMoneyValue {
+                     value: (a.value().value * 10) as f64,
+                     unit: Some("cent"),
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_3("<integer> jiao|fen 钱",
+             integer_check_by_range!(1, 9),
+             b.reg(r#"(毛|分)"#)?,
+             b.reg(r#"钱|錢"#)?,
+             |a, text_match, _| {
+                 let cents = if text_match.group(1) == "毛" { 10 } else { 1 };
+                 Ok(AmountOfMoneyValue {
+                     value: (a.value().value * cents) as f64,
+                     unit: Some("cent"),
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_3("<integer> jiao <digit> (两毛五)",
+             integer_check_by_range!(1, 9),
+             b.reg(r#"角|毛"#)?,
+             b.reg_neg_lh(r#"[1-9一二两兩三四五六七八九]"#, r#"^[^\W钱錢]"#)?,
+             |a, _, digit| {
+                 Ok(AmountOfMoneyValue {
+                     value: (a.value().value * 10 + chinese_digit(digit.group(0).as_ref())?) as f64,
+                     unit: Some("cent"),
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_4("<integer> jiao <integer> fen",
+             integer_check_by_range!(1, 9),
+             b.reg(r#"角|毛"#)?,
+             integer_check_by_range!(1, 9),
+             b.reg(r#"分"#)?,
+             |a, _, b, _| {
+                 Ok(AmountOfMoneyValue {
+                     value: (a.value().value * 10 + b.value().value) as f64,
+                     unit: Some("cent"),
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_3("<yuan> <integer> jiao",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("CNY") && money.value.fract() == 0.0),
+             integer_check_by_range!(1, 9),
+             b.reg(r#"角|毛"#)?,
+             |a, b, _| {
+                 Ok(AmountOfMoneyValue {
+                     value: (a.value().value * 10.0 + b.value().value as f64) / 10.0,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_3("<yuan|jiao> <integer> fen",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| (money.unit == Some("CNY") && is_whole_jiao(money.value * 10.0))
+                 || (money.unit == Some("cent") && is_whole_jiao(money.value / 10.0))),
+             integer_check_by_range!(1, 9),
+             b.reg(r#"分"#)?,
+             |a, b, _| {
+                 let value = if a.value().unit == Some("cent") {
+                     a.value().value + b.value().value as f64
+                 } else {
+                     ((a.value().value * 100.0).round() + b.value().value as f64) / 100.0
+                 };
+                 Ok(AmountOfMoneyValue {
+                     value,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("intersect <yuan> (X cents)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("CNY") && money.value.fract() == 0.0),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent") && money.value < 100.0),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: (a.value().value * 100.0 + b.value().value) / 100.0,
+                     ..a.value().clone()
+                 })
+             });
+    // The digit is the jiao of a whole amount ("五块五"), the fen of an amount with jiao ("三块二毛五")
+    b.rule_2("<yuan> <digit> (五块五)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("CNY") && is_whole_jiao(money.value * 10.0)),
+             b.reg_neg_lh(r#"[1-9一二两兩三四五六七八九]"#, r#"^[^\W钱錢]"#)?,
+             |a, digit| {
+                 let digit = chinese_digit(digit.group(0).as_ref())? as f64;
+                 let value = if a.value().value.fract() == 0.0 {
+                     (a.value().value * 10.0 + digit) / 10.0
+                 } else {
+                     ((a.value().value * 100.0).round() + digit) / 100.0
+                 };
+                 Ok(AmountOfMoneyValue {
+                     value,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<amount-of-money> 钱",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("CNY") || money.unit == Some("cent")),
+             b.reg(r#"钱|錢"#)?,
+             |a, _| Ok(a.value().clone()));
+    b.rule_1_terminal("CNY",
+                      b.reg(r#"人民(?:币|幣)|rmb|cny|元|圆|圓|块|塊|¥|￥"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("CNY") })
+    );
+    b.rule_1_terminal("$",
+                      b.reg(r#"\$"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("$") })
+    );
+    b.rule_1_terminal("USD",
+                      b.reg(r#"美元|美金|usd"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("USD") })
+    );
+    b.rule_1_terminal("EUR",
+                      b.reg(r#"€|欧元|歐元|eur"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("EUR") })
+    );
+    b.rule_1_terminal("£",
+                      b.reg(r#"£"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("£") })
+    );
+    b.rule_1_terminal("GBP",
+                      b.reg(r#"英(?:镑|鎊)|gbp"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("GBP") })
+    );
+    b.rule_1_terminal("JPY",
+                      b.reg(r#"日元|日(?:圆|圓)|円|jpy"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("JPY") })
+    );
+    b.rule_1_terminal("HKD",
+                      b.reg(r#"港(?:币|幣|元)|hkd"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("HKD") })
+    );
+    b.rule_1_terminal("TWD",
+                      b.reg(r#"新?(?:台|臺)(?:币|幣)|twd"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("TWD") })
+    );
+    b.rule_1_terminal("KRW",
+                      b.reg(r#"(?:韩|韓)元|₩|krw"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("KRW") })
+    );
+    b.rule_1_terminal("AUD",
+                      b.reg(r#"澳(?:元|币|幣)|aud"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("AUD") })
+    );
+    b.rule_1_terminal("CAD",
+                      b.reg(r#"加(?:元|币|幣)|cad"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("CAD") })
+    );
+    b.rule_1_terminal("CHF",
+                      b.reg(r#"瑞士法郎|chf"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("CHF") })
+    );
+    b.rule_1_terminal("INR",
+                      b.reg(r#"(?:卢|盧)比|inr"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("INR") })
+    );
+    b.rule_1_terminal("RUB",
+                      b.reg(r#"(?:卢|盧)布|rub"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("RUB") })
+    );
+    b.rule_1_terminal("Bitcoin",
+                      b.reg(r#"比特(?:币|幣)|฿|btc"#)?,
+                      |_| Ok(MoneyUnitValue { unit: Some("฿") })
+    );
+    b.rule_2("<amount> <unit>",
+             number_check!(),
+             money_unit!(),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: a.value().value(),
+                     unit: b.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    // "刀" (knife) only reads as dollars after an amount
+    b.rule_2("<amount> 刀",
+             number_check!(),
+             b.reg(r#"刀"#)?,
+             |a, _| {
+                 Ok(AmountOfMoneyValue {
+                     value: a.value().value(),
+                     unit: Some("$"),
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_2("<unit> <amount>",
+             money_unit!(),
+             number_check!(),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: b.value().value(),
+                     unit: a.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_2("about <amount-of-money>",
+             b.reg(r#"大约|大約|大概|约|約|差不多"#)?,
+             amount_of_money_check!(),
+             |_, a| {
+                 Ok(AmountOfMoneyValue {
+                     precision: Precision::Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<amount-of-money> about",
+             amount_of_money_check!(),
+             b.reg(r#"左右|上下"#)?,
+             |a, _| {
+                 Ok(AmountOfMoneyValue {
+                     precision: Precision::Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <amount-of-money>",
+             b.reg(r#"正好|刚好|剛好|整整"#)?,
+             amount_of_money_check!(),
+             |_, a| {
+                 Ok(AmountOfMoneyValue {
+                     precision: Precision::Exact,
+                     ..a.value().clone()
+                 })
+             });
     Ok(())
 }
 
@@ -787,29 +1093,9 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     });
 
     b.rule_2("numbers prefix with -, negative or minus",
-             b.reg(r#"-|负\s?|負\s?"#)?,
+             b.reg(r#"-|−|负\s?|負\s?"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
-             |_, a| -> RuleResult<NumberValue> {
-                 Ok(match a.value().clone() {
-                     // checked
-                     NumberValue::Integer(integer) => {
-                         IntegerValue {
-                             value: integer.value * -1,
-                             prefixed: true,
-                             ..integer
-                         }
-                             .into()
-                     }
-                     NumberValue::Float(float) => {
-                         FloatValue {
-                             value: float.value * -1.0,
-                             prefixed: true,
-                             ..float
-                         }
-                             .into()
-                     }
-                 })
-             });
+             |_, a| helpers::negate_number(a.value()));
     b.rule_2("numbers prefix with +, positive",
              b.reg(r#"\+"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -833,16 +1119,10 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  })
              }
     );
-    b.rule_1_terminal("decimal with thousands separator",
-                      b.reg(r#"(\d+(,\d\d\d)+\.\d+)"#)?,
-                      |text_match| {
-                          let reformatted_string = text_match.group(1).replace(",", "");
-                          let value: f64 = reformatted_string.parse()?;
-                          Ok(FloatValue {
-                              value: value,
-                              ..FloatValue::default()
-                          })
-                      });
+    b.rule_1_terminal("number with digit grouping",
+                      b.reg(&NUMBER_FORMAT.regex())?,
+                      |text_match| NUMBER_FORMAT.parse(&text_match.group(1))
+    );
 
     b.rule_2("<number>个",
              number_check!(),
@@ -864,40 +1144,44 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg_neg_lh(r#"([kmg])"#, r#"^[^\W\$€元¥(?:人民币)]"#)?,
              |a, text_match| -> RuleResult<NumberValue> {
                  let multiplier = match text_match.group(0).as_ref() {
-                     "k" => 1000,
-                     "m" => 1000000,
-                     "g" => 1000000000,
+                     "k" => 1e3,
+                     "m" => 1e6,
+                     "g" => 1e9,
                      _ => return Err(RuleError::Invalid.into()),
                  };
-                 Ok(match a.value().clone() {
-                     // checked
-                     NumberValue::Integer(integer) => {
-                         IntegerValue {
-                             value: integer.value * multiplier,
-                             suffixed: true,
-                             ..integer
-                         }
-                             .into()
-                     }
-                     NumberValue::Float(float) => {
-                         let product = float.value * (multiplier as f64);
-                         if product.floor() == product {
-                             IntegerValue {
-                                 value: product as i64,
-                                 suffixed: true,
-                                 ..IntegerValue::default()
-                             }
-                                 .into()
-                         } else {
-                             FloatValue {
-                                 value: product,
-                                 suffixed: true,
-                                 ..float
-                             }
-                                 .into()
-                         }
-                     }
-                 })
+                 helpers::compose_magnitude(a.value(), multiplier)
+             });
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d+) ?/ ?(\d+)"#)?,
+                      |text_match| helpers::numeric_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_3("<denominator> 分之 <numerator> (fraction)",
+             integer_check!(|integer: &IntegerValue| !integer.prefixed),
+             b.reg(r#"分之"#)?,
+             integer_check!(|integer: &IntegerValue| !integer.suffixed),
+             |denominator, _, numerator| helpers::fraction(numerator.value().value, denominator.value().value)
+    );
+    b.rule_1_terminal("scientific notation (1e6)",
+                      b.reg(r#"(\d+(?:\.\d+)?)e([+-]?\d{1,2})"#)?,
+                      |text_match| helpers::compose_power(text_match.group(1).parse()?, 10.0, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("power (10^3)",
+                      b.reg(r#"(\d+) ?\^ ?(\d{1,2})"#)?,
+                      |text_match| helpers::compose_power(1.0, text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_2("<number> 千, 万, 亿 (magnitude)",
+             number_check!(|number: &NumberValue| !number.suffixed()),
+             b.reg(r#"(百万|千万|千|万|萬|亿|億)"#)?,
+             |a, text_match| {
+                 let multiplier = match text_match.group(1).as_ref() {
+                     "千" => 1e3,
+                     "万" | "萬" => 1e4,
+                     "百万" => 1e6,
+                     "千万" => 1e7,
+                     "亿" | "億" => 1e8,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::compose_magnitude(a.value(), multiplier)
              });
 
     b.rule_2("integer 21..99",
@@ -910,17 +1194,6 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check_by_range!(1, 9),
              |_, b| IntegerValue::new(10 + b.value().value));
 
-    b.rule_1("integer with thousands separator, ",
-             b.reg(r#"(\d{1,3}(,\d\d\d){1,5})"#)?,
-             |text_match| {
-                 let reformatted_string = text_match.group(1).replace(",", "");
-                 let value: i64 = reformatted_string.parse()?;
-                 Ok(IntegerValue {
-                     value: value,
-                     ..IntegerValue::default()
-                 })
-             });
-
     b.rule_2("ordinal (digits)",
              b.reg(r#"第"#)?,
              integer_check!(),
@@ -0,0 +1,40 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+use rustling_ontology_values::rules_holidays::{Holiday, rules_holidays};
+use rustling_ontology_moment::Grain;
+
+const HOLIDAYS: &[(Holiday, &str)] = &[
+    (Holiday::NewYearsDay, r#"元旦(?:节|節)?"#),
+    (Holiday::ValentinesDay, r#"情人(?:节|節)"#),
+    (Holiday::InternationalWomensDay, r#"(?:妇|婦)女(?:节|節)"#),
+    (Holiday::InternationalWorkersDay, r#"(?:劳|勞)(?:动|動)(?:节|節)"#),
+    (Holiday::ChildrensDay, r#"(?:儿|兒)童(?:节|節)"#),
+    (Holiday::ChineseArmyDay, r#"建(?:军|軍)(?:节|節)"#),
+    (Holiday::ChineseNationalDay, r#"(?:国庆|國慶)(?:节|節)?"#),
+    (Holiday::ChineseMothersDay, r#"母(?:亲|親)(?:节|節)"#),
+    (Holiday::ChineseFathersDay, r#"父(?:亲|親)(?:节|節)"#),
+    (Holiday::Easter, r#"(?:复|復)活(?:节|節)"#),
+    (Holiday::Halloween, r#"(?:万|萬)(?:圣|聖)(?:节|節)"#),
+    (Holiday::Thanksgiving, r#"感恩(?:节|節)"#),
+    (Holiday::ChristmasEve, r#"平安夜"#),
+    (Holiday::Christmas, r#"(?:圣诞|聖誕)(?:节|節)?"#),
+    (Holiday::LunarNewYear, r#"春(?:节|節)|(?:农|農)(?:历|曆)新年|大年初一"#),
+    (Holiday::LanternFestival, r#"元宵(?:节|節)"#),
+    (Holiday::DragonBoatFestival, r#"端午(?:节|節)?"#),
+    (Holiday::Qixi, r#"七夕(?:节|節)?"#),
+    (Holiday::MidAutumnFestival, r#"中秋(?:节|節)?"#),
+    (Holiday::DoubleNinthFestival, r#"重(?:阳|陽)(?:节|節)?"#),
+];
+
+pub fn rules_celebration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rules_holidays(b, HOLIDAYS)?;
+
+    b.rule_1_terminal("chinese new year's eve",
+                      b.reg(r#"除夕|大年(?:夜|三十)"#)?,
+                      |_| Ok(helpers::cycle_nth_after(Grain::Day, -1, &helpers::lunar_month_day(1, 1)?)?
+                          .form(Form::Celebration))
+    );
+
+    Ok(())
+}
@@ -9,6 +9,10 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 1]), "1 小時", "一 小時");
     example!(v, check_duration!([0, 0, 0, 5]), "5 天", "五 天", "五 日");
     example!(v, check_duration!([0, 10]), "10 月", "十 月");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 250]), "250ms", "250毫秒");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 500]), "0.5秒");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 1, 23, 450]), "1:23.45");
+    example!(v, check_duration_interval!([0, 0, 0, 0, 2], [0, 0, 0, 0, 3]), "2 到 3 小时", "2 小时 到 3 小时");
 }
 
 
@@ -95,8 +99,26 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 6, 1]), "儿童节", "兒童節");
     example!(v, check_moment!(c, [2013, 10, 1]), "国庆", "國慶", "国庆节", "國慶節");
     example!(v, check_moment!(c, [2013, 12, 25]), "圣诞", "聖誕", "圣诞节", "聖誕節");
+    example!(v, check_moment!(c, [2014, 1, 31]), "春节", "春節", "农历新年", "農曆新年");
+    example!(v, check_moment!(c, [2014, 1, 30]), "除夕", "大年夜");
+    example!(v, check_moment!(c, [2013, 2, 24]), "元宵节", "元宵節");
+    example!(v, check_moment!(c, [2013, 6, 12]), "端午节", "端午節");
+    example!(v, check_moment!(c, [2013, 9, 19]), "中秋", "中秋节", "中秋節");
+    example!(v, check_moment!(c, [2013, 10, 13]), "重阳节", "重陽節");
+    example!(v, check_moment!(c, [2013, 8, 13]), "七夕", "七夕节");
+    example!(v, check_moment!(c, [2013, 3, 31]), "复活节", "復活節");
+    example!(v, check_moment!(c, [2013, 5, 12]), "母亲节", "母親節");
+    example!(v, check_moment!(c, [2013, 6, 16]), "父亲节", "父親節");
+    example!(v, check_moment!(c, [2013, 10, 31]), "万圣节", "萬聖節");
+    example!(v, check_moment!(c, [2013, 11, 28]), "感恩节", "感恩節");
+    example!(v, check_moment!(c, [2013, 12, 24]), "平安夜");
     example!(v, check_moment_span!(c, [2013, 10, 1, 18], [2013, 10, 2, 0]), "国庆节晚上", "國慶節晚上");
     example!(v, check_moment!(c, [2013, 6, 1, 15, 15]), "儿童节下午三点十五", "兒童節下午三點十五");
+    example!(v, check_moment_span!(c, [1990], [2000]), "90年代");
+    example!(v, check_moment_span!(c, [1900], [2000]), "20世纪", "二十世纪");
+    example!(v, check_moment_span!(c, [1970], [2000]), "20世纪末");
+    example!(v, check_moment!(c, [2013, 1, 1], Grain::Semester), "上半年");
+    example!(v, check_moment!(c, [2024, 7, 1], Grain::Semester), "2024年下半年");
 }
 
 
@@ -105,6 +127,30 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(45.0, Some("degree")), "45°", "45度");
     example!(v, check_temperature(50.0, Some("fahrenheit")), "50°F");
     example!(v, check_temperature(23.0, Some("celsius")), "23°C");
+    example!(v, check_temperature(-10.0, Some("degree")), "零下10度", "零下十度");
+    example!(v, check_temperature_interval(20.0, 25.0, Some("celsius")), "20到25°C", "20-25°C");
+}
+
+
+pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance(30.0, Some("CNY"), Precision::Exact), "三十块钱", "三十塊錢", "三十块", "30元", "三十元", "30rmb", "¥30", "30人民币");
+    example!(v, check_finance(5.5, Some("CNY"), Precision::Exact), "五块五", "五块五毛", "五元五角", "5块5", "5.5元");
+    example!(v, check_finance(3.25, Some("CNY"), Precision::Exact), "三块二毛五", "三元二角五分", "三块二毛五分", "3.25元");
+    example!(v, check_finance(50.0, Some("cent"), Precision::Exact), "五毛钱", "五角");
+    example!(v, check_finance(25.0, Some("cent"), Precision::Exact), "两毛五", "两毛五分", "二角五分");
+    example!(v, check_finance(5.0, Some("cent"), Precision::Exact), "五分钱");
+    example!(v, check_finance(10.0, Some("USD"), Precision::Exact), "10美元", "十美元", "十美金");
+    example!(v, check_finance(20.0, Some("$"), Precision::Exact), "$20", "20$", "二十刀", "20刀");
+    example!(v, check_finance(45.0, Some("EUR"), Precision::Approximate), "大约45欧元", "45欧元左右", "大概四十五歐元");
+    example!(v, check_finance(20.0, Some("GBP"), Precision::Exact), "正好二十英镑", "刚好20英鎊");
+    example!(v, check_finance(100.0, Some("JPY"), Precision::Exact), "100日元");
+    example!(v, check_finance(10000.0, Some("HKD"), Precision::Exact), "一万港币", "1万港元");
+}
+
+
+pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_percentage(20.0), "百分之二十", "百分之20", "20%", "二十%", "20％");
+    example!(v, check_percentage(0.5), "百分之0.5", "0.5%");
 }
 
 
@@ -122,6 +168,8 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(10), "10", "十");
     example!(v, check_float(1.1), "1.1", "1.10", "01.10");
     example!(v, check_float(0.77), "0.77", ".77");
+    example!(v, check_fraction(1, 3), "三分之一", "3分之1");
+    example!(v, check_fraction(3, 4), "四分之三");
     example!(v, check_integer(20), "20", "二十");
     example!(v, check_integer(30), "30", "三十");
     example!(v, check_integer(40), "40", "四十");
@@ -148,4 +196,8 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(7), "第七");
     example!(v, check_ordinal(11), "第十一");
     example!(v, check_ordinal(91), "第九十一");
+    example!(v, check_integer(15000), "1.5万");
+    example!(v, check_integer(200000000), "2亿");
+    example!(v, check_integer(1000000), "1e6", "10^6");
+    example!(v, check_integer(-5), "负5", "−5");
 }
@@ -103,6 +103,8 @@ pub enum SlotValue {
     AmountOfMoney(AmountOfMoneyValue),
     Temperature(TemperatureValue),
     Duration(DurationValue),
+    TemperatureInterval(TemperatureIntervalValue),
+    DurationInterval(DurationIntervalValue),
 }
 
 impl From<Output> for SlotValue {
@@ -110,9 +112,29 @@ impl From<Output> for SlotValue {
         match o {
             Output::Integer(int) => SlotValue::Number(NumberValue {
                 value: (int.0 as f64).into(),
+                fraction: None,
+                fuzzy_range: None,
+            }),
+            Output::FuzzyInteger(int) => SlotValue::Number(NumberValue {
+                value: int.value as f64,
+                fraction: None,
+                fuzzy_range: Some(FuzzyRangeValue {
+                    min: int.min as f64,
+                    max: int.max as f64,
+                }),
             }),
             Output::Float(float) => SlotValue::Number(NumberValue {
                 value: float.0.into(),
+                fraction: None,
+                fuzzy_range: None,
+            }),
+            Output::Fraction(fraction) => SlotValue::Number(NumberValue {
+                value: fraction.value.into(),
+                fraction: Some(FractionValue {
+                    numerator: fraction.numerator,
+                    denominator: fraction.denominator,
+                }),
+                fuzzy_range: None,
             }),
             Output::Ordinal(ordinal) => SlotValue::Ordinal(OrdinalValue {
                 value: ordinal.0 as i64,
@@ -149,26 +171,56 @@ impl From<Output> for SlotValue {
                 value: amount.value,
                 precision: amount.precision.into(),
                 unit: amount.unit.map(|it| it.to_string()),
+                fuzzy_range: amount
+                    .fuzzy_range
+                    .map(|(min, max)| FuzzyRangeValue { min, max }),
             }),
             Output::Temperature(temperature) => SlotValue::Temperature(TemperatureValue {
                 value: temperature.value,
                 unit: temperature.unit.map(|it| it.to_string()),
             }),
             Output::Duration(duration) => SlotValue::Duration(DurationValue {
-                years: *duration.period.0.get(Grain::Year as usize).unwrap_or(&0),
-                quarters: *duration.period.0.get(Grain::Quarter as usize).unwrap_or(&0),
-                months: *duration.period.0.get(Grain::Month as usize).unwrap_or(&0),
-                weeks: *duration.period.0.get(Grain::Week as usize).unwrap_or(&0),
-                days: *duration.period.0.get(Grain::Day as usize).unwrap_or(&0),
-                hours: *duration.period.0.get(Grain::Hour as usize).unwrap_or(&0),
-                minutes: *duration.period.0.get(Grain::Minute as usize).unwrap_or(&0),
-                seconds: *duration.period.0.get(Grain::Second as usize).unwrap_or(&0),
-                precision: duration.precision.into(),
+                fuzzy_range: duration.fuzzy_range.as_ref().map(|&(ref min, ref max)| {
+                    Box::new(DurationIntervalValue {
+                        min: duration_value(min, duration.precision),
+                        max: duration_value(max, duration.precision),
+                    })
+                }),
+                ..duration_value(&duration.period, duration.precision)
             }),
+            Output::TemperatureInterval(interval) => {
+                SlotValue::TemperatureInterval(TemperatureIntervalValue {
+                    min: interval.min,
+                    max: interval.max,
+                    unit: interval.unit.map(|it| it.to_string()),
+                })
+            }
+            Output::DurationInterval(interval) => {
+                SlotValue::DurationInterval(DurationIntervalValue {
+                    min: duration_value(&interval.min, interval.precision),
+                    max: duration_value(&interval.max, interval.precision),
+                })
+            }
         }
     }
 }
 
+fn duration_value(period: &moment::Period, precision: dimension::Precision) -> DurationValue {
+    DurationValue {
+        years: *period.0.get(Grain::Year as usize).unwrap_or(&0),
+        quarters: *period.0.get(Grain::Quarter as usize).unwrap_or(&0),
+        months: *period.0.get(Grain::Month as usize).unwrap_or(&0),
+        weeks: *period.0.get(Grain::Week as usize).unwrap_or(&0),
+        days: *period.0.get(Grain::Day as usize).unwrap_or(&0),
+        hours: *period.0.get(Grain::Hour as usize).unwrap_or(&0),
+        minutes: *period.0.get(Grain::Minute as usize).unwrap_or(&0),
+        seconds: *period.0.get(Grain::Second as usize).unwrap_or(&0),
+        milliseconds: *period.0.get(Grain::Millisecond as usize).unwrap_or(&0),
+        precision: precision.into(),
+        fuzzy_range: None,
+    }
+}
+
 fn nearly_equal_f64(a: f64, b: f64) -> bool {
     let abs_a = a.abs();
     let abs_b = b.abs();
@@ -189,8 +241,27 @@ fn nearly_equal_f64(a: f64, b: f64) -> bool {
 #[derive(Serialize, Deserialize, Clone, Copy, Debug)]
 pub struct NumberValue {
     pub value: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fraction: Option<FractionValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fuzzy_range: Option<FuzzyRangeValue>,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Copy, Debug)]
+pub struct FractionValue {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+/// Range covered by a fuzzy quantifier, e.g. "a few"
+#[derive(Serialize, Deserialize, Clone, PartialEq, Copy, Debug)]
+pub struct FuzzyRangeValue {
+    pub min: f64,
+    pub max: f64,
+}
+
+// The fraction and the fuzzy range are extra information on the value, two numbers with the
+// same value are equal
 impl PartialEq for NumberValue {
     fn eq(&self, other: &NumberValue) -> bool {
         nearly_equal_f64(self.value, other.value)
@@ -234,6 +305,8 @@ pub struct AmountOfMoneyValue {
     pub value: f64,
     pub precision: Precision,
     pub unit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fuzzy_range: Option<FuzzyRangeValue>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -252,7 +325,24 @@ pub struct DurationValue {
     pub hours: i64,
     pub minutes: i64,
     pub seconds: i64,
+    #[serde(default)]
+    pub milliseconds: i64,
     pub precision: Precision,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fuzzy_range: Option<Box<DurationIntervalValue>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct TemperatureIntervalValue {
+    pub min: f64,
+    pub max: f64,
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DurationIntervalValue {
+    pub min: DurationValue,
+    pub max: DurationValue,
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Hash)]
@@ -265,12 +355,15 @@ pub enum Grain {
     Hour = 5,
     Minute = 6,
     Second = 7,
+    Millisecond = 8,
+    Semester = 9,
 }
 
 impl From<moment::Grain> for Grain {
     fn from(o: moment::Grain) -> Grain {
         match o {
             moment::Grain::Year => Grain::Year,
+            moment::Grain::Semester => Grain::Semester,
             moment::Grain::Quarter => Grain::Quarter,
             moment::Grain::Month => Grain::Month,
             moment::Grain::Week => Grain::Week,
@@ -278,6 +371,7 @@ impl From<moment::Grain> for Grain {
             moment::Grain::Hour => Grain::Hour,
             moment::Grain::Minute => Grain::Minute,
             moment::Grain::Second => Grain::Second,
+            moment::Grain::Millisecond => Grain::Millisecond,
         }
     }
 }
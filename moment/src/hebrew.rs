@@ -0,0 +1,209 @@
+//! Arithmetic Hebrew calendar.
+//!
+//! Months are numbered from Nisan (1) to Adar (12), Adar II (13) only existing
+//! in leap years, while years start on the 1st of Tishri (7).
+
+use chrono::{Datelike, NaiveDate};
+
+/// Days from the Common Era of the 1st of Tishri, year 1 (October 7th, 3761
+/// BCE in the Julian calendar).
+const EPOCH: i32 = -1_373_427;
+
+pub const NISAN: u32 = 1;
+pub const TISHRI: u32 = 7;
+pub const KISLEV: u32 = 9;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HebrewDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl HebrewDate {
+    pub fn new(year: i32, month: u32, day: u32) -> HebrewDate {
+        HebrewDate { year, month, day }
+    }
+}
+
+/// Leap years, which have a 13th month, are the 3rd, 6th, 8th, 11th, 14th,
+/// 17th and 19th of each 19 years cycle.
+pub fn is_leap_year(year: i32) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+fn last_month_of_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        13
+    } else {
+        12
+    }
+}
+
+/// Days elapsed from the epoch to the molad of Tishri, postponed by a day when
+/// it falls on a Sunday, Wednesday or Friday.
+fn elapsed_days(year: i64) -> i64 {
+    let months_elapsed = (235 * year - 234).div_euclid(19);
+    let parts_elapsed = 12_084 + 13_753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25_920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+/// Further postponements keeping the length of a year within the allowed
+/// values.
+fn year_length_correction(year: i64) -> i64 {
+    let (previous, current, next) = (
+        elapsed_days(year - 1),
+        elapsed_days(year),
+        elapsed_days(year + 1),
+    );
+    if next - current == 356 {
+        2
+    } else if current - previous == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+/// Days from the Common Era of the 1st of Tishri of a year.
+fn new_year(year: i32) -> i32 {
+    let year = year as i64;
+    (EPOCH as i64 + elapsed_days(year) + year_length_correction(year)) as i32
+}
+
+fn year_length(year: i32) -> i32 {
+    new_year(year + 1) - new_year(year)
+}
+
+/// Number of days (29 or 30) of a Hebrew month.
+pub fn month_length(year: i32, month: u32) -> Option<u32> {
+    if month < 1 || month > last_month_of_year(year) {
+        return None;
+    }
+    let length = year_length(year);
+    let is_short = match month {
+        2 | 4 | 6 | 10 | 13 => true,
+        12 => !is_leap_year(year),
+        // Heshvan is long in complete years, Kislev is short in deficient ones
+        8 => length != 355 && length != 385,
+        9 => length == 353 || length == 383,
+        _ => false,
+    };
+    Some(if is_short { 29 } else { 30 })
+}
+
+/// Days from the Common Era of a Hebrew date.
+fn days_from_ce(date: HebrewDate) -> i32 {
+    let months_before: Vec<u32> = if date.month < TISHRI {
+        (TISHRI..=last_month_of_year(date.year))
+            .chain(1..date.month)
+            .collect()
+    } else {
+        (TISHRI..date.month).collect()
+    };
+    let days_before: i32 = months_before
+        .into_iter()
+        .filter_map(|m| month_length(date.year, m))
+        .map(|length| length as i32)
+        .sum();
+    new_year(date.year) + days_before + date.day as i32 - 1
+}
+
+/// Converts a Hebrew date to the Gregorian calendar, `None` if the date does
+/// not exist.
+pub fn to_gregorian(date: HebrewDate) -> Option<NaiveDate> {
+    let length = month_length(date.year, date.month)?;
+    if date.year < 1 || date.day < 1 || date.day > length {
+        return None;
+    }
+    NaiveDate::from_num_days_from_ce_opt(days_from_ce(date))
+}
+
+/// Converts a Gregorian date to the Hebrew calendar, `None` if it precedes the
+/// epoch.
+pub fn from_gregorian(date: NaiveDate) -> Option<HebrewDate> {
+    let days = date.num_days_from_ce();
+    if days < EPOCH {
+        return None;
+    }
+    // Mean year of 35975351 / 98496 days
+    let mut year = ((days - EPOCH) as i64 * 98_496 / 35_975_351) as i32;
+    while new_year(year + 1) <= days {
+        year += 1;
+    }
+    let first_month = if days < days_from_ce(HebrewDate::new(year, NISAN, 1)) {
+        TISHRI
+    } else {
+        NISAN
+    };
+    let month_start = |month| days_from_ce(HebrewDate::new(year, month, 1));
+    let mut month = first_month;
+    while days >= month_start(month) + month_length(year, month)? as i32 {
+        month += 1;
+    }
+    Some(HebrewDate {
+        year,
+        month,
+        day: (days - month_start(month)) as u32 + 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_leap_years() {
+        let leap_years: Vec<i32> = (1..20).filter(|&y| is_leap_year(y)).collect();
+        assert_eq!(vec![3, 6, 8, 11, 14, 17, 19], leap_years);
+        assert!(is_leap_year(5784));
+        assert!(!is_leap_year(5785));
+    }
+
+    #[test]
+    fn test_to_gregorian() {
+        // Passover 5773, Rosh Hashanah, Yom Kippur and Hanukkah 5774
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2013, 3, 26)),
+            to_gregorian(HebrewDate::new(5773, NISAN, 15))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2013, 9, 5)),
+            to_gregorian(HebrewDate::new(5774, TISHRI, 1))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2013, 9, 14)),
+            to_gregorian(HebrewDate::new(5774, TISHRI, 10))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2013, 11, 28)),
+            to_gregorian(HebrewDate::new(5774, KISLEV, 25))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2023, 9, 16)),
+            to_gregorian(HebrewDate::new(5784, TISHRI, 1))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2025, 4, 13)),
+            to_gregorian(HebrewDate::new(5785, NISAN, 15))
+        );
+        // Adar II only exists in leap years
+        assert_eq!(None, to_gregorian(HebrewDate::new(5785, 13, 1)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut date = NaiveDate::from_ymd(1900, 1, 1);
+        while date < NaiveDate::from_ymd(2100, 1, 1) {
+            let hebrew = from_gregorian(date).unwrap();
+            assert_eq!(Some(date), to_gregorian(hebrew));
+            date += Duration::days(1);
+        }
+    }
+}
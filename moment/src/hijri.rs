@@ -0,0 +1,138 @@
+//! Tabular Islamic (Hijri) calendar.
+//!
+//! The arithmetical calendar follows 30 years cycles of 11 leap years (the
+//! 2nd, 5th, 7th, 10th, 13th, 16th, 18th, 21st, 24th, 26th and 29th), odd
+//! months being 30 days long and even months 29 days, except the 12th month
+//! of leap years. It may differ by a day or two from the calendars based on
+//! the sighting of the moon.
+
+use chrono::{Datelike, NaiveDate};
+
+/// Days from the Common Era of the 1st of Muharram, year 1 (July 19th, 622).
+const EPOCH: i32 = 227_015;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HijriDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl HijriDate {
+    pub fn new(year: i32, month: u32, day: u32) -> HijriDate {
+        HijriDate { year, month, day }
+    }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+/// Number of days (29 or 30) of a Hijri month.
+pub fn month_length(year: i32, month: u32) -> Option<u32> {
+    match month {
+        12 if is_leap_year(year) => Some(30),
+        1..=12 if month % 2 == 1 => Some(30),
+        1..=12 => Some(29),
+        _ => None,
+    }
+}
+
+/// Days elapsed from the epoch to the first day of a Hijri year.
+fn days_before_year(year: i32) -> i32 {
+    (year - 1) * 354 + (3 + 11 * year).div_euclid(30)
+}
+
+/// Days elapsed from the first day of a Hijri year to the first day of a month.
+fn days_before_month(month: u32) -> i32 {
+    // Months alternate between 30 and 29 days
+    (59 * (month as i32 - 1) + 1) / 2
+}
+
+/// Converts a Hijri date to the Gregorian calendar, `None` if the date does
+/// not exist or precedes the epoch.
+pub fn to_gregorian(date: HijriDate) -> Option<NaiveDate> {
+    let length = month_length(date.year, date.month)?;
+    if date.year < 1 || date.day < 1 || date.day > length {
+        return None;
+    }
+    let days = days_before_year(date.year) + days_before_month(date.month) + date.day as i32 - 1;
+    NaiveDate::from_num_days_from_ce_opt(EPOCH + days)
+}
+
+/// Converts a Gregorian date to the Hijri calendar, `None` if it precedes the
+/// epoch.
+pub fn from_gregorian(date: NaiveDate) -> Option<HijriDate> {
+    let days = date.num_days_from_ce() - EPOCH;
+    if days < 0 {
+        return None;
+    }
+    let mut year = (30 * days + 10_646) / 10_631;
+    while days_before_year(year + 1) <= days {
+        year += 1;
+    }
+    while days_before_year(year) > days {
+        year -= 1;
+    }
+    let day_of_year = days - days_before_year(year);
+    let month = (1..=12)
+        .rev()
+        .find(|&m| days_before_month(m) <= day_of_year)
+        .unwrap_or(1);
+    Some(HijriDate {
+        year,
+        month,
+        day: (day_of_year - days_before_month(month)) as u32 + 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_leap_years() {
+        let leap_years: Vec<i32> = (1..31).filter(|&y| is_leap_year(y)).collect();
+        assert_eq!(vec![2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29], leap_years);
+        assert!(is_leap_year(1434));
+        assert!(!is_leap_year(1435));
+    }
+
+    #[test]
+    fn test_to_gregorian() {
+        assert_eq!(
+            Some(NaiveDate::from_ymd(622, 7, 19)),
+            to_gregorian(HijriDate::new(1, 1, 1))
+        );
+        // Ramadan, Eid al-Fitr and Eid al-Adha 1434
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2013, 7, 9)),
+            to_gregorian(HijriDate::new(1434, 9, 1))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2013, 8, 8)),
+            to_gregorian(HijriDate::new(1434, 10, 1))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2013, 10, 15)),
+            to_gregorian(HijriDate::new(1434, 12, 10))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2013, 11, 5)),
+            to_gregorian(HijriDate::new(1435, 1, 1))
+        );
+        assert_eq!(None, to_gregorian(HijriDate::new(1435, 12, 30)));
+        assert_eq!(None, to_gregorian(HijriDate::new(1435, 13, 1)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut date = NaiveDate::from_ymd(1900, 1, 1);
+        while date < NaiveDate::from_ymd(2100, 1, 1) {
+            let hijri = from_gregorian(date).unwrap();
+            assert_eq!(Some(date), to_gregorian(hijri));
+            date += Duration::days(1);
+        }
+    }
+}
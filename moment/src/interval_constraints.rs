@@ -1,17 +1,79 @@
 use crate::bidirectional_walker::*;
+use crate::hebrew::{self, HebrewDate};
+use crate::hijri::{self, HijriDate};
+use crate::lunisolar::{self, LunarDate};
 use crate::period::*;
 use crate::walker::*;
 use crate::{last_day_in_month, Interval, Moment, MomentError, MomentResult};
-use chrono::{Datelike, Local, TimeZone, Timelike, Weekday};
+use chrono::{Datelike, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone, Timelike, Weekday};
 use std::fmt;
 use std::ops;
 use std::rc::Rc;
 
+/// Computus dating Easter, and therefore the celebrations derived from it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Computus {
+    /// Gregorian computus of the Western churches
+    Western,
+    /// Julian computus of the Orthodox churches
+    Orthodox,
+}
+
+impl Default for Computus {
+    fn default() -> Computus {
+        Computus::Western
+    }
+}
+
+/// Date a holiday resolves to when it falls on a weekend.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HolidayDate {
+    /// The calendar date of the holiday
+    Nominal,
+    /// The weekday the holiday is moved to, see `Observance`
+    Observed,
+}
+
+impl Default for HolidayDate {
+    fn default() -> HolidayDate {
+        HolidayDate::Nominal
+    }
+}
+
+/// Rule moving a holiday off the weekend.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Observance {
+    /// Saturday to the Friday before, Sunday to the Monday after (US federal holidays)
+    NearestWeekday,
+    /// Saturday and Sunday to the Monday after (UK bank holidays)
+    NextMonday,
+    /// Sunday to the Monday after, Saturday kept
+    SundayToMonday,
+}
+
+impl Observance {
+    /// Days to add to a holiday falling on `weekday` to get the observed day.
+    pub fn shift(&self, weekday: Weekday) -> i64 {
+        match (*self, weekday) {
+            (Observance::NearestWeekday, Weekday::Sat) => -1,
+            (Observance::NearestWeekday, Weekday::Sun) => 1,
+            (Observance::NextMonday, Weekday::Sat) => 2,
+            (Observance::NextMonday, Weekday::Sun) => 1,
+            (Observance::SundayToMonday, Weekday::Sun) => 1,
+            _ => 0,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, new)]
 pub struct Context<T: TimeZone> {
     pub reference: Interval<T>,
     pub min: Interval<T>,
     pub max: Interval<T>,
+    #[new(default)]
+    pub computus: Computus,
+    #[new(default)]
+    pub holiday_date: HolidayDate,
 }
 
 impl<T: TimeZone> Copy for Context<T> where <T as TimeZone>::Offset: Copy {}
@@ -20,8 +82,8 @@ impl<T: TimeZone> fmt::Debug for Context<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "Context {{ reference: {:?}, min: {:?}, max: {:?} }}",
-            self.reference, self.min, self.max
+            "Context {{ reference: {:?}, min: {:?}, max: {:?}, computus: {:?}, holiday_date: {:?} }}",
+            self.reference, self.min, self.max, self.computus, self.holiday_date
         )
     }
 }
@@ -65,6 +127,17 @@ where
         );
         Context::new(now, min_interval, max_interval)
     }
+
+    pub fn with_computus(self, computus: Computus) -> Context<T> {
+        Context { computus, ..self }
+    }
+
+    pub fn with_holiday_date(self, holiday_date: HolidayDate) -> Context<T> {
+        Context {
+            holiday_date,
+            ..self
+        }
+    }
 }
 
 pub type IntervalWalker<T> = BidirectionalWalker<Interval<T>>;
@@ -147,6 +220,28 @@ where
         Translate::new(self, Rc::new(offset))
     }
 
+    /// Holiday moved off the weekend per `observance` when the context asks for
+    /// observed dates.
+    pub fn observed(&self, observance: Observance) -> RcConstraint<T> {
+        self.observed_as(observance, None)
+    }
+
+    /// Same as `observed`, `holiday_date` overriding the one of the context if given.
+    pub fn observed_as(
+        &self,
+        observance: Observance,
+        holiday_date: Option<HolidayDate>,
+    ) -> RcConstraint<T> {
+        self.translate_with(move |i: &Interval<T>, context: &Context<T>| {
+            match holiday_date.unwrap_or(context.holiday_date) {
+                HolidayDate::Nominal => Some(*i),
+                HolidayDate::Observed => {
+                    Some(*i + PeriodComp::days(observance.shift(i.start.0.weekday())))
+                }
+            }
+        })
+    }
+
     pub fn take_the_nth(&self, n: i64) -> RcConstraint<T> {
         TakeTheNth::new(n, false, self)
     }
@@ -176,7 +271,11 @@ where
     }
 
     pub fn last_of(&self, inner: &RcConstraint<T>) -> RcConstraint<T> {
-        TakeLastOf::new(inner, self)
+        TakeLastOf::new(0, inner, self)
+    }
+
+    pub fn nth_last_of(&self, n: i64, inner: &RcConstraint<T>) -> RcConstraint<T> {
+        TakeLastOf::new(n, inner, self)
     }
 
     pub fn the_nth(&self, n: i64) -> NthConstraint<T> {
@@ -236,6 +335,125 @@ where
     }
 }
 
+/// A span of consecutive years, e.g. a decade or a century
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Years {
+    pub start: i32,
+    pub count: i32,
+}
+
+impl Years {
+    pub fn new<T: TimeZone>(start: i32, count: i32) -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(Years { start, count })
+    }
+}
+
+impl<T: TimeZone> IntervalConstraint<T> for Years
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
+        // Unlike a single year, an explicit span like "the 19th century" or "the thirties"
+        // is not bounded by the context, which only reaches back to 1970
+        let end_year = self.start + self.count;
+        if self.count <= 0 || self.start < 1 {
+            return BidirectionalWalker::new();
+        }
+        let interval = Interval {
+            start: Moment(origin.timezone().ymd(self.start, 1, 1).and_hms(0, 0, 0)),
+            end: Some(Moment(origin.timezone().ymd(end_year, 1, 1).and_hms(0, 0, 0))),
+            grain: Grain::Year,
+        };
+        // The current decade is a forward value, like the current year
+        if origin.start.year() < end_year {
+            BidirectionalWalker::new().forward_values(vec![interval])
+        } else {
+            BidirectionalWalker::new().backward_values(vec![interval])
+        }
+    }
+}
+
+/// Years of the most recent decade named by its last two digits, e.g. 1990-1999 for "the 90s"
+/// or 1920-1929 for "the 20s" in 2013, narrowed to `count` years from `offset` for "the late 90s"
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct YearsOfRecentDecade {
+    pub decade: i32,
+    pub offset: i32,
+    pub count: i32,
+}
+
+impl YearsOfRecentDecade {
+    pub fn new<T: TimeZone>(decade: i32, offset: i32, count: i32) -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(YearsOfRecentDecade { decade, offset, count })
+    }
+
+    fn years(&self, origin_year: i32) -> Years {
+        let mut start = origin_year - origin_year.rem_euclid(100) + self.decade;
+        if start > origin_year {
+            start -= 100;
+        }
+        Years {
+            start: start + self.offset,
+            count: self.count,
+        }
+    }
+}
+
+impl<T: TimeZone> IntervalConstraint<T> for YearsOfRecentDecade
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        self.years(origin.start.year()).to_walker(origin, context)
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct YearMonthDay {
     pub year: i32,
@@ -419,7 +637,588 @@ where
                     origin_copied.timezone(),
                 )
         })
-        .map(move |interval| interval + PeriodComp::days(day_of_month as i64 - 1));
+        .map(move |interval| interval + PeriodComp::days(day_of_month as i64 - 1));
+
+        BidirectionalWalker::new()
+            .forward(forward_walker)
+            .backward(backward_walker)
+    }
+}
+
+/// Walks the dates yielded by `date_of` for each year of `years`, given in
+/// chronological order, within the boundaries of the context. This is how the
+/// constraints on non-Gregorian calendars are resolved.
+fn calendar_walker<T, F>(
+    origin: &Interval<T>,
+    context: &Context<T>,
+    years: ops::RangeInclusive<i32>,
+    date_of: F,
+) -> IntervalWalker<T>
+where
+    T: TimeZone,
+    <T as TimeZone>::Offset: Copy,
+    F: Fn(i32) -> Option<NaiveDate>,
+{
+    let tz = origin.timezone();
+    let (forward, mut backward): (Vec<_>, Vec<_>) = years
+        .filter_map(date_of)
+        .filter_map(|date| {
+            tz.ymd_opt(date.year(), date.month(), date.day())
+                .single()
+                .map(|day| Interval::starting_at(Moment(day.and_hms(0, 0, 0)), Grain::Day))
+        })
+        .filter(|i| i.start >= context.min.start && i.end_moment() <= context.max.end_moment())
+        .partition(|i| i.end_moment() > origin.start);
+    backward.reverse();
+    BidirectionalWalker::new()
+        .forward_values(forward)
+        .backward_values(backward)
+}
+
+/// Day of the Chinese lunisolar calendar, e.g. `LunarMonthDay(8, 15)` for the
+/// Mid-Autumn festival. Leap months are never matched.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LunarMonthDay(pub u32, pub u32);
+
+impl LunarMonthDay {
+    pub fn new<T: TimeZone + 'static>(m: u32, d: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = LunarMonthDay(m, d);
+        if (1..=12).contains(&m) && (1..=30).contains(&d) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for LunarMonthDay
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let LunarMonthDay(month, day) = *self;
+        // A lunar year starts between January 21st and February 20th
+        let years = (context.min.start.year() - 1)..=context.max.start.year();
+        calendar_walker(origin, context, years, |year| {
+            lunisolar::to_gregorian(LunarDate::new(year, month, day))
+        })
+    }
+}
+
+/// Day of the tabular Hijri calendar, e.g. `HijriMonthDay(10, 1)` for Eid
+/// al-Fitr.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HijriMonthDay(pub u32, pub u32);
+
+impl HijriMonthDay {
+    pub fn new<T: TimeZone + 'static>(m: u32, d: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = HijriMonthDay(m, d);
+        if (1..=12).contains(&m) && (1..=30).contains(&d) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for HijriMonthDay
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let HijriMonthDay(month, day) = *self;
+        let hijri_year = |year: i32| {
+            hijri::from_gregorian(NaiveDate::from_ymd(year, 1, 1)).map(|date| date.year)
+        };
+        let years = match (
+            hijri_year(context.min.start.year()),
+            hijri_year(context.max.start.year() + 1),
+        ) {
+            (Some(first), Some(last)) => first..=last,
+            _ => return BidirectionalWalker::new(),
+        };
+        calendar_walker(origin, context, years, |year| {
+            hijri::to_gregorian(HijriDate::new(year, month, day))
+        })
+    }
+}
+
+/// Day of the Hebrew calendar, e.g. `HebrewMonthDay(7, 10)` for Yom Kippur,
+/// months being numbered from Nisan.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HebrewMonthDay(pub u32, pub u32);
+
+impl HebrewMonthDay {
+    pub fn new<T: TimeZone + 'static>(m: u32, d: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = HebrewMonthDay(m, d);
+        if (1..=13).contains(&m) && (1..=30).contains(&d) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for HebrewMonthDay
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let HebrewMonthDay(month, day) = *self;
+        // The Hebrew year starts in September or October
+        let years = (context.min.start.year() + 3760)..=(context.max.start.year() + 3761);
+        calendar_walker(origin, context, years, |year| {
+            hebrew::to_gregorian(HebrewDate::new(year, month, day))
+        })
+    }
+}
+
+/// Nth weekday of a month, counted from the end of the month if `n` is negative,
+/// e.g. `NthWeekdayOfMonth::new(4, Weekday::Thu, 11)` for Thanksgiving.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NthWeekdayOfMonth {
+    pub n: i64,
+    pub weekday: Weekday,
+    pub month: u32,
+}
+
+impl NthWeekdayOfMonth {
+    pub fn new<T: TimeZone + 'static>(
+        n: i64,
+        weekday: Weekday,
+        month: u32,
+    ) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = NthWeekdayOfMonth { n, weekday, month };
+        if n != 0 && (-5..=5).contains(&n) && is_valid_month(month) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+
+    fn date_of(&self, year: i32) -> Option<NaiveDate> {
+        let weekday = i64::from(self.weekday.num_days_from_monday());
+        let day = if self.n > 0 {
+            let first = NaiveDate::from_ymd_opt(year, self.month, 1)?;
+            let first_weekday = i64::from(first.weekday().num_days_from_monday());
+            1 + (weekday - first_weekday + 7) % 7 + 7 * (self.n - 1)
+        } else {
+            let next_month =
+                NaiveDate::from_ymd_opt(year + (self.month / 12) as i32, self.month % 12 + 1, 1)?;
+            let last = next_month.pred();
+            let last_weekday = i64::from(last.weekday().num_days_from_monday());
+            i64::from(last.day()) - (last_weekday - weekday + 7) % 7 + 7 * (self.n + 1)
+        };
+        if day < 1 {
+            return None;
+        }
+        NaiveDate::from_ymd_opt(year, self.month, day as u32)
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for NthWeekdayOfMonth
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let years = context.min.start.year()..=context.max.start.year();
+        calendar_walker(origin, context, years, |year| self.date_of(year))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Month(pub u32);
+
+impl Month {
+    pub fn new<T: TimeZone + 'static>(m: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = Month(m);
+        if is_valid_month(m) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+
+    pub fn new_unchecked<T: TimeZone + 'static>(m: u32) -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(Month(m))
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for Month
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Month
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Month
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Month
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Month
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
+        if !is_valid_month(self.0) {
+            return BidirectionalWalker::new();
+        }
+        let rounded_moment = Moment(
+            origin
+                .timezone()
+                .ymd(origin.start.year(), self.0, 1)
+                .and_hms(0, 0, 0),
+        );
+        let rounded_interval = Interval::starting_at(rounded_moment, Grain::Month);
+        let offset_year = !(origin.start <= rounded_interval.end_moment()) as i64;
+        let anchor = rounded_interval + PeriodComp::years(offset_year);
+        let forward_walker = Walker::generator(anchor, |prev| prev + PeriodComp::years(1));
+        let backward_walker = Walker::generator(anchor - PeriodComp::years(1), |prev| {
+            prev - PeriodComp::years(1)
+        });
+        BidirectionalWalker::new()
+            .forward(forward_walker)
+            .backward(backward_walker)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DayOfMonth(pub u32);
+
+impl DayOfMonth {
+    pub fn new<T: TimeZone + 'static>(dom: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = DayOfMonth(dom);
+        if is_valid_day_of_month(dom) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+
+    pub fn new_unchecked<T: TimeZone + 'static>(dom: u32) -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(DayOfMonth(dom))
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for DayOfMonth
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Month
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
+        let offset_month = (origin.start.0.day() > self.0) as i64;
+        let anchor = origin.start_round_to(Grain::Month) + PeriodComp::months(offset_month);
+        let origin_copied = origin.clone();
+        let day_of_month = self.0;
+        let forward_walker = Walker::generator(anchor, |prev| prev + PeriodComp::months(1))
+            .filter(move |interval| {
+                day_of_month
+                    <= last_day_in_month(
+                        interval.start.year(),
+                        interval.start.month(),
+                        origin_copied.timezone(),
+                    )
+            })
+            .map(move |interval| interval + PeriodComp::days(day_of_month as i64 - 1));
+
+        let backward_walker = Walker::generator(anchor - PeriodComp::months(1), |prev| {
+            prev - PeriodComp::months(1)
+        })
+        .filter(move |interval| {
+            day_of_month
+                <= last_day_in_month(
+                    interval.start.year(),
+                    interval.start.month(),
+                    origin_copied.timezone(),
+                )
+        })
+        .map(move |interval| interval + PeriodComp::days(day_of_month as i64 - 1));
+
+        BidirectionalWalker::new()
+            .forward(forward_walker)
+            .backward(backward_walker)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DayOfWeek(pub Weekday);
+
+impl DayOfWeek {
+    pub fn new<T: TimeZone>(dow: Weekday) -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(DayOfWeek(dow))
+    }
+}
+
+impl<T: TimeZone> IntervalConstraint<T> for DayOfWeek
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
+        // number_from_monday is u32 -> use i64
+        let offset = (self.0.number_from_monday() as i64
+            - origin.start.weekday().number_from_monday() as i64
+            + 7)
+            % 7;
+        let anchor = origin.start_round_to(Grain::Day) + PeriodComp::days(offset);
+
+        BidirectionalWalker::new()
+            .forward_with(anchor, |prev| prev + PeriodComp::weeks(1))
+            .backward_with(anchor - PeriodComp::weeks(1), |prev| {
+                prev - PeriodComp::weeks(1)
+            })
+    }
+}
+
+fn is_valid_iso_week(w: u32) -> bool {
+    1 <= w && w <= 53
+}
+
+/// The Monday-based week `week` of the ISO 8601 week-numbering year `year`, if this year has it
+fn iso_week_interval<T: TimeZone>(year: i32, week: u32, timezone: T) -> Option<Interval<T>>
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    timezone
+        .isoywd_opt(year, week, Weekday::Mon)
+        .single()
+        .map(|monday| Interval::starting_at(Moment(monday.and_hms(0, 0, 0)), Grain::Week))
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IsoWeek(pub u32);
+
+impl IsoWeek {
+    pub fn new<T: TimeZone + 'static>(w: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = IsoWeek(w);
+        if is_valid_iso_week(w) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for IsoWeek
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
+        if !is_valid_iso_week(self.0) {
+            return BidirectionalWalker::new();
+        }
+        let week = self.0;
+        let iso_year = origin.start.0.iso_week().year();
+        // January 4th always belongs to the first week of its ISO year
+        let offset_year = iso_week_interval(iso_year, week, origin.timezone())
+            .map(|interval| !(origin.start < interval.end_moment()) as i64)
+            .unwrap_or(0);
+        let anchor = Interval::starting_at(
+            Moment(origin.timezone().ymd(iso_year, 1, 4).and_hms(0, 0, 0)),
+            Grain::Day,
+        ) + PeriodComp::years(offset_year);
+
+        let forward_walker = Walker::generator(anchor, |prev| prev + PeriodComp::years(1))
+            .filter_map(move |interval| {
+                iso_week_interval(interval.start.year(), week, interval.timezone())
+            });
+        let backward_walker = Walker::generator(anchor - PeriodComp::years(1), |prev| {
+            prev - PeriodComp::years(1)
+        })
+        .filter_map(move |interval| {
+            iso_week_interval(interval.start.year(), week, interval.timezone())
+        });
 
         BidirectionalWalker::new()
             .forward(forward_walker)
@@ -428,15 +1227,18 @@ where
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub struct Month(pub u32);
+pub struct IsoYearWeek {
+    pub year: i32,
+    pub week: u32,
+}
 
-impl Month {
-    pub fn new<T: TimeZone + 'static>(m: u32) -> MomentResult<RcConstraint<T>>
+impl IsoYearWeek {
+    pub fn new<T: TimeZone + 'static>(year: i32, week: u32) -> MomentResult<RcConstraint<T>>
     where
         <T as TimeZone>::Offset: Copy,
     {
-        let args = Month(m);
-        if is_valid_month(m) {
+        let args = IsoYearWeek { year, week };
+        if is_valid_iso_week(week) {
             Ok(rc!(args))
         } else {
             Err(MomentError::ConstraintsInvalidArgs {
@@ -444,196 +1246,158 @@ impl Month {
             })
         }
     }
-
-    pub fn new_unchecked<T: TimeZone + 'static>(m: u32) -> RcConstraint<T>
-    where
-        <T as TimeZone>::Offset: Copy,
-    {
-        rc!(Month(m))
-    }
 }
 
-impl<T: TimeZone + 'static> IntervalConstraint<T> for Month
+impl<T: TimeZone + 'static> IntervalConstraint<T> for IsoYearWeek
 where
     <T as TimeZone>::Offset: Copy,
 {
     fn grain(&self) -> Grain {
-        Grain::Month
+        Grain::Week
     }
 
     fn grain_min(&self) -> Grain {
-        Grain::Month
+        Grain::Week
     }
 
     fn grain_left(&self) -> Grain {
-        Grain::Month
+        Grain::Week
     }
 
     fn grain_right(&self) -> Grain {
-        Grain::Month
+        Grain::Week
     }
 
     fn coarse_grain_step(&self) -> Grain {
         Grain::Year
     }
 
-    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        if !is_valid_month(self.0) {
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        if self.year > context.max.start.year() || self.year < context.min.start.year() {
             return BidirectionalWalker::new();
         }
-        let rounded_moment = Moment(
-            origin
-                .timezone()
-                .ymd(origin.start.year(), self.0, 1)
-                .and_hms(0, 0, 0),
-        );
-        let rounded_interval = Interval::starting_at(rounded_moment, Grain::Month);
-        let offset_year = !(origin.start <= rounded_interval.end_moment()) as i64;
-        let anchor = rounded_interval + PeriodComp::years(offset_year);
-        let forward_walker = Walker::generator(anchor, |prev| prev + PeriodComp::years(1));
-        let backward_walker = Walker::generator(anchor - PeriodComp::years(1), |prev| {
-            prev - PeriodComp::years(1)
-        });
-        BidirectionalWalker::new()
-            .forward(forward_walker)
-            .backward(backward_walker)
+        match iso_week_interval(self.year, self.week, origin.timezone()) {
+            Some(interval) if origin.start < interval.end_moment() => {
+                BidirectionalWalker::new().forward_values(vec![interval])
+            }
+            Some(interval) => BidirectionalWalker::new().backward_values(vec![interval]),
+            None => BidirectionalWalker::new(),
+        }
     }
 }
 
+/// A week of the ISO year the origin falls in, e.g. "the 3rd week of the year". The first week of
+/// an ISO year can start in December, so it is not intersected with the calendar year.
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub struct DayOfMonth(pub u32);
+pub struct IsoWeekOfThisYear(pub u32);
 
-impl DayOfMonth {
-    pub fn new<T: TimeZone + 'static>(dom: u32) -> MomentResult<RcConstraint<T>>
+impl IsoWeekOfThisYear {
+    pub fn new<T: TimeZone + 'static>(week: u32) -> MomentResult<RcConstraint<T>>
     where
         <T as TimeZone>::Offset: Copy,
     {
-        let args = DayOfMonth(dom);
-        if is_valid_day_of_month(dom) {
-            Ok(rc!(args))
+        if is_valid_iso_week(week) {
+            Ok(rc!(IsoWeekOfThisYear(week)))
         } else {
             Err(MomentError::ConstraintsInvalidArgs {
-                context: format!("{:?}", args),
+                context: format!("{:?}", IsoWeekOfThisYear(week)),
             })
         }
     }
-
-    pub fn new_unchecked<T: TimeZone + 'static>(dom: u32) -> RcConstraint<T>
-    where
-        <T as TimeZone>::Offset: Copy,
-    {
-        rc!(DayOfMonth(dom))
-    }
 }
 
-impl<T: TimeZone + 'static> IntervalConstraint<T> for DayOfMonth
+impl<T: TimeZone + 'static> IntervalConstraint<T> for IsoWeekOfThisYear
 where
     <T as TimeZone>::Offset: Copy,
 {
     fn grain(&self) -> Grain {
-        Grain::Day
+        Grain::Week
     }
 
     fn grain_min(&self) -> Grain {
-        Grain::Day
+        Grain::Week
     }
 
     fn grain_left(&self) -> Grain {
-        Grain::Day
+        Grain::Week
     }
 
     fn grain_right(&self) -> Grain {
-        Grain::Day
+        Grain::Week
     }
 
     fn coarse_grain_step(&self) -> Grain {
-        Grain::Month
+        Grain::Year
     }
 
-    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        let offset_month = (origin.start.0.day() > self.0) as i64;
-        let anchor = origin.start_round_to(Grain::Month) + PeriodComp::months(offset_month);
-        let origin_copied = origin.clone();
-        let day_of_month = self.0;
-        let forward_walker = Walker::generator(anchor, |prev| prev + PeriodComp::months(1))
-            .filter(move |interval| {
-                day_of_month
-                    <= last_day_in_month(
-                        interval.start.year(),
-                        interval.start.month(),
-                        origin_copied.timezone(),
-                    )
-            })
-            .map(move |interval| interval + PeriodComp::days(day_of_month as i64 - 1));
-
-        let backward_walker = Walker::generator(anchor - PeriodComp::months(1), |prev| {
-            prev - PeriodComp::months(1)
-        })
-        .filter(move |interval| {
-            day_of_month
-                <= last_day_in_month(
-                    interval.start.year(),
-                    interval.start.month(),
-                    origin_copied.timezone(),
-                )
-        })
-        .map(move |interval| interval + PeriodComp::days(day_of_month as i64 - 1));
-
-        BidirectionalWalker::new()
-            .forward(forward_walker)
-            .backward(backward_walker)
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let year = origin.start.0.iso_week().year();
+        IsoYearWeek { year, week: self.0 }.to_walker(origin, context)
     }
 }
 
+/// An absolute point in time, e.g. "2019-03-04T10:00:00+02:00". Without an offset, the date and
+/// time are read in the timezone of the resolution context.
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub struct DayOfWeek(pub Weekday);
+pub struct Timestamp {
+    pub datetime: NaiveDateTime,
+    pub offset: Option<FixedOffset>,
+}
 
-impl DayOfWeek {
-    pub fn new<T: TimeZone>(dow: Weekday) -> RcConstraint<T>
+impl Timestamp {
+    pub fn new<T: TimeZone>(datetime: NaiveDateTime, offset: Option<FixedOffset>) -> RcConstraint<T>
     where
         <T as TimeZone>::Offset: Copy,
     {
-        rc!(DayOfWeek(dow))
+        rc!(Timestamp { datetime, offset })
     }
 }
 
-impl<T: TimeZone> IntervalConstraint<T> for DayOfWeek
+impl<T: TimeZone> IntervalConstraint<T> for Timestamp
 where
     <T as TimeZone>::Offset: Copy,
 {
     fn grain(&self) -> Grain {
-        Grain::Day
+        Grain::Second
     }
 
     fn grain_min(&self) -> Grain {
-        Grain::Day
+        Grain::Second
     }
 
+    // A timestamp carries both a date and a time, like the intersection of both
     fn grain_left(&self) -> Grain {
         Grain::Day
     }
 
     fn grain_right(&self) -> Grain {
-        Grain::Day
+        Grain::Second
     }
 
     fn coarse_grain_step(&self) -> Grain {
-        Grain::Week
+        Grain::Year
     }
 
-    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        // number_from_monday is u32 -> use i64
-        let offset = (self.0.number_from_monday() as i64
-            - origin.start.weekday().number_from_monday() as i64
-            + 7)
-            % 7;
-        let anchor = origin.start_round_to(Grain::Day) + PeriodComp::days(offset);
-
-        BidirectionalWalker::new()
-            .forward_with(anchor, |prev| prev + PeriodComp::weeks(1))
-            .backward_with(anchor - PeriodComp::weeks(1), |prev| {
-                prev - PeriodComp::weeks(1)
-            })
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let timezone = origin.timezone();
+        let start = match self.offset {
+            Some(offset) => offset
+                .from_local_datetime(&self.datetime)
+                .single()
+                .map(|datetime| datetime.with_timezone(&timezone)),
+            None => timezone.from_local_datetime(&self.datetime).earliest(),
+        };
+        let interval = match start {
+            Some(start) => Interval::starting_at(Moment(start), Grain::Second),
+            None => return BidirectionalWalker::new(),
+        };
+        if interval.start > context.max.end_moment() || interval.start < context.min.start {
+            BidirectionalWalker::new()
+        } else if origin.start < interval.end_moment() {
+            BidirectionalWalker::new().forward_values(vec![interval])
+        } else {
+            BidirectionalWalker::new().backward_values(vec![interval])
+        }
     }
 }
 
@@ -1246,6 +2010,7 @@ where
 
 #[derive(Clone)]
 pub struct TakeLastOf<T: TimeZone> {
+    n: i64,
     base: RcConstraint<T>,
     cycle: RcConstraint<T>,
 }
@@ -1254,8 +2019,11 @@ impl<T: TimeZone + 'static> TakeLastOf<T>
 where
     <T as TimeZone>::Offset: Copy,
 {
-    pub fn new(base: &RcConstraint<T>, cycle: &RcConstraint<T>) -> RcConstraint<T> {
+    /// Takes the `n`-th `cycle` counting backward from the end of each `base` interval,
+    /// `n = 0` being the last one.
+    pub fn new(n: i64, base: &RcConstraint<T>, cycle: &RcConstraint<T>) -> RcConstraint<T> {
         rc!(TakeLastOf {
+            n: n,
             base: base.clone(),
             cycle: cycle.clone(),
         })
@@ -1288,13 +2056,22 @@ where
 
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         let cycle = self.cycle.clone();
+        let n = self.n;
         let translate = Translate {
             generator: self.base.clone(),
             offset: Rc::new(
                 move |i: &Interval<T>, c: &Context<T>| -> Option<Interval<T>> {
+                    if n < 0 {
+                        return None;
+                    }
                     let pivot = i.after();
                     let walker = cycle.to_walker(&pivot, c);
-                    walker.backward.clone().next()
+                    walker
+                        .backward
+                        .clone()
+                        .skip(n as usize)
+                        .next()
+                        .filter(|nth_last| nth_last.start >= i.start)
                 },
             ),
         };
@@ -1597,7 +2374,8 @@ where
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         if let Some(period_grain) = self.period.finer_grain() {
             let period = self.period.clone();
-            let is_forward = period.coarse_num_secs() >= 0;
+            // in milliseconds, as a sub-second period has no whole seconds
+            let is_forward = period.coarse_num_millis() >= 0;
             let grain_after_shift = period_grain.after_shift();
             let translate = Translate {
                 generator: self.base.clone(),
@@ -1681,48 +2459,242 @@ mod tests {
     }
 
     #[test]
-    fn test_year() {
+    fn test_year() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let year = Year(2015);
+        let walker = year.to_walker(&context.reference, &context);
+        let mut backward = walker.backward.clone();
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2015, 1, 1).and_hms(0, 0, 0)),
+                Grain::Year
+            )),
+            backward.next()
+        );
+
+        assert_eq!(None, backward.next());
+        assert_eq!(None, walker.forward.clone().next());
+
+        let year = Year(2018);
+        let walker = year.to_walker(&context.reference, &context);
+        assert_eq!(None, walker.backward.clone().next());
+
+        let walker = year.to_walker(&context.reference, &context);
+        let mut forward = walker.forward.clone();
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 1, 1).and_hms(0, 0, 0)),
+                Grain::Year
+            )),
+            forward.next()
+        );
+        assert_eq!(None, forward.next());
+
+        assert_eq!(None, walker.backward.clone().next());
+
+        let year = Year(100);
+        let walker = year.to_walker(&context.reference, &context);
+        assert_eq!(None, walker.backward.clone().next());
+        assert_eq!(None, walker.forward.clone().next());
+
+        let year = Year(-100);
+        let walker = year.to_walker(&context.reference, &context);
+        assert_eq!(None, walker.backward.clone().next());
+        assert_eq!(None, walker.forward.clone().next());
+    }
+
+    #[test]
+    fn test_years() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let nineties = Years { start: 1990, count: 10 };
+        let walker = nineties.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval {
+                start: Moment(Paris.ymd(1990, 1, 1).and_hms(0, 0, 0)),
+                end: Some(Moment(Paris.ymd(2000, 1, 1).and_hms(0, 0, 0))),
+                grain: Grain::Year,
+            }),
+            walker.backward.clone().next()
+        );
+        assert_eq!(None, walker.forward.clone().next());
+
+        let twenty_tens = Years { start: 2010, count: 10 };
+        let walker = twenty_tens.to_walker(&context.reference, &context);
+        assert_eq!(None, walker.backward.clone().next());
+        assert_eq!(
+            Some(Interval {
+                start: Moment(Paris.ymd(2010, 1, 1).and_hms(0, 0, 0)),
+                end: Some(Moment(Paris.ymd(2020, 1, 1).and_hms(0, 0, 0))),
+                grain: Grain::Year,
+            }),
+            walker.forward.clone().next()
+        );
+    }
+
+    #[test]
+    fn test_years_of_recent_decade() {
+        let context = build_context(Moment(Paris.ymd(2013, 02, 12).and_hms(4, 30, 0)));
+        let first_year = |decade: YearsOfRecentDecade| {
+            let walker = decade.to_walker(&context.reference, &context);
+            walker.backward.clone().next().or_else(|| walker.forward.clone().next()).map(|i| i.start.year())
+        };
+        let decade = |decade| YearsOfRecentDecade { decade, offset: 0, count: 10 };
+        assert_eq!(Some(1990), first_year(decade(90)));
+        assert_eq!(Some(1930), first_year(decade(30)));
+        assert_eq!(Some(1920), first_year(decade(20)));
+        assert_eq!(Some(2010), first_year(decade(10)));
+        assert_eq!(Some(2000), first_year(decade(0)));
+        let late_twenties = YearsOfRecentDecade { decade: 20, offset: 7, count: 3 };
+        assert_eq!(Some(1927), first_year(late_twenties));
+    }
+
+    #[test]
+    fn test_iso_week() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let walker = IsoWeek(42).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 10, 16).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 10, 17).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.backward.clone().next()
+        );
+
+        // 2017 has no 53rd week, the next one starts on 2020-12-28
+        let walker = IsoWeek(53).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2020, 12, 28).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+        // The first week of 2016 starts on January 4th
+        let walker = IsoWeek(1).to_walker(&context.reference, &context);
+        let mut backward = walker.backward.clone();
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 1, 2).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            backward.next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 1, 4).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            backward.next()
+        );
+    }
+
+    #[test]
+    fn test_iso_week_of_this_year() {
+        // On 2019-12-31, the ISO year 2020 has already started
+        let context = build_context(Moment(Paris.ymd(2019, 12, 31).and_hms(9, 10, 11)));
+        let walker = IsoWeekOfThisYear(1).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2019, 12, 30).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+        let context = build_context(Moment(Paris.ymd(2015, 1, 1).and_hms(9, 10, 11)));
+        let walker = IsoWeekOfThisYear(1).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2014, 12, 29).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+        let walker = IsoWeekOfThisYear(3).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2015, 1, 12).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+    }
+
+    #[test]
+    fn test_iso_year_week() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
-        let year = Year(2015);
-        let walker = year.to_walker(&context.reference, &context);
-        let mut backward = walker.backward.clone();
+        let constraint = IsoYearWeek { year: 2025, week: 1 };
+        let walker = constraint.to_walker(&context.reference, &context);
         assert_eq!(
             Some(Interval::starting_at(
-                Moment(Paris.ymd(2015, 1, 1).and_hms(0, 0, 0)),
-                Grain::Year
+                Moment(Paris.ymd(2024, 12, 30).and_hms(0, 0, 0)),
+                Grain::Week
             )),
-            backward.next()
+            walker.forward.clone().next()
         );
-
-        assert_eq!(None, backward.next());
+        let constraint = IsoYearWeek { year: 2017, week: 17 };
+        let walker = constraint.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 4, 24).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+        let constraint = IsoYearWeek { year: 2017, week: 53 };
+        let walker = constraint.to_walker(&context.reference, &context);
         assert_eq!(None, walker.forward.clone().next());
-
-        let year = Year(2018);
-        let walker = year.to_walker(&context.reference, &context);
         assert_eq!(None, walker.backward.clone().next());
+    }
 
-        let walker = year.to_walker(&context.reference, &context);
-        let mut forward = walker.forward.clone();
+    #[test]
+    fn test_timestamp() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let datetime = NaiveDate::from_ymd(2019, 3, 4).and_hms(10, 0, 0);
+        // Paris is always UTC+2 in these tests
+        let constraint = Timestamp {
+            datetime,
+            offset: Some(FixedOffset::east(0)),
+        };
+        let walker = constraint.to_walker(&context.reference, &context);
         assert_eq!(
             Some(Interval::starting_at(
-                Moment(Paris.ymd(2018, 1, 1).and_hms(0, 0, 0)),
-                Grain::Year
+                Moment(Paris.ymd(2019, 3, 4).and_hms(12, 0, 0)),
+                Grain::Second
             )),
-            forward.next()
+            walker.forward.clone().next()
         );
-        assert_eq!(None, forward.next());
-
-        assert_eq!(None, walker.backward.clone().next());
-
-        let year = Year(100);
-        let walker = year.to_walker(&context.reference, &context);
-        assert_eq!(None, walker.backward.clone().next());
-        assert_eq!(None, walker.forward.clone().next());
-
-        let year = Year(-100);
-        let walker = year.to_walker(&context.reference, &context);
-        assert_eq!(None, walker.backward.clone().next());
+        let constraint = Timestamp {
+            datetime,
+            offset: None,
+        };
+        let walker = constraint.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2019, 3, 4).and_hms(10, 0, 0)),
+                Grain::Second
+            )),
+            walker.forward.clone().next()
+        );
+        let constraint = Timestamp {
+            datetime: NaiveDate::from_ymd(2015, 6, 5).and_hms(23, 30, 0),
+            offset: Some(FixedOffset::west(5 * 3600)),
+        };
+        let walker = constraint.to_walker(&context.reference, &context);
         assert_eq!(None, walker.forward.clone().next());
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2015, 6, 6).and_hms(6, 30, 0)),
+                Grain::Second
+            )),
+            walker.backward.clone().next()
+        );
     }
 
     #[test]
@@ -1823,6 +2795,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cycle_semester() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let cycle = Cycle(Grain::Semester);
+        let walker = cycle.to_walker(&context.reference, &context);
+        let mut forward = walker.forward.clone();
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 01, 01).and_hms(0, 0, 0)),
+                Grain::Semester
+            )),
+            forward.next()
+        );
+        let next_semester = forward.next().unwrap();
+        assert_eq!(Moment(Paris.ymd(2017, 07, 01).and_hms(0, 0, 0)), next_semester.start);
+        assert_eq!(
+            Moment(Paris.ymd(2018, 01, 01).and_hms(0, 0, 0)),
+            next_semester.end_moment()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 07, 01).and_hms(0, 0, 0)),
+                Grain::Semester
+            )),
+            walker.backward.clone().next()
+        );
+    }
+
     #[test]
     fn test_take_the_nth_forward_positive() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
@@ -1908,6 +2908,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_take_the_nth_day_within() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        // the days after the enclosing period are dropped, e.g. day 366 of 2013
+        let year = Year::new(2013);
+        let walker = Cycle::rc(Grain::Day)
+            .the_nth(365)
+            .after(&year)
+            .intersect(&year)
+            .to_walker(&context.reference, &context);
+        assert_eq!(None, walker.forward.clone().next());
+        assert_eq!(None, walker.backward.clone().next());
+        let walker = Cycle::rc(Grain::Day)
+            .the_nth(59)
+            .after(&year)
+            .intersect(&year)
+            .to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2013, 03, 01).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+        // there is no 31st day in February
+        let february = rc!(Month(2));
+        let walker = Cycle::rc(Grain::Day)
+            .the_nth(30)
+            .after(&february)
+            .intersect(&february)
+            .to_walker(&context.reference, &context);
+        assert_eq!(None, walker.forward.clone().next());
+        assert_eq!(None, walker.backward.clone().next());
+        let walker = Cycle::rc(Grain::Day)
+            .the_nth(27)
+            .after(&february)
+            .intersect(&february)
+            .to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 02, 28).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+    }
+
     #[test]
     fn test_take_the_nth_after_negative() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
@@ -2021,6 +3068,190 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_take_the_nth_last_day_of_month() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let third_last = Cycle::rc(Grain::Day).nth_last_of(2, &rc!(Month(5)));
+
+        let walker = third_last.to_walker(&context.reference, &context);
+
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 05, 29).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 05, 29).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+
+        let out_of_month = Cycle::rc(Grain::Day).nth_last_of(31, &rc!(Month(5)));
+        let walker = out_of_month.to_walker(&context.reference, &context);
+        assert_eq!(None, walker.forward.clone().next());
+    }
+
+    #[test]
+    fn test_take_the_last_day_of_year() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let last_day = Cycle::rc(Grain::Day).last_of(&Year::new(2017));
+
+        let walker = last_day.to_walker(&context.reference, &context);
+
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 12, 31).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+    }
+
+    #[test]
+    fn test_lunar_month_day() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let mid_autumn = LunarMonthDay(8, 15);
+        let walker = mid_autumn.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 10, 4).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 9, 24).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().skip(1).next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 9, 15).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+
+        let new_year = LunarMonthDay(1, 1);
+        let walker = new_year.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 2, 16).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 1, 28).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+    }
+
+    #[test]
+    fn test_hijri_month_day() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let eid_al_fitr = HijriMonthDay(10, 1);
+        let walker = eid_al_fitr.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 6, 26).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 6, 15).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().skip(1).next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 7, 7).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+    }
+
+    #[test]
+    fn test_hebrew_month_day() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let rosh_hashanah = HebrewMonthDay(7, 1);
+        let walker = rosh_hashanah.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 9, 21).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 10, 3).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+
+        let passover = HebrewMonthDay(1, 15);
+        let walker = passover.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 3, 31).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 4, 11).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+    }
+
+    #[test]
+    fn test_nth_weekday_of_month() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let day = |y, m, d| {
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(y, m, d).and_hms(0, 0, 0)),
+                Grain::Day,
+            ))
+        };
+
+        let thanksgiving = NthWeekdayOfMonth::new(4, Weekday::Thu, 11).unwrap();
+        let walker = thanksgiving.to_walker(&context.reference, &context);
+        assert_eq!(day(2017, 11, 23), walker.forward.clone().next());
+        assert_eq!(day(2018, 11, 22), walker.forward.clone().skip(1).next());
+        assert_eq!(day(2016, 11, 24), walker.backward.clone().next());
+
+        let memorial_day = NthWeekdayOfMonth::new(-1, Weekday::Mon, 5).unwrap();
+        let walker = memorial_day.to_walker(&context.reference, &context);
+        assert_eq!(day(2017, 5, 29), walker.forward.clone().next());
+        assert_eq!(day(2016, 5, 30), walker.backward.clone().next());
+
+        // December has only four Sundays from 2020 to 2022
+        let fifth_sunday = NthWeekdayOfMonth::new(5, Weekday::Sun, 12).unwrap();
+        let walker = fifth_sunday.to_walker(&context.reference, &context);
+        assert_eq!(day(2019, 12, 29), walker.forward.clone().skip(2).next());
+        assert_eq!(day(2023, 12, 31), walker.forward.clone().skip(3).next());
+
+        assert!(NthWeekdayOfMonth::new::<Paris>(0, Weekday::Mon, 1).is_err());
+    }
+
     #[test]
     fn test_month_day_above_current_month() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
@@ -2908,6 +4139,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_observed_holiday() {
+        let context = build_context(Moment(Paris.ymd(2020, 06, 01).and_hms(9, 10, 11)));
+        let day = |y, m, d| {
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(y, m, d).and_hms(0, 0, 0)),
+                Grain::Day,
+            ))
+        };
+
+        // July 4th falls on a Saturday in 2020, a Sunday in 2021 and a Thursday in 2019
+        let independence_day = MonthDay::new(7, 4).unwrap();
+        let walker = independence_day
+            .observed(Observance::NearestWeekday)
+            .to_walker(&context.reference, &context);
+        assert_eq!(day(2020, 7, 4), walker.forward.clone().next());
+
+        let observed_context = context.with_holiday_date(HolidayDate::Observed);
+        let walker = independence_day
+            .observed(Observance::NearestWeekday)
+            .to_walker(&observed_context.reference, &observed_context);
+        assert_eq!(day(2020, 7, 3), walker.forward.clone().next());
+        assert_eq!(day(2021, 7, 5), walker.forward.clone().skip(1).next());
+        assert_eq!(day(2019, 7, 4), walker.backward.clone().next());
+
+        let walker = independence_day
+            .observed(Observance::NextMonday)
+            .to_walker(&observed_context.reference, &observed_context);
+        assert_eq!(day(2020, 7, 6), walker.forward.clone().next());
+
+        let walker = independence_day
+            .observed_as(Observance::SundayToMonday, Some(HolidayDate::Observed))
+            .to_walker(&context.reference, &context);
+        assert_eq!(day(2020, 7, 4), walker.forward.clone().next());
+        assert_eq!(day(2021, 7, 5), walker.forward.clone().skip(1).next());
+    }
+
     #[test]
     fn test_translate_date_by_days_back_() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
@@ -3015,6 +4283,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_shift_by_negative_milliseconds() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let walker = YearMonthDay::new(2017, 5, 2)
+            .unwrap()
+            .span_to(&YearMonthDay::new(2017, 5, 4).unwrap())
+            .shift_by(PeriodComp::milliseconds(-500).into())
+            .to_walker(&context.reference, &context);
+        // shifting backward starts from the beginning of the span, not its end
+        assert_eq!(
+            Some(Moment(Paris.ymd(2017, 05, 01).and_hms_milli(23, 59, 59, 500))),
+            walker.forward.clone().next().map(|interval| interval.start)
+        );
+    }
+
     #[test]
     fn test_month_day_special_case() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
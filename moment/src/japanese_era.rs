@@ -0,0 +1,72 @@
+//! Japanese eras (元号), counting years from the accession of an emperor.
+//!
+//! The first year of an era (元年) starts on the day of the accession, the
+//! rest of that Gregorian year belonging to the previous era.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum JapaneseEra {
+    Meiji,
+    Taisho,
+    Showa,
+    Heisei,
+    Reiwa,
+}
+
+impl JapaneseEra {
+    /// First day of the era, as `(year, month, day)` in the Gregorian calendar.
+    pub fn start(&self) -> (i32, u32, u32) {
+        match *self {
+            JapaneseEra::Meiji => (1868, 10, 23),
+            JapaneseEra::Taisho => (1912, 7, 30),
+            JapaneseEra::Showa => (1926, 12, 25),
+            JapaneseEra::Heisei => (1989, 1, 8),
+            JapaneseEra::Reiwa => (2019, 5, 1),
+        }
+    }
+
+    /// First day of the next era, `None` for the current era.
+    pub fn end(&self) -> Option<(i32, u32, u32)> {
+        match *self {
+            JapaneseEra::Meiji => Some(JapaneseEra::Taisho.start()),
+            JapaneseEra::Taisho => Some(JapaneseEra::Showa.start()),
+            JapaneseEra::Showa => Some(JapaneseEra::Heisei.start()),
+            JapaneseEra::Heisei => Some(JapaneseEra::Reiwa.start()),
+            JapaneseEra::Reiwa => None,
+        }
+    }
+
+    /// Gregorian year of a year of the era, `None` if the era does not last
+    /// that long.
+    pub fn to_gregorian_year(&self, year: i32) -> Option<i32> {
+        let (start_year, _, _) = self.start();
+        let gregorian_year = start_year + year - 1;
+        let is_within_era = match self.end() {
+            Some((end_year, _, _)) => gregorian_year <= end_year,
+            None => true,
+        };
+        if year >= 1 && is_within_era {
+            Some(gregorian_year)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_gregorian_year() {
+        assert_eq!(Some(2019), JapaneseEra::Reiwa.to_gregorian_year(1));
+        assert_eq!(Some(2023), JapaneseEra::Reiwa.to_gregorian_year(5));
+        assert_eq!(Some(1989), JapaneseEra::Heisei.to_gregorian_year(1));
+        assert_eq!(Some(2018), JapaneseEra::Heisei.to_gregorian_year(30));
+        assert_eq!(Some(2019), JapaneseEra::Heisei.to_gregorian_year(31));
+        assert_eq!(None, JapaneseEra::Heisei.to_gregorian_year(32));
+        assert_eq!(Some(1989), JapaneseEra::Showa.to_gregorian_year(64));
+        assert_eq!(None, JapaneseEra::Showa.to_gregorian_year(65));
+        assert_eq!(Some(1926), JapaneseEra::Taisho.to_gregorian_year(15));
+        assert_eq!(None, JapaneseEra::Meiji.to_gregorian_year(0));
+    }
+}
@@ -8,7 +8,11 @@ extern crate failure;
 extern crate vec_map;
 
 pub mod bidirectional_walker;
+pub mod hebrew;
+pub mod hijri;
 pub mod interval_constraints;
+pub mod japanese_era;
+pub mod lunisolar;
 mod period;
 pub mod walker;
 
@@ -17,7 +21,7 @@ use std::fmt;
 use std::ops;
 
 use chrono::{DateTime, Datelike, Duration, Timelike};
-pub use chrono::{Local, TimeZone, Weekday};
+pub use chrono::{FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone, Weekday};
 pub use interval_constraints::*;
 pub use period::*;
 
@@ -137,7 +141,7 @@ where
         ))
     }
 
-    fn round_to(self, g: Grain) -> Moment<T> {
+    pub fn round_to(self, g: Grain) -> Moment<T> {
         match g {
             Grain::Year => Moment(self.timezone().ymd(self.year(), 1, 1).and_hms(0, 0, 0)),
             Grain::Month => Moment(
@@ -148,12 +152,25 @@ where
             Grain::Day => Moment(self.date().and_hms(0, 0, 0)),
             Grain::Hour => Moment(self.date().and_hms(self.hour(), 0, 0)),
             Grain::Minute => Moment(self.date().and_hms(self.hour(), self.minute(), 0)),
-            Grain::Second => self,
+            // A timestamp can carry fractional seconds, which only the millisecond grain keeps
+            Grain::Second => Moment(
+                self.date()
+                    .and_hms(self.hour(), self.minute(), self.second()),
+            ),
+            Grain::Millisecond => Moment(self.date().and_hms_milli(
+                self.hour(),
+                self.minute(),
+                self.second(),
+                self.0.nanosecond() / 1_000_000,
+            )),
             Grain::Week => {
                 // shift to monday morning
                 let day_offset = self.weekday().num_days_from_monday(); // monday is 0 here
                 self.round_to(Grain::Day) - PeriodComp::days(day_offset as i64)
             }
+            Grain::Semester => {
+                self.round_to(Grain::Month) - PeriodComp::months(self.month0() as i64 % 6)
+            }
             Grain::Quarter => {
                 self.round_to(Grain::Month) - PeriodComp::months(self.month0() as i64 % 3)
             }
@@ -185,14 +202,9 @@ where
 {
     type Output = Moment<T>;
     fn add(self, p: &'a Period) -> Moment<T> {
-        use enum_primitive::FromPrimitive;
         let mut result = self;
-        for (g, q) in p.0.iter() {
-            result = result
-                + PeriodComp {
-                    grain: Grain::from_usize(g).unwrap(), // checked
-                    quantity: *q,
-                };
+        for comp in p.comps() {
+            result = result + comp;
         }
         result
     }
@@ -216,6 +228,7 @@ where
     fn add(self, p: &'a PeriodComp) -> Moment<T> {
         match p.grain {
             Grain::Year => self.add_months(12 * p.quantity as i32),
+            Grain::Semester => self.add_months(6 * p.quantity as i32),
             Grain::Quarter => self.add_months(3 * p.quantity as i32),
             Grain::Month => self.add_months(p.quantity as i32),
             Grain::Week => {
@@ -225,6 +238,7 @@ where
             Grain::Hour => Moment(self.0 + Duration::hours(p.quantity)),
             Grain::Minute => Moment(self.0 + Duration::minutes(p.quantity)),
             Grain::Second => Moment(self.0 + Duration::seconds(p.quantity)),
+            Grain::Millisecond => Moment(self.0 + Duration::milliseconds(p.quantity)),
         }
     }
 }
@@ -430,14 +444,9 @@ where
 {
     type Output = Interval<T>;
     fn add(self, p: &'a Period) -> Interval<T> {
-        use enum_primitive::FromPrimitive;
         let mut result = self;
-        for (g, q) in p.0.iter() {
-            result = result
-                + PeriodComp {
-                    grain: Grain::from_usize(g).unwrap(), // checked
-                    quantity: *q,
-                };
+        for comp in p.comps() {
+            result = result + comp;
         }
         result
     }
@@ -671,6 +680,29 @@ mod tests {
             Moment(Paris.ymd(2017, 04, 01).and_hms(0, 0, 0)),
             now.round_to(Grain::Quarter)
         );
+        assert_eq!(
+            Moment(Paris.ymd(2017, 01, 01).and_hms(0, 0, 0)),
+            now.round_to(Grain::Semester)
+        );
+    }
+
+    #[test]
+    fn round_to_sub_seconds() {
+        // the second grain truncates the fractional seconds of a timestamp
+        let now = Moment(Paris.ymd(2017, 04, 25).and_hms_micro(9, 10, 11, 250_300));
+        assert_eq!(
+            Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)),
+            now.round_to(Grain::Second)
+        );
+        assert_eq!(
+            Moment(Paris.ymd(2017, 04, 25).and_hms_milli(9, 10, 11, 250)),
+            now.round_to(Grain::Millisecond)
+        );
+        let interval = Interval::starting_at(now, Grain::Millisecond);
+        assert_eq!(
+            Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 12)),
+            interval.interval_round_to(Grain::Second).end_moment()
+        );
     }
 
     #[test]
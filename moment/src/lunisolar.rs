@@ -0,0 +1,249 @@
+//! Chinese lunisolar calendar, used for the traditional celebrations of
+//! China, Korea and Vietnam.
+//!
+//! The conversion relies on an embedded table of the lunar years 1900 to 2100
+//! rather than on an astronomical computation, which keeps it exact (the
+//! published calendars do not always follow the astronomical rules) and cheap.
+
+use chrono::{Duration, NaiveDate};
+
+pub const FIRST_YEAR: i32 = 1900;
+pub const LAST_YEAR: i32 = 2100;
+
+/// One entry per lunar year from `FIRST_YEAR`: bits 15 to 4 flag the long
+/// (30 days) months from the 1st to the 12th, bits 3 to 0 hold the leap
+/// month (0 if there is none) and bit 16 flags a long leap month.
+#[rustfmt::skip]
+const LUNAR_YEARS: [u32; 201] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0, 0x055d2,
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0, 0x14977,
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2, 0x04970,
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7, 0x0c950,
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0b557,
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950, 0x06aa0,
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57, 0x056a0,
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0, 0x195a6,
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60, 0x09570,
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5, 0x092e0,
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5,
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0, 0x0a930,
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65, 0x0d530,
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520, 0x0dd45,
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20, 0x0ada0,
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1a6c4, 0x0aae0,
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0, 0x055d4,
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0,
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4, 0x0d160,
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150, 0x0f252,
+    0x0d520,
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LunarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub is_leap_month: bool,
+}
+
+impl LunarDate {
+    pub fn new(year: i32, month: u32, day: u32) -> LunarDate {
+        LunarDate {
+            year,
+            month,
+            day,
+            is_leap_month: false,
+        }
+    }
+
+    pub fn leap(year: i32, month: u32, day: u32) -> LunarDate {
+        LunarDate {
+            year,
+            month,
+            day,
+            is_leap_month: true,
+        }
+    }
+}
+
+fn year_info(year: i32) -> Option<u32> {
+    if !(FIRST_YEAR..=LAST_YEAR).contains(&year) {
+        None
+    } else {
+        Some(LUNAR_YEARS[(year - FIRST_YEAR) as usize])
+    }
+}
+
+/// Leap month of a lunar year, if any.
+pub fn leap_month(year: i32) -> Option<u32> {
+    year_info(year).and_then(|info| match info & 0xf {
+        0 => None,
+        m => Some(m),
+    })
+}
+
+/// Number of days (29 or 30) of a lunar month.
+pub fn month_length(year: i32, month: u32, is_leap_month: bool) -> Option<u32> {
+    let info = year_info(year)?;
+    if !(1..=12).contains(&month) || (is_leap_month && leap_month(year) != Some(month)) {
+        return None;
+    }
+    let long_flag = if is_leap_month {
+        0x10000
+    } else {
+        0x10000 >> month
+    };
+    Some(if info & long_flag != 0 { 30 } else { 29 })
+}
+
+/// Months of a lunar year in order, as `(month, is_leap_month, length)`.
+fn months(year: i32) -> Vec<(u32, bool, u32)> {
+    let leap = leap_month(year);
+    let mut months = vec![];
+    for m in 1..13 {
+        if let Some(length) = month_length(year, m, false) {
+            months.push((m, false, length));
+        }
+        if leap == Some(m) {
+            if let Some(length) = month_length(year, m, true) {
+                months.push((m, true, length));
+            }
+        }
+    }
+    months
+}
+
+fn year_length(year: i32) -> i64 {
+    year_info(year)
+        .map(|info| {
+            let long_months = (info & 0xfff0).count_ones() as i64;
+            let leap_length = match leap_month(year) {
+                Some(m) => month_length(year, m, true).unwrap_or(0) as i64,
+                None => 0,
+            };
+            12 * 29 + long_months + leap_length
+        })
+        .unwrap_or(0)
+}
+
+/// Gregorian date of the first day of a lunar year.
+pub fn new_year(year: i32) -> Option<NaiveDate> {
+    year_info(year)?;
+    let days: i64 = (FIRST_YEAR..year).map(year_length).sum();
+    Some(NaiveDate::from_ymd(1900, 1, 31) + Duration::days(days))
+}
+
+/// Converts a lunar date to the Gregorian calendar, `None` if the date does
+/// not exist (e.g. a 30th day in a short month) or is out of the table.
+pub fn to_gregorian(date: LunarDate) -> Option<NaiveDate> {
+    let length = month_length(date.year, date.month, date.is_leap_month)?;
+    if date.day < 1 || date.day > length {
+        return None;
+    }
+    let offset: i64 = months(date.year)
+        .iter()
+        .take_while(|&&(m, is_leap, _)| (m, is_leap) != (date.month, date.is_leap_month))
+        .map(|&(_, _, length)| length as i64)
+        .sum();
+    Some(new_year(date.year)? + Duration::days(offset + date.day as i64 - 1))
+}
+
+/// Converts a Gregorian date to the lunar calendar, `None` if it is out of the
+/// table.
+pub fn from_gregorian(date: NaiveDate) -> Option<LunarDate> {
+    let mut year_start = new_year(FIRST_YEAR)?;
+    if date < year_start {
+        return None;
+    }
+    for year in FIRST_YEAR..=LAST_YEAR {
+        let mut days = (date - year_start).num_days();
+        if days >= year_length(year) {
+            year_start += Duration::days(year_length(year));
+            continue;
+        }
+        for (month, is_leap_month, length) in months(year) {
+            if days < length as i64 {
+                return Some(LunarDate {
+                    year,
+                    month,
+                    day: days as u32 + 1,
+                    is_leap_month,
+                });
+            }
+            days -= length as i64;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_year() {
+        assert_eq!(Some(NaiveDate::from_ymd(1900, 1, 31)), new_year(1900));
+        assert_eq!(Some(NaiveDate::from_ymd(2000, 2, 5)), new_year(2000));
+        assert_eq!(Some(NaiveDate::from_ymd(2013, 2, 10)), new_year(2013));
+        assert_eq!(Some(NaiveDate::from_ymd(2014, 1, 31)), new_year(2014));
+        assert_eq!(Some(NaiveDate::from_ymd(2020, 1, 25)), new_year(2020));
+        assert_eq!(Some(NaiveDate::from_ymd(2023, 1, 22)), new_year(2023));
+        assert_eq!(Some(NaiveDate::from_ymd(2024, 2, 10)), new_year(2024));
+        assert_eq!(None, new_year(2101));
+    }
+
+    #[test]
+    fn test_to_gregorian() {
+        // Mid-Autumn festival
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2013, 9, 19)),
+            to_gregorian(LunarDate::new(2013, 8, 15))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2020, 10, 1)),
+            to_gregorian(LunarDate::new(2020, 8, 15))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2023, 9, 29)),
+            to_gregorian(LunarDate::new(2023, 8, 15))
+        );
+        // 2020 has a leap 4th month
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2020, 5, 23)),
+            to_gregorian(LunarDate::leap(2020, 4, 1))
+        );
+        assert_eq!(None, to_gregorian(LunarDate::leap(2021, 4, 1)));
+        // The 12th month of 2012 is short
+        assert_eq!(None, to_gregorian(LunarDate::new(2012, 12, 30)));
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2013, 2, 9)),
+            to_gregorian(LunarDate::new(2012, 12, 29))
+        );
+    }
+
+    #[test]
+    fn test_from_gregorian() {
+        assert_eq!(
+            Some(LunarDate::new(2013, 1, 3)),
+            from_gregorian(NaiveDate::from_ymd(2013, 2, 12))
+        );
+        assert_eq!(
+            Some(LunarDate::new(2012, 12, 29)),
+            from_gregorian(NaiveDate::from_ymd(2013, 2, 9))
+        );
+        assert_eq!(
+            Some(LunarDate::leap(2020, 4, 1)),
+            from_gregorian(NaiveDate::from_ymd(2020, 5, 23))
+        );
+        assert_eq!(None, from_gregorian(NaiveDate::from_ymd(1900, 1, 30)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut date = NaiveDate::from_ymd(1990, 1, 1);
+        while date < NaiveDate::from_ymd(2030, 1, 1) {
+            assert_eq!(Some(date), from_gregorian(date).and_then(to_gregorian));
+            date += Duration::days(1);
+        }
+    }
+}
@@ -2,7 +2,9 @@ use std::ops;
 use vec_map::VecMap;
 
 enum_from_primitive! {
-    #[derive(Debug,PartialEq,Copy,Clone,Eq,Ord,PartialOrd, Hash)]
+    // Grains are numbered in the order they were added, `Grain::rank` orders them from the
+    // coarsest to the finest
+    #[derive(Debug,PartialEq,Copy,Clone,Eq, Hash)]
     pub enum Grain {
         Year = 0,
         Quarter = 1,
@@ -12,13 +14,43 @@ enum_from_primitive! {
         Hour = 5,
         Minute = 6,
         Second = 7,
+        Millisecond = 8,
+        Semester = 9,
+    }
+}
+
+impl PartialOrd for Grain {
+    fn partial_cmp(&self, other: &Grain) -> Option<::std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Grain {
+    fn cmp(&self, other: &Grain) -> ::std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
     }
 }
 
 impl Grain {
+    pub fn rank(&self) -> usize {
+        match self {
+            &Grain::Year => 0,
+            &Grain::Semester => 1,
+            &Grain::Quarter => 2,
+            &Grain::Month => 3,
+            &Grain::Week => 4,
+            &Grain::Day => 5,
+            &Grain::Hour => 6,
+            &Grain::Minute => 7,
+            &Grain::Second => 8,
+            &Grain::Millisecond => 9,
+        }
+    }
+
     pub fn next(&self) -> Grain {
         match self {
             &Grain::Year => Grain::Month,
+            &Grain::Semester => Grain::Month,
             &Grain::Quarter => Grain::Month,
             &Grain::Month => Grain::Day,
             &Grain::Week => Grain::Day,
@@ -26,12 +58,14 @@ impl Grain {
             &Grain::Hour => Grain::Minute,
             &Grain::Minute => Grain::Second,
             &Grain::Second => Grain::Second,
+            &Grain::Millisecond => Grain::Millisecond,
         }
     }
 
     pub fn after_shift(&self) -> Grain {
         match self {
             &Grain::Year => Grain::Month,
+            &Grain::Semester => Grain::Month,
             &Grain::Quarter => Grain::Month,
             &Grain::Month => Grain::Day,
             &Grain::Week => Grain::Day,
@@ -39,38 +73,45 @@ impl Grain {
             &Grain::Hour => Grain::Minute,
             &Grain::Minute => Grain::Second,
             &Grain::Second => Grain::Second,
+            &Grain::Millisecond => Grain::Millisecond,
         }
     }
 
     pub fn half_period(&self) -> Option<PeriodComp> {
         match self {
             &Grain::Year => Some(PeriodComp::months(6)),
+            &Grain::Semester => Some(PeriodComp::months(3)),
             &Grain::Quarter => Some(PeriodComp::days(45)),
             &Grain::Month => Some(PeriodComp::days(15)),
             &Grain::Week => Some(PeriodComp::days(4)),
             &Grain::Day => Some(PeriodComp::hours(12)),
             &Grain::Hour => Some(PeriodComp::minutes(30)),
             &Grain::Minute => Some(PeriodComp::seconds(30)),
-            &Grain::Second => None,
+            &Grain::Second => Some(PeriodComp::milliseconds(500)),
+            &Grain::Millisecond => None,
         }
     }
 
     pub fn quarter_period(&self) -> Option<PeriodComp> {
         match self {
             &Grain::Year => Some(PeriodComp::months(3)),
+            &Grain::Semester => Some(PeriodComp::days(45)),
             &Grain::Quarter => Some(PeriodComp::days(22)),
             &Grain::Month => Some(PeriodComp::days(7)),
             &Grain::Week => Some(PeriodComp::days(2)),
             &Grain::Day => Some(PeriodComp::hours(6)),
             &Grain::Hour => Some(PeriodComp::minutes(15)),
             &Grain::Minute => Some(PeriodComp::seconds(15)),
-            &Grain::Second => None,
+            &Grain::Second => Some(PeriodComp::milliseconds(250)),
+            &Grain::Millisecond => None,
         }
     }
 
+    /// Rounds sub-second grains down to 0, use `coarse_num_millis` to compare periods.
     pub fn coarse_num_secs(&self) -> i64 {
         match self {
             &Grain::Year => 12 * 30 * 24 * 3600,
+            &Grain::Semester => 6 * 30 * 24 * 3600,
             &Grain::Quarter => 3 * 30 * 24 * 3600,
             &Grain::Month => 30 * 24 * 3600,
             &Grain::Week => 7 * 24 * 3600,
@@ -78,12 +119,20 @@ impl Grain {
             &Grain::Hour => 3600,
             &Grain::Minute => 60,
             &Grain::Second => 1,
+            &Grain::Millisecond => 0,
+        }
+    }
+
+    pub fn coarse_num_millis(&self) -> i64 {
+        match self {
+            &Grain::Millisecond => 1,
+            _ => 1000 * self.coarse_num_secs(),
         }
     }
 
     pub fn is_greater_than_day(&self) -> bool {
         match self {
-            Grain::Week | Grain::Month | Grain::Year | Grain::Quarter => true,
+            Grain::Week | Grain::Month | Grain::Year | Grain::Semester | Grain::Quarter => true,
             _ => false,
         }
     }
@@ -91,6 +140,7 @@ impl Grain {
     pub fn is_date_grain(&self) -> bool {
         match self {
             &Grain::Year => true,
+            &Grain::Semester => true,
             &Grain::Quarter => true,
             &Grain::Month => true,
             &Grain::Week => true,
@@ -98,12 +148,14 @@ impl Grain {
             &Grain::Hour => false,
             &Grain::Minute => false,
             &Grain::Second => false,
+            &Grain::Millisecond => false,
         }
     }
 
     pub fn is_time_grain(&self) -> bool {
         match self {
             &Grain::Year => false,
+            &Grain::Semester => false,
             &Grain::Quarter => false,
             &Grain::Month => false,
             &Grain::Week => false,
@@ -111,6 +163,7 @@ impl Grain {
             &Grain::Hour => true,
             &Grain::Minute => true,
             &Grain::Second => true,
+            &Grain::Millisecond => true,
         }
     }
 }
@@ -118,7 +171,7 @@ impl Grain {
 impl Grain {
     pub fn all() -> Vec<Grain> {
         use enum_primitive::FromPrimitive;
-        (0..8)
+        (0..10)
             .filter_map(|primitive| Grain::from_usize(primitive))
             .collect::<Vec<Grain>>()
     }
@@ -143,24 +196,18 @@ impl PartialEq for Period {
 
 impl Period {
     pub fn finer_grain(&self) -> Option<Grain> {
-        use enum_primitive::FromPrimitive;
-        self.0
-            .iter()
-            .max_by_key(|&(g, _)| g)
-            .and_then(|(g, _)| Grain::from_usize(g))
+        self.comps().last().map(|comp| comp.grain)
     }
 
     pub fn coarser_grain(&self) -> Option<Grain> {
-        use enum_primitive::FromPrimitive;
-        self.0
-            .iter()
-            .min_by_key(|&(g, _)| g)
-            .and_then(|(g, _)| Grain::from_usize(g))
+        self.comps().first().map(|comp| comp.grain)
     }
 
+    /// The components of the period, from the coarsest grain to the finest
     pub fn comps(&self) -> Vec<PeriodComp> {
         use enum_primitive::FromPrimitive;
-        self.0
+        let mut comps = self
+            .0
             .iter()
             .filter_map(|(g, q)| {
                 if let Some(grain) = Grain::from_usize(g) {
@@ -169,12 +216,18 @@ impl Period {
                     None
                 }
             })
-            .collect()
+            .collect::<Vec<_>>();
+        comps.sort_by_key(|comp| comp.grain);
+        comps
     }
 
     pub fn coarse_num_secs(&self) -> i64 {
         self.comps().iter().map(|it| it.coarse_num_secs()).sum()
     }
+
+    pub fn coarse_num_millis(&self) -> i64 {
+        self.comps().iter().map(|it| it.coarse_num_millis()).sum()
+    }
 }
 
 impl From<PeriodComp> for Period {
@@ -215,7 +268,7 @@ impl ops::Add<Period> for Period {
     type Output = Period;
     fn add(self, p: Period) -> Period {
         let mut result = Period::default();
-        for i in 0..8 {
+        for i in 0..10 {
             if !self.0.get(i).is_none() || !p.0.get(i).is_none() {
                 result
                     .0
@@ -230,7 +283,7 @@ impl<'a> ops::Add<&'a Period> for Period {
     type Output = Period;
     fn add(self, p: &'a Period) -> Period {
         let mut result = self;
-        for i in 0..8 {
+        for i in 0..10 {
             if !p.0.get(i).is_none() {
                 *result.0.entry(i).or_insert(0) += *p.0.get(i).unwrap_or(&0);
             }
@@ -243,7 +296,7 @@ impl<'a, 'b> ops::Add<&'a Period> for &'b Period {
     type Output = Period;
     fn add(self, p: &'a Period) -> Period {
         let mut result = Period::default();
-        for i in 0..8 {
+        for i in 0..10 {
             if !self.0.get(i).is_none() || !p.0.get(i).is_none() {
                 result
                     .0
@@ -292,12 +345,22 @@ impl PeriodComp {
         self.grain.coarse_num_secs() * self.quantity
     }
 
+    pub fn coarse_num_millis(&self) -> i64 {
+        self.grain.coarse_num_millis() * self.quantity
+    }
+
     pub fn years(n: i64) -> PeriodComp {
         PeriodComp {
             grain: Grain::Year,
             quantity: n,
         }
     }
+    pub fn semesters(n: i64) -> PeriodComp {
+        PeriodComp {
+            grain: Grain::Semester,
+            quantity: n,
+        }
+    }
     pub fn quarters(n: i64) -> PeriodComp {
         PeriodComp {
             grain: Grain::Quarter,
@@ -340,6 +403,12 @@ impl PeriodComp {
             quantity: n,
         }
     }
+    pub fn milliseconds(n: i64) -> PeriodComp {
+        PeriodComp {
+            grain: Grain::Millisecond,
+            quantity: n,
+        }
+    }
 }
 
 impl ops::Neg for PeriodComp {
@@ -441,5 +510,25 @@ mod tests {
 
         a.0.insert(Grain::Hour as usize, 4);
         assert_eq!(a.finer_grain(), Some(Grain::Hour));
+
+        a.0.insert(Grain::Millisecond as usize, 250);
+        assert_eq!(a.finer_grain(), Some(Grain::Millisecond));
+    }
+
+    #[test]
+    fn grain_order() {
+        assert!(Grain::Year < Grain::Semester);
+        assert!(Grain::Semester < Grain::Quarter);
+        assert!(Grain::Second < Grain::Millisecond);
+        let period = Period::from(PeriodComp::days(1)) + PeriodComp::semesters(1);
+        assert_eq!(Some(Grain::Semester), period.coarser_grain());
+        assert_eq!(Some(Grain::Day), period.finer_grain());
+    }
+
+    #[test]
+    fn coarse_num_millis() {
+        let period = Period::from(PeriodComp::seconds(1)) + PeriodComp::milliseconds(250);
+        assert_eq!(1250, period.coarse_num_millis());
+        assert_eq!(1, period.coarse_num_secs());
     }
 }
@@ -29,11 +29,11 @@ pub use grammar::{dims, Lang};
 pub use rustling::RustlingResult;
 pub use rustling::{AttemptInto, ParsedNode, ParserMatch, ParsingAnalysis, Range, Sym, Value};
 pub use rustling_ontology_moment::Grain;
-pub use rustling_ontology_moment::{Interval, Local, Moment, TimeZone};
+pub use rustling_ontology_moment::{Computus, HolidayDate, Interval, Local, Moment, PeriodComp, TimeZone};
 pub use rustling_ontology_values::dimension;
 pub use rustling_ontology_values::output;
 pub use rustling_ontology_values::output::{Output, OutputKind};
-pub use rustling_ontology_values::{IdentityContext, ParsingContext, ResolverContext};
+pub use rustling_ontology_values::{IdentityContext, ParsingContext, ResolverContext, VagueSpan, VaguenessTable};
 
 mod mapper;
 mod parser;
@@ -194,6 +194,224 @@ mod tests {
         assert_eq!(1521082, int.0);
     }
 
+    #[test]
+    fn test_numeric_fraction_and_date_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        // "3/4" reads as a date unless only numbers are asked for
+        let result = parser.parse("3/4", &ctx).unwrap();
+        assert_eq!(OutputKind::Date, result[0].value.kind());
+        let result = parser
+            .parse_with_kind_order("3/4", &ctx, &[OutputKind::Number])
+            .unwrap();
+        let fraction: output::FractionOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((3, 4), (fraction.numerator, fraction.denominator));
+        // neither a mixed number nor "7/64" can be a date
+        for &(text, numerator, denominator) in &[("1 3/4", 7, 4), ("7/64", 7, 64)] {
+            let result = parser.parse(text, &ctx).unwrap();
+            let fraction: output::FractionOutput = result[0].value.clone().attempt_into().unwrap();
+            assert_eq!((numerator, denominator), (fraction.numerator, fraction.denominator));
+        }
+        // other latent readings follow the order asked for, a bare year being a date first
+        let result = parser
+            .parse_with_kind_order("2013", &ctx, &[OutputKind::Datetime, OutputKind::Number])
+            .unwrap();
+        assert_ne!(OutputKind::Number, result[0].value.kind());
+    }
+
+    #[test]
+    fn test_fuzzy_quantifiers_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("several", &ctx, &[OutputKind::Number])
+            .unwrap();
+        let int: output::FuzzyIntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((4, 3, 7), (int.value, int.min, int.max));
+        let result = parser
+            .parse_with_kind_order("a few hundred", &ctx, &[OutputKind::Number])
+            .unwrap();
+        let int: output::FuzzyIntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((300, 200, 500), (int.value, int.min, int.max));
+        let result = parser
+            .parse_with_kind_order("a few minutes", &ctx, &[OutputKind::Duration])
+            .unwrap();
+        let duration: output::DurationOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(
+            Some((PeriodComp::minutes(2).into(), PeriodComp::minutes(5).into())),
+            duration.fuzzy_range
+        );
+        let result = parser
+            .parse_with_kind_order("a few dollars", &ctx, &[OutputKind::AmountOfMoney])
+            .unwrap();
+        let amount: output::AmountOfMoneyOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Some((2.0, 5.0)), amount.fuzzy_range);
+    }
+
+    #[test]
+    fn test_roman_numerals() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::FR).unwrap();
+        let result = parser
+            .parse_with_kind_order("dix", &ctx, &[OutputKind::Number])
+            .unwrap();
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(10, int.0);
+        // elisions and abbreviations are not numerals
+        for text in &["c'est", "l'homme", "d'accord", "m. dupont"] {
+            assert!(parser
+                .parse_with_kind_order(text, &ctx, &[OutputKind::Number])
+                .unwrap()
+                .is_empty());
+        }
+        let parser = build_parser(Lang::IT).unwrap();
+        for text in &["i libri", "ci vediamo", "vi ringrazio"] {
+            assert!(parser
+                .parse_with_kind_order(text, &ctx, &[OutputKind::Number])
+                .unwrap()
+                .is_empty());
+        }
+        let parser = build_parser(Lang::EN).unwrap();
+        // the input is lower-cased, as the CLI does
+        let result = parser
+            .parse_with_kind_order("louis xiv", &ctx, &[OutputKind::Number])
+            .unwrap();
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(14, int.0);
+        assert!(result[0].latent);
+        for text in &["i", "mix", "cd", "md", "dc"] {
+            assert!(parser.parse(text, &ctx).unwrap().is_empty());
+        }
+        // a latent integer never makes a duration or a date
+        assert!(parser
+            .parse_with_kind_order("in xiv days", &ctx, &[OutputKind::Duration, OutputKind::Datetime])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_nth_day_within_period() {
+        // Tuesday 2013-02-12, as in the training examples
+        let now = Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second);
+        let ctx = ResolverContext::new(now);
+        for &(lang, text) in &[
+            (Lang::EN, "day 366 of 2013"),
+            (Lang::EN, "the 31st day of the month"),
+            (Lang::FR, "le 31ème jour du mois"),
+        ] {
+            let parser = build_parser(lang).unwrap();
+            let result = parser
+                .parse_with_kind_order(text, &ctx, &[OutputKind::Datetime])
+                .unwrap();
+            assert!(result.iter().all(|m| m.byte_range.1 - m.byte_range.0 < text.len()), "{}", text);
+        }
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("day 365 of 2013", &ctx, &[OutputKind::Datetime])
+            .unwrap();
+        let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Moment(Local.ymd(2013, 12, 31).and_hms(0, 0, 0)), datetime.moment);
+    }
+
+    #[test]
+    fn test_first_century_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        // there is no year 0
+        let result = parser
+            .parse_with_kind_order("the 1st century", &ctx, &[OutputKind::Datetime])
+            .unwrap();
+        let interval: output::DatetimeIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+        match interval.interval_kind {
+            output::DatetimeIntervalKind::Between { start, .. } => {
+                assert_eq!(Moment(Local.ymd(1, 1, 1).and_hms(0, 0, 0)), start)
+            }
+            _ => panic!("expected a span"),
+        }
+    }
+
+    #[test]
+    fn test_two_digit_decade_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        // a bare "30s" is an age or a duration, not the 1930s
+        for text in &["in her 30s", "30s"] {
+            let result = parser
+                .parse_with_kind_order(text, &ctx, &[OutputKind::Datetime])
+                .unwrap();
+            assert!(result.iter().all(|m| m.byte_range.1 < text.len()), "{}", text);
+        }
+        let result = parser
+            .parse_with_kind_order("in the 30s", &ctx, &[OutputKind::Datetime])
+            .unwrap();
+        let interval: output::DatetimeIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+        match interval.interval_kind {
+            output::DatetimeIntervalKind::Between { start, .. } => {
+                assert_eq!(Moment(Local.ymd(1930, 1, 1).and_hms(0, 0, 0)), start)
+            }
+            _ => panic!("expected a span"),
+        }
+    }
+
+    #[test]
+    fn test_observed_holidays_en() {
+        let ctx = ResolverContext::default().with_holiday_date(HolidayDate::Observed);
+        let parser = build_parser(Lang::EN).unwrap();
+        // Saturday 2022-01-01 is observed in the year before
+        let result = parser
+            .parse_with_kind_order("new year's day 2022", &ctx, &[OutputKind::Datetime])
+            .unwrap();
+        let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Moment(Local.ymd(2021, 12, 31).and_hms(0, 0, 0)), datetime.moment);
+        // only fixed-date holidays are moved off the weekend
+        for text in &["easter holiday", "march 3rd day off"] {
+            let result = parser
+                .parse_with_kind_order(text, &ctx, &[OutputKind::Datetime])
+                .unwrap();
+            assert!(result.iter().all(|m| m.byte_range.1 - m.byte_range.0 < text.len()), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_japanese_era_initials() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::JA).unwrap();
+        // the input is lower-cased, as the CLI does
+        let result = parser
+            .parse_with_kind_order("h30.4.1", &ctx, &[OutputKind::Datetime])
+            .unwrap();
+        let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Moment(Local.ymd(2018, 4, 1).and_hms(0, 0, 0)), datetime.moment);
+        let result = parser
+            .parse_with_kind_order("h30", &ctx, &[OutputKind::Datetime])
+            .unwrap();
+        assert!(result[0].latent);
+        // not an era year inside a longer number or an invalid date
+        for text in &["h300", "s50.13.1"] {
+            let result = parser
+                .parse_with_kind_order(text, &ctx, &[OutputKind::Datetime])
+                .unwrap();
+            assert!(result.iter().all(|m| m.byte_range.0 > 0), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_timestamps_all_langs() {
+        let ctx = ResolverContext::default();
+        // the timestamp rules are shared, so they must parse the same way in every language
+        for lang in Lang::all() {
+            let parser = build_parser(lang).unwrap();
+            for &text in &["2019-03-04T10:00:00+02:00", "2019-03-04 08:00:00Z", "@1551686400"] {
+                let result = parser
+                    .parse_with_kind_order(text, &ctx, &[OutputKind::Datetime])
+                    .unwrap();
+                assert_eq!((0, text.len()), (result[0].byte_range.0, result[0].byte_range.1));
+                let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+                assert_eq!(1551686400, datetime.moment.0.timestamp());
+            }
+        }
+    }
+
     #[test]
     #[ignore]
     fn time_resolve_complex_train_sentence() {
@@ -1,5 +1,6 @@
 use crate::mapper;
 use rustling::{Candidate, MaxElementTagger, ParsedNode, ParserMatch, Range, Value};
+use rustling_ontology_values::dimension::NumberValue;
 use rustling_ontology_values::{Dimension, OutputKind, ParsingContext};
 use std::cmp::{Ordering, PartialOrd};
 
@@ -57,6 +58,7 @@ impl<'a, C: ParsingContext<Dimension>> MaxElementTagger<Dimension> for Candidate
                 .len()
                 .cmp(&b.1.byte_range.len())
                 .then_with(|| a.1.byte_range.0.cmp(&b.1.byte_range.0))
+                .then_with(|| fraction_date_order(&a.1.value, &b.1.value))
                 .then_with(|| a.2.cmp(&b.2))
                 .then_with(|| {
                     if a.1.value.kind() == b.1.value.kind() {
@@ -121,3 +123,12 @@ impl<'a, C: ParsingContext<Dimension>> MaxElementTagger<Dimension> for Candidate
             .collect()
     }
 }
+
+// A numeric fraction that could be a date, e.g. "3/4", gives way to the date
+fn fraction_date_order(a: &Dimension, b: &Dimension) -> Ordering {
+    match (a, b) {
+        (&Dimension::Number(NumberValue::Float(ref float)), &Dimension::Datetime(_)) if float.latent => Ordering::Less,
+        (&Dimension::Datetime(_), &Dimension::Number(NumberValue::Float(ref float))) if float.latent => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
@@ -21,6 +21,27 @@ pub fn check_integer(v: i64) -> CheckInteger {
     CheckInteger { value: v }
 }
 
+#[derive(Debug)]
+pub struct CheckFuzzyInteger {
+    pub value: i64,
+    pub range: (i64, i64),
+}
+
+impl Check<Dimension> for CheckFuzzyInteger {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        IntegerValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.fuzzy_range == Some(self.range))
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_fuzzy_integer(v: i64, min: i64, max: i64) -> CheckFuzzyInteger {
+    CheckFuzzyInteger {
+        value: v,
+        range: (min, max),
+    }
+}
+
 #[derive(Debug)]
 pub struct CheckOrdinal {
     pub value: i64,
@@ -55,6 +76,33 @@ pub fn check_float(v: f64) -> CheckFloat {
     CheckFloat { value: v }
 }
 
+#[derive(Debug)]
+pub struct CheckFraction {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+impl Check<Dimension> for CheckFraction {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        FloatValue::attempt_from(pn.value.clone())
+            .map(|v| {
+                v.fraction
+                    == Some(Fraction {
+                        numerator: self.numerator,
+                        denominator: self.denominator,
+                    })
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_fraction(numerator: i64, denominator: i64) -> CheckFraction {
+    CheckFraction {
+        numerator,
+        denominator,
+    }
+}
+
 #[derive(Debug)]
 pub struct CheckDuration {
     pub period: Period,
@@ -246,3 +294,53 @@ impl Check<Dimension> for CheckTemperature {
 pub fn check_temperature(value: f64, unit: Option<&'static str>) -> CheckTemperature {
     CheckTemperature { value, unit }
 }
+
+#[derive(Debug)]
+pub struct CheckTemperatureInterval {
+    pub min: f64,
+    pub max: f64,
+    pub unit: Option<&'static str>,
+}
+
+impl Check<Dimension> for CheckTemperatureInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        TemperatureIntervalValue::attempt_from(pn.value.clone())
+            .map(|v| v.min == self.min && v.max == self.max && v.unit == self.unit)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_temperature_interval(
+    min: f64,
+    max: f64,
+    unit: Option<&'static str>,
+) -> CheckTemperatureInterval {
+    CheckTemperatureInterval { min, max, unit }
+}
+
+#[derive(Debug)]
+pub struct CheckDurationInterval {
+    pub min: Period,
+    pub max: Period,
+    pub precision: Precision,
+}
+
+impl Check<Dimension> for CheckDurationInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        DurationIntervalValue::attempt_from(pn.value.clone())
+            .map(|v| v.precision == self.precision && v.min == self.min && v.max == self.max)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_duration_interval(
+    min: Period,
+    max: Period,
+    precision: Precision,
+) -> CheckDurationInterval {
+    CheckDurationInterval {
+        min,
+        max,
+        precision,
+    }
+}
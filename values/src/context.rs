@@ -28,9 +28,67 @@ impl<V: Value + Clone> ParsingContext<V> for IdentityContext<V> {
     }
 }
 
+/// Span of a vague expression, as offsets from the reference moment
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VagueSpan {
+    pub start: PeriodComp,
+    pub end: PeriodComp,
+}
+
+impl VagueSpan {
+    pub fn new(start: PeriodComp, end: PeriodComp) -> VagueSpan {
+        VagueSpan { start, end }
+    }
+}
+
+/// Calibration of the spans vague expressions such as "soon" or "recently" resolve to.
+/// Grammars only tag these expressions with a `Vagueness`, the span is read from this table
+/// relative to the reference when resolving, so that it can be tuned per application.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VaguenessTable {
+    pub shortly: VagueSpan,
+    pub soon: VagueSpan,
+    pub in_a_while: VagueSpan,
+    /// The end of the span is capped to the end of the reference day
+    pub later_today: VagueSpan,
+    /// Ends at the reference itself
+    pub recently: VagueSpan,
+    pub coming_days: VagueSpan,
+    pub few_weeks_ago: VagueSpan,
+}
+
+impl VaguenessTable {
+    pub fn span(&self, vagueness: Vagueness) -> VagueSpan {
+        match vagueness {
+            Vagueness::Shortly => self.shortly,
+            Vagueness::Soon => self.soon,
+            Vagueness::InAWhile => self.in_a_while,
+            Vagueness::LaterToday => self.later_today,
+            Vagueness::Recently => self.recently,
+            Vagueness::ComingDays => self.coming_days,
+            Vagueness::FewWeeksAgo => self.few_weeks_ago,
+        }
+    }
+}
+
+impl Default for VaguenessTable {
+    fn default() -> VaguenessTable {
+        VaguenessTable {
+            shortly: VagueSpan::new(PeriodComp::minutes(0), PeriodComp::minutes(30)),
+            soon: VagueSpan::new(PeriodComp::minutes(0), PeriodComp::hours(3)),
+            in_a_while: VagueSpan::new(PeriodComp::minutes(30), PeriodComp::hours(3)),
+            later_today: VagueSpan::new(PeriodComp::hours(1), PeriodComp::hours(24)),
+            recently: VagueSpan::new(PeriodComp::days(-7), PeriodComp::days(0)),
+            coming_days: VagueSpan::new(PeriodComp::days(1), PeriodComp::days(6)),
+            few_weeks_ago: VagueSpan::new(PeriodComp::weeks(-5), PeriodComp::weeks(-1)),
+        }
+    }
+}
+
 #[derive(Default, Debug, Copy, Clone)]
 pub struct ResolverContext {
     ctx: Context<Local>,
+    vagueness: VaguenessTable,
 }
 
 impl ResolverContext {
@@ -42,8 +100,55 @@ impl ResolverContext {
     pub fn new(now: Interval<Local>) -> ResolverContext {
         ResolverContext {
             ctx: Context::for_reference(now),
+            vagueness: VaguenessTable::default(),
+        }
+    }
+
+    pub fn with_vagueness_table(self, vagueness: VaguenessTable) -> ResolverContext {
+        ResolverContext { vagueness, ..self }
+    }
+
+    /// Picks the Easter the celebrations refer to, e.g. `Computus::Orthodox`
+    /// for Greek or Russian users.
+    pub fn with_computus(self, computus: Computus) -> ResolverContext {
+        ResolverContext {
+            ctx: self.ctx.with_computus(computus),
+            ..self
         }
     }
+
+    /// Picks whether holidays falling on a weekend resolve to their calendar date
+    /// or to the weekday they are observed on.
+    pub fn with_holiday_date(self, holiday_date: HolidayDate) -> ResolverContext {
+        ResolverContext {
+            ctx: self.ctx.with_holiday_date(holiday_date),
+            ..self
+        }
+    }
+
+    fn resolve_vague(&self, vagueness: Vagueness, datetime_value: &DatetimeValue) -> Option<Output> {
+        let span = self.vagueness.span(vagueness);
+        let grain = vagueness.grain();
+        let now = self.ctx.reference.start;
+        let start = (now + span.start).round_to(grain);
+        // A span ending at the reference, e.g. "recently", ends now and not at the start of the day
+        let mut end = if span.end.quantity == 0 { now } else { (now + span.end).round_to(grain) };
+        if vagueness == Vagueness::LaterToday {
+            end = ::std::cmp::min(end, (now + PeriodComp::days(1)).round_to(Grain::Day));
+        }
+        if start >= end {
+            return None;
+        }
+        Some(Output::DatetimeInterval(DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::Between {
+                start,
+                end,
+                precision: datetime_value.precision,
+                latent: datetime_value.latent,
+            },
+            datetime_kind: datetime_value.datetime_kind,
+        }))
+    }
 }
 
 impl ParsingContext<Dimension> for ResolverContext {
@@ -52,6 +157,9 @@ impl ParsingContext<Dimension> for ResolverContext {
     fn resolve(&self, dim: &Dimension) -> Option<Output> {
         match dim {
             &Dimension::Datetime(ref datetime_value) => {
+                if let Form::Vague(vagueness) = datetime_value.form {
+                    return self.resolve_vague(vagueness, datetime_value);
+                }
                 let mut walker = datetime_value
                     .constraint
                     .to_walker(&self.ctx.reference, &self.ctx);
@@ -136,8 +244,22 @@ impl ParsingContext<Dimension> for ResolverContext {
                     })
             }
             &Dimension::Number(ref number) => match number {
-                &NumberValue::Integer(ref v) => Some(Output::Integer(IntegerOutput(v.value))),
-                &NumberValue::Float(ref v) => Some(Output::Float(FloatOutput(v.value))),
+                &NumberValue::Integer(ref v) => match v.fuzzy_range {
+                    Some((min, max)) => Some(Output::FuzzyInteger(FuzzyIntegerOutput {
+                        value: v.value,
+                        min,
+                        max,
+                    })),
+                    None => Some(Output::Integer(IntegerOutput(v.value))),
+                },
+                &NumberValue::Float(ref v) => match v.fraction {
+                    Some(fraction) => Some(Output::Fraction(FractionOutput {
+                        value: v.value,
+                        numerator: fraction.numerator,
+                        denominator: fraction.denominator,
+                    })),
+                    None => Some(Output::Float(FloatOutput(v.value))),
+                },
             },
             &Dimension::Ordinal(ref ordinal) => Some(Output::Ordinal(OrdinalOutput(ordinal.value))),
             &Dimension::AmountOfMoney(ref aom) => {
@@ -145,6 +267,7 @@ impl ParsingContext<Dimension> for ResolverContext {
                     value: aom.value,
                     precision: aom.precision,
                     unit: aom.unit,
+                    fuzzy_range: aom.fuzzy_range,
                 }))
             }
             &Dimension::Temperature(ref temp) => Some(Output::Temperature(TemperatureOutput {
@@ -155,7 +278,23 @@ impl ParsingContext<Dimension> for ResolverContext {
             &Dimension::Duration(ref duration) => Some(Output::Duration(DurationOutput {
                 period: duration.period.clone(),
                 precision: duration.precision,
+                fuzzy_range: duration.fuzzy_range.clone(),
             })),
+            &Dimension::TemperatureInterval(ref temp) => {
+                Some(Output::TemperatureInterval(TemperatureIntervalOutput {
+                    min: temp.min,
+                    max: temp.max,
+                    unit: temp.unit,
+                    latent: temp.latent,
+                }))
+            }
+            &Dimension::DurationInterval(ref duration) => {
+                Some(Output::DurationInterval(DurationIntervalOutput {
+                    min: duration.min.clone(),
+                    max: duration.max.clone(),
+                    precision: duration.precision,
+                }))
+            }
             &Dimension::Percentage(ref percentage) => {
                 Some(Output::Percentage(PercentageOutput(percentage.0)))
             }
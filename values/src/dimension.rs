@@ -1,4 +1,4 @@
-use moment::{Grain, Local, Period, RcConstraint};
+use moment::{Grain, HolidayDate, Local, Observance, Period, PeriodComp, RcConstraint};
 use rustling::*;
 use std::{fmt, result};
 
@@ -12,9 +12,11 @@ rustling_value! {
         AmountOfMoney(AmountOfMoneyValue),
         Ordinal(OrdinalValue),
         Temperature(TemperatureValue),
+        TemperatureInterval(TemperatureIntervalValue),
         MoneyUnit(MoneyUnitValue),
         Datetime(DatetimeValue),
         Duration(DurationValue),
+        DurationInterval(DurationIntervalValue),
         Percentage(PercentageValue),
         Cycle(CycleValue),
         UnitOfDuration(UnitOfDurationValue),
@@ -23,14 +25,16 @@ rustling_value! {
 
     fn latent(v: &Dimension) -> bool {
         match v {
-            &Dimension::Number(_) => false,
+            &Dimension::Number(ref number) => number.latent(),
             &Dimension::Percentage(_) => false,
             &Dimension::AmountOfMoney(_) => false,
             &Dimension::Ordinal(_) => false,
             &Dimension::Temperature(ref temp) => temp.latent,
+            &Dimension::TemperatureInterval(ref temp) => temp.latent,
             &Dimension::MoneyUnit(_) => true,
             &Dimension::Datetime(ref dtv) => dtv.latent,
             &Dimension::Duration(_) => false,
+            &Dimension::DurationInterval(_) => false,
             &Dimension::Cycle(_) => true,
             &Dimension::UnitOfDuration(_) => true,
             &Dimension::RelativeMinute(_) => true,
@@ -44,9 +48,11 @@ rustling_value! {
             &Dimension::AmountOfMoney(_) => None,
             &Dimension::Ordinal(_) => None,
             &Dimension::Temperature(_) => None,
+            &Dimension::TemperatureInterval(_) => None,
             &Dimension::MoneyUnit(_) => None,
             &Dimension::Datetime(ref dtv) => Some(Payload(dtv.constraint.grain())),
             &Dimension::Duration(_) => None,
+            &Dimension::DurationInterval(_) => None,
             &Dimension::Cycle(_) => None,
             &Dimension::UnitOfDuration(_) => None,
             &Dimension::RelativeMinute(_) => None,
@@ -62,9 +68,11 @@ impl Dimension {
             &Dimension::AmountOfMoney(_) => false,
             &Dimension::Ordinal(_) => false,
             &Dimension::Temperature(_) => false,
+            &Dimension::TemperatureInterval(_) => false,
             &Dimension::MoneyUnit(_) => false,
             &Dimension::Datetime(ref dtv) => dtv.is_too_ambiguous(),
             &Dimension::Duration(_) => false,
+            &Dimension::DurationInterval(_) => false,
             &Dimension::Cycle(_) => true,
             &Dimension::UnitOfDuration(_) => true,
             &Dimension::RelativeMinute(_) => true,
@@ -85,10 +93,12 @@ impl fmt::Display for Dimension {
             &Dimension::Percentage(ref v) => write!(fmt, "Percentage: {}", v.0),
             &Dimension::Ordinal(_) => write!(fmt, "Ordinal"),
             &Dimension::Temperature(_) => write!(fmt, "Temperature"),
+            &Dimension::TemperatureInterval(_) => write!(fmt, "TemperatureInterval"),
             &Dimension::AmountOfMoney(_) => write!(fmt, "AmountOfMoney"),
             &Dimension::MoneyUnit(_) => write!(fmt, "MoneyUnit"),
             &Dimension::Datetime(_) => write!(fmt, "Datetime"),
             &Dimension::Duration(_) => write!(fmt, "Duration"),
+            &Dimension::DurationInterval(_) => write!(fmt, "DurationInterval"),
             &Dimension::Cycle(_) => write!(fmt, "Cycle"),
             &Dimension::UnitOfDuration(_) => write!(fmt, "UnitOfDuration"),
             &Dimension::RelativeMinute(_) => write!(fmt, "RelativeMinute"),
@@ -148,6 +158,8 @@ pub struct AmountOfMoneyValue {
     pub value: f64,
     pub precision: Precision,
     pub unit: Option<&'static str>,
+    /// Range of an amount counted with a fuzzy quantifier, e.g. "a few dollars"
+    pub fuzzy_range: Option<(f64, f64)>,
 }
 
 /// Payload for the unit of money value of Dimension
@@ -178,6 +190,11 @@ pub struct IntegerValue {
     pub combine_from: Option<CombinationDirection>,
     #[doc(hidden)]
     pub precision: Precision,
+    /// Set for standalone roman numerals, e.g. "I" or "mix"
+    pub latent: bool,
+    /// Range covered by a fuzzy quantifier, e.g. (2, 4) for "a few", `value` being its
+    /// representative value
+    pub fuzzy_range: Option<(i64, i64)>,
 }
 
 impl IntegerValue {
@@ -196,6 +213,19 @@ impl IntegerValue {
         })
     }
 
+    pub fn new_fuzzy(value: i64, min: i64, max: i64) -> RuleResult<IntegerValue> {
+        if min > value || value > max {
+            return Err(RuleError::Invalid.into());
+        }
+        Ok(IntegerValue {
+            value,
+            grain: Some(1),
+            precision: Precision::Approximate,
+            fuzzy_range: Some((min, max)),
+            ..IntegerValue::default()
+        })
+    }
+
     pub fn with_grain(self, grain: Option<u8>) -> RuleResult<IntegerValue> {
         Ok(IntegerValue { grain, ..self })
     }
@@ -322,6 +352,17 @@ pub struct FloatValue {
     pub combine_from: Option<CombinationDirection>,
     #[doc(hidden)]
     pub precision: Precision,
+    /// Set when the value was expressed as a fraction, e.g. "two thirds" or "1 1/2"
+    pub fraction: Option<Fraction>,
+    /// Set for numeric fractions that could also be read as a date, e.g. "3/4"
+    pub latent: bool,
+}
+
+/// Numerator and denominator of a fraction, mixed numbers are stored as improper fractions
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Fraction {
+    pub numerator: i64,
+    pub denominator: i64,
 }
 
 impl FloatValue {
@@ -392,6 +433,35 @@ impl NumberValue {
         }
     }
 
+    pub fn latent(&self) -> bool {
+        match self {
+            &NumberValue::Float(ref v) => v.latent,
+            &NumberValue::Integer(ref v) => v.latent,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn is_latent_integer(&self) -> bool {
+        match self {
+            &NumberValue::Float(_) => false,
+            &NumberValue::Integer(ref v) => v.latent,
+        }
+    }
+
+    pub fn precision(&self) -> Precision {
+        match self {
+            &NumberValue::Float(ref v) => v.precision,
+            &NumberValue::Integer(ref v) => v.precision,
+        }
+    }
+
+    pub fn fuzzy_range(&self) -> Option<(f64, f64)> {
+        match self {
+            &NumberValue::Float(_) => None,
+            &NumberValue::Integer(ref v) => v.fuzzy_range.map(|(min, max)| (min as f64, max as f64)),
+        }
+    }
+
     #[doc(hidden)]
     pub fn combine_from(self, direction: CombinationDirection) -> RuleResult<NumberValue> {
         match self {
@@ -454,6 +524,17 @@ pub struct TemperatureValue {
     pub latent: bool,
 }
 
+/// Payload for the temperature intervals value of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct TemperatureIntervalValue {
+    pub min: f64,
+    pub max: f64,
+    /// Celsius, Fahrenheit, ... shared by both bounds
+    pub unit: Option<&'static str>,
+    /// true if it can not be confirmed that the value is actually a temperature interval
+    pub latent: bool,
+}
+
 /// Payload for the cycle value of Dimension
 #[derive(Debug, PartialEq, Clone)]
 pub struct CycleValue {
@@ -517,6 +598,17 @@ pub struct DatetimeValue {
     pub latent: bool,
     pub ambiguity: Ambiguity,
     pub datetime_kind: DatetimeKind,
+    /// Set for a holiday moved off the weekend
+    pub observed: Option<ObservedHoliday>,
+}
+
+/// Holiday before it is moved off the weekend, to intersect it with its year first: new year's
+/// day 2022 is observed on Friday 2021-12-31.
+#[derive(Clone)]
+pub struct ObservedHoliday {
+    pub nominal: RcConstraint<Local>,
+    pub observance: Observance,
+    pub holiday_date: Option<HolidayDate>,
 }
 
 // We need partial eq to make Dimension partial eq happy, but this is only
@@ -542,11 +634,11 @@ impl ::std::fmt::Debug for DatetimeValue {
 
 impl DatetimeValue {
     pub fn is_coarse_grain_smaller_than(&self, grain: Grain) -> bool {
-        (self.constraint.coarse_grain_step() as usize) < (grain as usize)
+        self.constraint.coarse_grain_step() < grain
     }
 
     pub fn is_coarse_grain_greater_than(&self, grain: Grain) -> bool {
-        (self.constraint.coarse_grain_step() as usize) > (grain as usize)
+        self.constraint.coarse_grain_step() > grain
     }
 
     pub fn has_period_form(&self) -> bool {
@@ -554,6 +646,7 @@ impl DatetimeValue {
             Form::Cycle(grain) => match grain {
                 Grain::Day => false,
                 Grain::Second => false,
+                Grain::Millisecond => false,
                 _ => true,
             },
             Form::Year(_) => true,
@@ -573,6 +666,10 @@ impl DatetimeValue {
             Form::PartOfForm(_) => true,
             Form::PartOfWeek => true,
             Form::Span => true,
+            Form::Decade(_) => true,
+            Form::Century(_) => true,
+            Form::IsoWeek(_) => true,
+            Form::Vague(_) => true,
         }
     }
 
@@ -580,6 +677,7 @@ impl DatetimeValue {
         match self.constraint.grain() {
             Grain::Week => true,
             Grain::Month => true,
+            Grain::Semester => true,
             Grain::Quarter => true,
             Grain::Year => true,
             _ => false,
@@ -623,6 +721,15 @@ pub enum Form {
     Meal,
     Celebration,
     Span,
+    /// First year of the decade, e.g. 1990 for "the 1990s", or its last two digits when the
+    /// century is not given, e.g. 90 for "the nineties"
+    Decade(i32),
+    /// First year of the century, e.g. 1800 for "the 19th century"
+    Century(i32),
+    /// ISO 8601 week number, e.g. 42 for "week 42"
+    IsoWeek(u32),
+    /// Vague expression resolved against the vagueness table of the context, e.g. "soon"
+    Vague(Vagueness),
     Empty,
 }
 
@@ -647,6 +754,10 @@ impl Form {
             &Form::PartOfForm(_) => None,
             &Form::PartOfWeek => None,
             &Form::Span => None,
+            &Form::Decade(_) => None,
+            &Form::Century(_) => None,
+            &Form::IsoWeek(_) => None,
+            &Form::Vague(_) => None,
         }
     }
 
@@ -847,6 +958,32 @@ impl TimeOfDayForm {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vagueness {
+    Shortly,
+    Soon,
+    InAWhile,
+    LaterToday,
+    Recently,
+    ComingDays,
+    FewWeeksAgo,
+}
+
+impl Vagueness {
+    /// Grain the bounds of the resolved span are rounded to
+    pub fn grain(&self) -> Grain {
+        match self {
+            &Vagueness::Shortly => Grain::Minute,
+            &Vagueness::Soon => Grain::Minute,
+            &Vagueness::InAWhile => Grain::Minute,
+            &Vagueness::LaterToday => Grain::Minute,
+            &Vagueness::Recently => Grain::Day,
+            &Vagueness::ComingDays => Grain::Day,
+            &Vagueness::FewWeeksAgo => Grain::Week,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct MonthDayForm {
     pub month: u32,
@@ -876,6 +1013,8 @@ pub struct DurationValue {
     pub suffixed: bool,
     pub prefixed: bool,
     pub from_addition: Option<FromAddition>,
+    /// Range of a duration counted with a fuzzy quantifier, e.g. "a few minutes"
+    pub fuzzy_range: Option<(Period, Period)>,
 }
 
 impl DurationValue {
@@ -886,9 +1025,28 @@ impl DurationValue {
             suffixed: false,
             prefixed: false,
             from_addition: None,
+            fuzzy_range: None,
+        }
+    }
+
+    /// Duration of `integer` units of `grain`, keeping its precision and fuzzy range
+    pub fn from_integer(grain: Grain, integer: &IntegerValue) -> DurationValue {
+        DurationValue {
+            precision: integer.precision,
+            fuzzy_range: integer.fuzzy_range.map(|(min, max)| {
+                (PeriodComp::new(grain, min).into(), PeriodComp::new(grain, max).into())
+            }),
+            ..DurationValue::new(PeriodComp::new(grain, integer.value).into())
         }
     }
 
+    /// Shortest and longest periods of the duration, both its period unless it is fuzzy
+    pub fn bounds(&self) -> (Period, Period) {
+        self.fuzzy_range
+            .clone()
+            .unwrap_or_else(|| (self.period.clone(), self.period.clone()))
+    }
+
     pub fn precision(self, precision: Precision) -> DurationValue {
         DurationValue { precision, ..self }
     }
@@ -943,6 +1101,14 @@ impl DurationValue {
     }
 }
 
+/// Payload for the duration intervals value of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct DurationIntervalValue {
+    pub min: Period,
+    pub max: Period,
+    pub precision: Precision,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct RelativeMinuteValue {
     pub value: i32,
@@ -1,10 +1,14 @@
 use crate::dimension::*;
+use moment::japanese_era::JapaneseEra;
 use moment::*;
 use regex::Regex;
 use rustling::{RuleError, RuleResult};
 use std::ops;
 
 pub fn compose_numbers(a: &NumberValue, b: &NumberValue) -> RuleResult<NumberValue> {
+    if a.latent() || b.latent() {
+        return Err(RuleError::Invalid.into());
+    }
     let grain = a.grain().unwrap_or(0) as u32;
     if 10u64.pow(grain) as f64 > b.value() && a.value() >= 0.0 && b.value() >= 0.0 {
         match (a, b) {
@@ -75,6 +79,53 @@ pub fn decimal_hour_in_minute(a: &str, b: &str) -> RuleResult<i64> {
     Ok((b_value * 6) / 10i64.pow(b.len() as u32 - 1) + a_value * 60)
 }
 
+fn period_of_comps(comps: &[PeriodComp]) -> Period {
+    comps
+        .iter()
+        .filter(|comp| comp.quantity != 0)
+        .fold(Period::default(), |period, comp| period + comp)
+}
+
+pub fn decimal_seconds(seconds: f64) -> RuleResult<DurationValue> {
+    // e.g. 1.25 => 1 second and 250 milliseconds
+    if seconds <= 0.0 {
+        return Err(RuleError::Invalid.into());
+    }
+    let millis = (seconds * 1000.0).round() as i64;
+    Ok(DurationValue::new(period_of_comps(&[
+        PeriodComp::seconds(millis / 1000),
+        PeriodComp::milliseconds(millis % 1000),
+    ])))
+}
+
+pub fn stopwatch_duration(text: &str) -> RuleResult<DurationValue> {
+    // e.g. "1:23.45" for a lap time of 1 minute, 23 seconds and 450 milliseconds, or "1:02:03.5"
+    let separator = text.find(|c| c == '.' || c == ',').ok_or(RuleError::Invalid)?;
+    let fraction = &text[separator + 1..];
+    if fraction.is_empty() || fraction.len() > 3 {
+        return Err(RuleError::Invalid.into());
+    }
+    let fields = text[..separator]
+        .split(':')
+        .map(|field| field.parse())
+        .collect::<Result<Vec<i64>, _>>()?;
+    let (hours, minutes, seconds) = match fields.as_slice() {
+        &[minutes, seconds] => (0, minutes, seconds),
+        &[hours, minutes, seconds] if minutes < 60 => (hours, minutes, seconds),
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    if seconds >= 60 {
+        return Err(RuleError::Invalid.into());
+    }
+    let millis: i64 = format!("{:0<3}", fraction).parse()?;
+    Ok(DurationValue::new(period_of_comps(&[
+        PeriodComp::hours(hours),
+        PeriodComp::minutes(minutes),
+        PeriodComp::seconds(seconds),
+        PeriodComp::milliseconds(millis),
+    ])))
+}
+
 pub fn compose_money(
     a: &AmountOfMoneyValue,
     b: &AmountOfMoneyValue,
@@ -91,6 +142,10 @@ pub fn compose_money_number(
     a: &AmountOfMoneyValue,
     b: &NumberValue,
 ) -> RuleResult<AmountOfMoneyValue> {
+    // "2 dollars 50" reads as cents, but "2 dollars 5k" or "rs 2 5 lakh" must not
+    if b.suffixed() || b.value() >= 100.0 {
+        return Err(RuleError::Invalid.into());
+    }
     let amount = a.value + b.value() / 100.0;
     Ok(AmountOfMoneyValue {
         value: amount,
@@ -99,6 +154,205 @@ pub fn compose_money_number(
     })
 }
 
+pub fn compose_magnitude(number: &NumberValue, multiplier: f64) -> RuleResult<NumberValue> {
+    let product = number.value() * multiplier;
+    if !product.is_finite() {
+        return Err(RuleError::Invalid.into());
+    }
+    // Keep an integer whenever the magnitude leaves no decimal part, e.g. "1.5k" or "1e6"
+    if product.fract() == 0.0 && product.abs() < 1e18 {
+        let integer = match number {
+            &NumberValue::Integer(ref integer) => integer.clone(),
+            &NumberValue::Float(ref float) => IntegerValue {
+                prefixed: float.prefixed,
+                precision: float.precision,
+                ..IntegerValue::default()
+            },
+        };
+        Ok(IntegerValue {
+            value: product as i64,
+            suffixed: true,
+            fuzzy_range: integer
+                .fuzzy_range
+                .map(|(min, max)| ((min as f64 * multiplier) as i64, (max as f64 * multiplier) as i64)),
+            ..integer
+        }
+        .into())
+    } else {
+        let float = match number {
+            &NumberValue::Float(ref float) => float.clone(),
+            &NumberValue::Integer(ref integer) => FloatValue {
+                prefixed: integer.prefixed,
+                precision: integer.precision,
+                ..FloatValue::default()
+            },
+        };
+        Ok(FloatValue {
+            value: product,
+            suffixed: true,
+            fraction: None,
+            ..float
+        }
+        .into())
+    }
+}
+
+pub fn compose_power(mantissa: f64, base: f64, exponent: i32) -> RuleResult<NumberValue> {
+    compose_magnitude(&FloatValue::new(mantissa)?.into(), base.powi(exponent))
+}
+
+pub fn negate_number(number: &NumberValue) -> RuleResult<NumberValue> {
+    Ok(match number.clone() {
+        NumberValue::Integer(integer) => IntegerValue {
+            value: -integer.value,
+            prefixed: true,
+            ..integer
+        }
+        .into(),
+        NumberValue::Float(float) => FloatValue {
+            value: -float.value,
+            fraction: float.fraction.map(|fraction| -fraction),
+            prefixed: true,
+            ..float
+        }
+        .into(),
+    })
+}
+
+pub fn temperature_below_zero(temp: &TemperatureValue) -> RuleResult<TemperatureValue> {
+    // "5 below zero" and "-5 below zero" both mean -5
+    Ok(TemperatureValue {
+        value: -temp.value.abs(),
+        latent: false,
+        ..*temp
+    })
+}
+
+pub fn compose_temperature_interval(
+    min: &TemperatureValue,
+    max: &TemperatureValue,
+) -> RuleResult<TemperatureIntervalValue> {
+    if min.value >= max.value {
+        return Err(RuleError::Invalid.into());
+    }
+    // A bound without unit inherits the unit of the other bound, e.g. "20 to 25 degrees celsius"
+    let unit = match (min.unit, max.unit) {
+        (None, unit) | (unit, None) => unit,
+        (Some(lhs), Some(rhs)) if lhs == rhs => Some(lhs),
+        (Some("degree"), unit) | (unit, Some("degree")) => unit,
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    Ok(TemperatureIntervalValue {
+        min: min.value,
+        max: max.value,
+        unit,
+        latent: min.latent && max.latent,
+    })
+}
+
+pub fn compose_duration_interval(
+    min: &DurationValue,
+    max: &DurationValue,
+) -> RuleResult<DurationIntervalValue> {
+    if min.period.coarse_num_millis() >= max.period.coarse_num_millis() {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(DurationIntervalValue {
+        min: min.period.clone(),
+        max: max.period.clone(),
+        precision: precision_resolution(min.precision, max.precision),
+    })
+}
+
+pub fn compose_duration_interval_with_integer(
+    min: &IntegerValue,
+    max: &DurationValue,
+) -> RuleResult<DurationIntervalValue> {
+    // The integer inherits the unit of the duration, e.g. "2 to 3 hours"
+    let grain = max
+        .period
+        .finer_grain()
+        .ok_or_else(|| -> RuleError { RuleError::Invalid.into() })?;
+    if max.period.coarser_grain() != Some(grain) {
+        return Err(RuleError::Invalid.into());
+    }
+    let min = DurationValue::new(PeriodComp::new(grain, min.value).into()).precision(min.precision);
+    compose_duration_interval(&min, max)
+}
+
+const ROMAN_NUMERALS: [(i64, &str); 13] = [
+    (1000, "m"), (900, "cm"), (500, "d"), (400, "cd"), (100, "c"), (90, "xc"), (50, "l"),
+    (40, "xl"), (10, "x"), (9, "ix"), (5, "v"), (4, "iv"), (1, "i"),
+];
+
+pub fn roman_numeral(text: &str) -> RuleResult<i64> {
+    let text = text.to_lowercase();
+    let mut rest = text.as_str();
+    let mut value = 0;
+    for &(numeral_value, numeral) in ROMAN_NUMERALS.iter() {
+        while rest.starts_with(numeral) {
+            value += numeral_value;
+            rest = &rest[numeral.len()..];
+        }
+    }
+    // Only accept the canonical form, so that "iiii", "xcx" or "mil" are rejected
+    if !rest.is_empty() || value == 0 || value >= 4000 || to_roman_numeral(value) != text {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(value)
+}
+
+fn to_roman_numeral(mut value: i64) -> String {
+    let mut text = String::new();
+    for &(numeral_value, numeral) in ROMAN_NUMERALS.iter() {
+        while value >= numeral_value {
+            text.push_str(numeral);
+            value -= numeral_value;
+        }
+    }
+    text
+}
+
+pub fn fraction(numerator: i64, denominator: i64) -> RuleResult<FloatValue> {
+    if numerator < 0 || denominator <= 1 {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(FloatValue {
+        value: numerator as f64 / denominator as f64,
+        fraction: Some(Fraction {
+            numerator,
+            denominator,
+        }),
+        ..FloatValue::default()
+    })
+}
+
+pub fn numeric_fraction(numerator: i64, denominator: i64) -> RuleResult<FloatValue> {
+    // "3/4" is also a day and a month, leave the date reading the priority in that case
+    let could_be_date = |day: i64, month: i64| (1..=31).contains(&day) && (1..=12).contains(&month);
+    Ok(FloatValue {
+        latent: could_be_date(numerator, denominator) || could_be_date(denominator, numerator),
+        ..fraction(numerator, denominator)?
+    })
+}
+
+pub fn compose_mixed_fraction(integer: &IntegerValue, float: &FloatValue) -> RuleResult<FloatValue> {
+    // Only proper fractions can follow the integer part, e.g. "1 1/2" but not "1 3/2"
+    let fraction = match float.fraction {
+        Some(fraction) if fraction.numerator > 0 && fraction.numerator < fraction.denominator => {
+            fraction
+        }
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    if integer.value < 0 {
+        return Err(RuleError::Invalid.into());
+    }
+    self::fraction(
+        integer.value * fraction.denominator + fraction.numerator,
+        fraction.denominator,
+    )
+}
+
 impl Form {
     fn time_of_day_hour(full_hour: u32, is_12_clock: bool) -> Form {
         Form::TimeOfDay(TimeOfDayForm::hour(full_hour, is_12_clock))
@@ -191,6 +445,7 @@ impl DatetimeValue {
             // Could be filled through rules too, not only in tagger
             // (but could be overridden before the end of parsing)
             datetime_kind: DatetimeKind::Empty,
+            observed: None,
         }
     }
 
@@ -239,6 +494,29 @@ impl DatetimeValue {
         DatetimeValue { form, ..self }
     }
 
+    /// Holiday moved off the weekend per `observance`, if the context asks for
+    /// observed dates.
+    pub fn observed(self, observance: Observance) -> DatetimeValue {
+        self.observed_as(observance, None)
+    }
+
+    /// Holiday moved off the weekend per `observance`, whatever the context.
+    pub fn always_observed(self, observance: Observance) -> DatetimeValue {
+        self.observed_as(observance, Some(HolidayDate::Observed))
+    }
+
+    fn observed_as(self, observance: Observance, holiday_date: Option<HolidayDate>) -> DatetimeValue {
+        DatetimeValue {
+            constraint: self.constraint.observed_as(observance, holiday_date),
+            observed: Some(ObservedHoliday {
+                nominal: self.constraint.clone(),
+                observance,
+                holiday_date,
+            }),
+            ..self
+        }
+    }
+
     pub fn datetime_kind(self, datetime_kind: DatetimeKind) -> DatetimeValue {
         DatetimeValue {
             datetime_kind,
@@ -300,21 +578,55 @@ impl DatetimeValue {
         DatetimeValue { precision, ..self }
     }
 
+    // The constraint of a vague datetime is a placeholder, only the resolver context gives it a
+    // span, so it must stay on its own
+    fn check_not_vague(&self) -> RuleResult<()> {
+        if let Form::Vague(_) = self.form {
+            Err(RuleError::Invalid.into())
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn intersect(&self, other: &DatetimeValue) -> RuleResult<DatetimeValue> {
+        self.check_not_vague()?;
+        other.check_not_vague()?;
+        // A holiday is moved off the weekend once its year is known, even if it moves to the year
+        // before
+        let constraint = match (&self.observed, &other.observed, &self.form, &other.form) {
+            (&Some(ref holiday), _, _, &Form::Year(_)) => holiday
+                .nominal
+                .intersect(&other.constraint)
+                .observed_as(holiday.observance, holiday.holiday_date),
+            (_, &Some(ref holiday), &Form::Year(_), _) => self
+                .constraint
+                .intersect(&holiday.nominal)
+                .observed_as(holiday.observance, holiday.holiday_date),
+            _ => self.constraint.intersect(&other.constraint),
+        };
         Ok(
-            DatetimeValue::constraint(self.constraint.intersect(&other.constraint))
+            DatetimeValue::constraint(constraint)
                 .direction(self.direction.or(other.direction))
                 .precision(precision_resolution(self.precision, other.precision)),
         )
     }
 
     pub fn last_of(&self, other: &DatetimeValue) -> RuleResult<DatetimeValue> {
+        other.check_not_vague()?;
         Ok(
             DatetimeValue::constraint(self.constraint.last_of(&other.constraint))
                 .precision(precision_resolution(self.precision, other.precision)),
         )
     }
 
+    pub fn nth_last_of(&self, n: i64, other: &DatetimeValue) -> RuleResult<DatetimeValue> {
+        other.check_not_vague()?;
+        Ok(
+            DatetimeValue::constraint(self.constraint.nth_last_of(n, &other.constraint))
+                .precision(precision_resolution(self.precision, other.precision)),
+        )
+    }
+
     pub fn the_nth(&self, n: i64) -> RuleResult<DatetimeValue> {
         Ok(DatetimeValue::constraint(self.constraint.take_the_nth(n)).precision(self.precision))
     }
@@ -327,6 +639,7 @@ impl DatetimeValue {
     }
 
     pub fn the_nth_after(&self, n: i64, after_value: &DatetimeValue) -> RuleResult<DatetimeValue> {
+        after_value.check_not_vague()?;
         Ok(DatetimeValue::constraint(
             self.constraint
                 .the_nth(n)
@@ -364,6 +677,8 @@ impl DatetimeValue {
     }
 
     pub fn span_to(&self, to: &DatetimeValue, is_inclusive: bool) -> RuleResult<DatetimeValue> {
+        self.check_not_vague()?;
+        to.check_not_vague()?;
         if is_inclusive
             || (self.constraint.grain() == Grain::Day && to.constraint.grain() == Grain::Day)
         {
@@ -413,6 +728,14 @@ impl DatetimeValue {
         }
     }
 
+    pub fn form_iso_week(&self) -> RuleResult<u32> {
+        if let Form::IsoWeek(w) = self.form {
+            Ok(w)
+        } else {
+            Err(format_err!("Form {:?} is not an iso week form", self.form))?
+        }
+    }
+
     pub fn form_time_of_day(&self) -> RuleResult<TimeOfDayForm> {
         if let Form::TimeOfDay(v) = self.form.clone() {
             Ok(v)
@@ -435,6 +758,34 @@ impl DatetimeValue {
         }
     }
 
+    pub fn part_of_years(&self, position: Position) -> RuleResult<DatetimeValue> {
+        let count = match self.form {
+            Form::Decade(_) => 10,
+            Form::Century(_) => 100,
+            _ => return Err(RuleError::Invalid.into()),
+        };
+        // "early 90s" is 1990-1993, "mid 90s" 1994-1996 and "late 90s" 1997-1999
+        let (from, to) = match position {
+            Position::Start => (0, count * 4 / 10),
+            Position::Middle => (count * 4 / 10, count * 7 / 10),
+            Position::End => (count * 7 / 10, count),
+        };
+        let constraint = match self.form {
+            Form::Decade(start) => decade_years(start, from, to - from),
+            Form::Century(start) => century_years(start, from, to - from),
+            _ => return Err(RuleError::Invalid.into()),
+        };
+        let inner_form = self.form.clone();
+        let form = match position {
+            Position::Start => PartOfForm::start_of(inner_form),
+            Position::Middle => PartOfForm::middle_of(inner_form),
+            Position::End => PartOfForm::end_of(inner_form),
+        };
+        Ok(DatetimeValue::constraint(constraint)
+            .form(form)
+            .datetime_kind(DatetimeKind::DatePeriod))
+    }
+
     pub fn form_part_of_form(&self) -> RuleResult<PartOfForm> {
         if let Form::PartOfForm(v) = self.form.clone() {
             Ok(v)
@@ -470,6 +821,138 @@ pub fn year(y: i32) -> RuleResult<DatetimeValue> {
     Ok(DatetimeValue::constraint(Year::new(y)).form(Form::Year(y)))
 }
 
+/// Year of a Japanese era, e.g. 平成31年, restricted to the days of the era
+/// when it started or ended during that year.
+pub fn japanese_era_year(era: JapaneseEra, year: i32) -> RuleResult<DatetimeValue> {
+    let y = era.to_gregorian_year(year).ok_or(RuleError::Invalid)?;
+    let (start_year, start_month, start_day) = era.start();
+    let is_first_year = start_year == y;
+    let start = if is_first_year {
+        YearMonthDay::new(start_year, start_month, start_day).invalid_if_err()?
+    } else {
+        Year::new(y)
+    };
+    let constraint = match era.end() {
+        Some((end_year, end_month, end_day)) if end_year == y => {
+            start.span_to(&YearMonthDay::new(end_year, end_month, end_day).invalid_if_err()?)
+        }
+        _ if is_first_year => start.span_to(&Year::new(y + 1)),
+        _ => start,
+    };
+    Ok(DatetimeValue::constraint(constraint).form(Form::Year(y)))
+}
+
+pub fn decade(start: i32) -> RuleResult<DatetimeValue> {
+    if start < 0 || start % 10 != 0 {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(DatetimeValue::constraint(decade_years(start, 0, 10))
+        .form(Form::Decade(start))
+        .datetime_kind(DatetimeKind::DatePeriod))
+}
+
+fn decade_years(start: i32, offset: i32, count: i32) -> RcConstraint<Local> {
+    // Without a century, e.g. "the 20s" or "the thirties", the decade is the most recent one
+    // ending with these digits, unlike years where 45 stands for 2045
+    if start < 100 {
+        YearsOfRecentDecade::new(start, offset, count)
+    } else {
+        Years::new(start + offset, count)
+    }
+}
+
+pub fn century(nth: i32) -> RuleResult<DatetimeValue> {
+    // The 19th century covers the years 1800 to 1899, as in "the 1800s"
+    if nth < 1 || nth > 30 {
+        return Err(RuleError::Invalid.into());
+    }
+    let start = (nth - 1) * 100;
+    Ok(DatetimeValue::constraint(century_years(start, 0, 100))
+        .form(Form::Century(start))
+        .datetime_kind(DatetimeKind::DatePeriod))
+}
+
+fn century_years(start: i32, offset: i32, count: i32) -> RcConstraint<Local> {
+    // There is no year 0, the 1st century starts in year 1
+    let first = ::std::cmp::max(start + offset, 1);
+    Years::new(first, start + offset + count - first)
+}
+
+pub fn iso_week(week: u32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(IsoWeek::new(week).invalid_if_err()?)
+        .form(Form::IsoWeek(week))
+        .datetime_kind(DatetimeKind::DatePeriod))
+}
+
+pub fn iso_week_of_this_year(week: u32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(IsoWeekOfThisYear::new(week).invalid_if_err()?)
+        .form(Form::IsoWeek(week))
+        .datetime_kind(DatetimeKind::DatePeriod))
+}
+
+pub fn iso_year_week(y: i32, week: u32) -> RuleResult<DatetimeValue> {
+    // The week is not intersected with the year, as the first week of 2025 starts on 2024-12-30
+    let y = normalize_year(y)?;
+    Ok(DatetimeValue::constraint(IsoYearWeek::new(y, week).invalid_if_err()?)
+        .form(Form::IsoWeek(week))
+        .datetime_kind(DatetimeKind::DatePeriod))
+}
+
+pub fn vague(vagueness: Vagueness) -> RuleResult<DatetimeValue> {
+    // The constraint only carries the grain, the span itself comes from the vagueness table
+    // of the resolver context
+    let grain = vagueness.grain();
+    let datetime_kind = if grain.is_date_grain() {
+        DatetimeKind::DatePeriod
+    } else {
+        DatetimeKind::TimePeriod
+    };
+    Ok(DatetimeValue::constraint(Cycle::rc(grain).take_the_nth(0))
+        .form(Form::Vague(vagueness))
+        .precision(Precision::Approximate)
+        .datetime_kind(datetime_kind)
+        .latent())
+}
+
+fn timestamp_offset(offset: &str) -> RuleResult<FixedOffset> {
+    if offset == "z" {
+        return Ok(FixedOffset::east(0));
+    }
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let digits = offset[1..].replace(":", "");
+    let (hours, minutes): (i32, i32) = match digits.len() {
+        2 => (digits.parse()?, 0),
+        4 => (digits[..2].parse()?, digits[2..].parse()?),
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    if hours > 23 || minutes > 59 {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(FixedOffset::east(sign * (hours * 3600 + minutes * 60)))
+}
+
+pub fn iso_timestamp(text: &str) -> RuleResult<DatetimeValue> {
+    // e.g. "2019-03-04t10:00:00.250+02:00", the offset starts after the seconds
+    let text = text.to_lowercase();
+    let (datetime, offset) = match text
+        .char_indices()
+        .skip(19)
+        .find(|&(_, c)| c == 'z' || c == '+' || c == '-')
+    {
+        Some((i, _)) => (&text[..i], Some(timestamp_offset(&text[i..])?)),
+        None => (&text[..], None),
+    };
+    let datetime = datetime.replace('t', " ").replace(',', ".");
+    let datetime = NaiveDateTime::parse_from_str(&datetime, "%Y-%m-%d %H:%M:%S%.f")
+        .map_err(|_| RuleError::Invalid)?;
+    Ok(DatetimeValue::constraint(Timestamp::new(datetime, offset)))
+}
+
+pub fn epoch_timestamp(seconds: i64) -> RuleResult<DatetimeValue> {
+    let datetime = NaiveDateTime::from_timestamp_opt(seconds, 0).ok_or(RuleError::Invalid)?;
+    Ok(DatetimeValue::constraint(Timestamp::new(datetime, Some(FixedOffset::east(0)))))
+}
+
 pub fn month(m: u32) -> RuleResult<DatetimeValue> {
     if !(1 <= m && m <= 12) {
         return Err(RuleError::Invalid.into());
@@ -503,6 +986,34 @@ pub fn month_day(m: u32, d: u32) -> RuleResult<DatetimeValue> {
     )
 }
 
+/// Day of the Chinese lunisolar calendar, for the traditional celebrations.
+pub fn lunar_month_day(m: u32, d: u32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(LunarMonthDay::new(m, d).invalid_if_err()?)
+        .datetime_kind(DatetimeKind::Date))
+}
+
+/// Day of the tabular Hijri calendar, for the Islamic celebrations.
+pub fn hijri_month_day(m: u32, d: u32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(HijriMonthDay::new(m, d).invalid_if_err()?)
+        .datetime_kind(DatetimeKind::Date))
+}
+
+/// Day of the Hebrew calendar, months being numbered from Nisan, for the
+/// Jewish celebrations.
+pub fn hebrew_month_day(m: u32, d: u32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(HebrewMonthDay::new(m, d).invalid_if_err()?)
+        .datetime_kind(DatetimeKind::Date))
+}
+
+/// Nth weekday of a month every year, counted from the end of the month if `n`
+/// is negative, e.g. `nth_weekday_of_month(-1, Weekday::Mon, 5)` for Memorial Day.
+pub fn nth_weekday_of_month(n: i64, weekday: Weekday, m: u32) -> RuleResult<DatetimeValue> {
+    Ok(
+        DatetimeValue::constraint(NthWeekdayOfMonth::new(n, weekday, m).invalid_if_err()?)
+            .datetime_kind(DatetimeKind::Date),
+    )
+}
+
 pub fn year_month_day(y: i32, m: u32, d: u32) -> RuleResult<DatetimeValue> {
     let y = normalize_year(y)?;
     Ok(
@@ -602,17 +1113,26 @@ pub fn cycle_nth_after(
     n: i64,
     after_value: &DatetimeValue,
 ) -> RuleResult<DatetimeValue> {
+    after_value.check_not_vague()?;
     Ok(
         DatetimeValue::constraint(Cycle::rc(grain).the_nth(n).after(&after_value.constraint))
             .form(Form::Cycle(grain)),
     )
 }
 
+pub fn nth_day_of(n: i64, outer: &DatetimeValue) -> RuleResult<DatetimeValue> {
+    // Kept within the enclosing period: there is no 31st day of February, nor a day 366 of 2013
+    Ok(cycle_nth_after(Grain::Day, n, outer)?
+        .intersect(outer)?
+        .form(Form::Cycle(Grain::Day)))
+}
+
 pub fn cycle_nth_after_not_immediate(
     grain: Grain,
     n: i64,
     after_value: &DatetimeValue,
 ) -> RuleResult<DatetimeValue> {
+    after_value.check_not_vague()?;
     Ok(DatetimeValue::constraint(
         Cycle::rc(grain)
             .the_nth(n)
@@ -637,15 +1157,28 @@ pub fn weekend() -> RuleResult<DatetimeValue> {
         .datetime_kind(DatetimeKind::DatePeriod))
 }
 
+/// Easter as dated by the computus of the resolution context.
 pub fn easter() -> RuleResult<DatetimeValue> {
-    fn offset(i: &Interval<Local>, _: &Context<Local>) -> Option<Interval<Local>> {
-        let (year, month, day) = computer_easter(i.start.year());
-        Some(Interval::ymd(year, month, day))
-    }
-    Ok(
-        DatetimeValue::constraint(Month::new(3).invalid_if_err()?.translate_with(offset))
-            .datetime_kind(DatetimeKind::Date),
-    ) // otherwise grain is Month; not the cleanest but does the job
+    easter_with_computus(None)
+}
+
+/// Orthodox Easter, whatever the computus of the resolution context.
+pub fn orthodox_easter() -> RuleResult<DatetimeValue> {
+    easter_with_computus(Some(Computus::Orthodox))
+}
+
+fn easter_with_computus(computus: Option<Computus>) -> RuleResult<DatetimeValue> {
+    let constraint = Month::new(3).invalid_if_err()?.translate_with(
+        move |i: &Interval<Local>, context: &Context<Local>| {
+            let (year, month, day) = match computus.unwrap_or(context.computus) {
+                Computus::Western => computer_easter(i.start.year()),
+                Computus::Orthodox => computer_orthodox_easter(i.start.year()),
+            };
+            Some(Interval::ymd(year, month, day))
+        },
+    );
+    Ok(DatetimeValue::constraint(constraint).datetime_kind(DatetimeKind::Date))
+    // otherwise grain is Month; not the cleanest but does the job
 }
 
 pub fn computer_easter(year: i32) -> (i32, u32, u32) {
@@ -667,10 +1200,29 @@ pub fn computer_easter(year: i32) -> (i32, u32, u32) {
     (year, month, day)
 }
 
+/// Orthodox Easter from the Julian computus, given in the Gregorian calendar.
+pub fn computer_orthodox_easter(year: i32) -> (i32, u32, u32) {
+    let a = year % 4;
+    let b = year % 7;
+    let c = year % 19;
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let month = (d + e + 114) / 31;
+    let day = (d + e + 114) % 31 + 1;
+    // Gap between the Julian and Gregorian calendars, 13 days from 1900 to 2099
+    let gap = year / 100 - year / 400 - 2;
+    let easter = Interval::ymd(year, month as u32, day as u32) + PeriodComp::days(gap as i64);
+    (year, easter.start.month(), easter.start.day())
+}
+
 impl CycleValue {
     pub fn last_of(&self, base: &DatetimeValue) -> RuleResult<DatetimeValue> {
         cycle(self.grain)?.last_of(base)
     }
+
+    pub fn nth_last_of(&self, n: i64, base: &DatetimeValue) -> RuleResult<DatetimeValue> {
+        cycle(self.grain)?.nth_last_of(n, base)
+    }
 }
 
 impl DurationValue {
@@ -734,6 +1286,7 @@ impl DurationValue {
 
     pub fn after(&self, datetime: &DatetimeValue) -> RuleResult<DatetimeValue> {
         self.check_period()?;
+        datetime.check_not_vague()?;
         Ok(
             DatetimeValue::constraint(datetime.constraint.shift_by(self.period.clone()))
                 .precision(self.precision),
@@ -742,15 +1295,28 @@ impl DurationValue {
 
     pub fn before(&self, datetime: &DatetimeValue) -> RuleResult<DatetimeValue> {
         self.check_period()?;
+        datetime.check_not_vague()?;
         Ok(
             DatetimeValue::constraint(datetime.constraint.shift_by(-self.period.clone()))
                 .precision(self.precision),
         )
     }
 }
+
+// Adding to a fuzzy duration gives a range too, e.g. "two hours and a few minutes"
+fn duration_fuzzy_range(a: &DurationValue, b: &DurationValue) -> Option<(Period, Period)> {
+    if a.fuzzy_range.is_none() && b.fuzzy_range.is_none() {
+        return None;
+    }
+    let (a_min, a_max) = a.bounds();
+    let (b_min, b_max) = b.bounds();
+    Some((a_min + b_min, a_max + b_max))
+}
+
 impl ops::Add<DurationValue> for DurationValue {
     type Output = DurationValue;
     fn add(self, duration: DurationValue) -> DurationValue {
+        let fuzzy_range = duration_fuzzy_range(&self, &duration);
         DurationValue {
             period: self.period + duration.period,
             precision: precision_resolution(self.precision, duration.precision),
@@ -760,6 +1326,7 @@ impl ops::Add<DurationValue> for DurationValue {
                 self.from_addition,
                 duration.from_addition,
             )),
+            fuzzy_range,
         }
     }
 }
@@ -767,6 +1334,7 @@ impl ops::Add<DurationValue> for DurationValue {
 impl<'a> ops::Add<&'a DurationValue> for DurationValue {
     type Output = DurationValue;
     fn add(self, duration: &'a DurationValue) -> DurationValue {
+        let fuzzy_range = duration_fuzzy_range(&self, duration);
         DurationValue {
             period: self.period + &duration.period,
             precision: precision_resolution(self.precision, duration.precision),
@@ -776,6 +1344,7 @@ impl<'a> ops::Add<&'a DurationValue> for DurationValue {
                 self.from_addition,
                 duration.from_addition,
             )),
+            fuzzy_range,
         }
     }
 }
@@ -783,6 +1352,7 @@ impl<'a> ops::Add<&'a DurationValue> for DurationValue {
 impl<'a, 'b> ops::Add<&'a DurationValue> for &'b DurationValue {
     type Output = DurationValue;
     fn add(self, duration: &'a DurationValue) -> DurationValue {
+        let fuzzy_range = duration_fuzzy_range(self, duration);
         DurationValue {
             period: &self.period + &duration.period,
             precision: precision_resolution(self.precision, duration.precision),
@@ -792,6 +1362,7 @@ impl<'a, 'b> ops::Add<&'a DurationValue> for &'b DurationValue {
                 self.from_addition,
                 duration.from_addition,
             )),
+            fuzzy_range,
         }
     }
 }
@@ -799,6 +1370,7 @@ impl<'a, 'b> ops::Add<&'a DurationValue> for &'b DurationValue {
 impl<'a> ops::Add<DurationValue> for &'a DurationValue {
     type Output = DurationValue;
     fn add(self, duration: DurationValue) -> DurationValue {
+        let fuzzy_range = duration_fuzzy_range(self, &duration);
         DurationValue {
             period: &self.period + duration.period,
             precision: precision_resolution(self.precision, duration.precision),
@@ -808,6 +1380,17 @@ impl<'a> ops::Add<DurationValue> for &'a DurationValue {
                 self.from_addition,
                 duration.from_addition,
             )),
+            fuzzy_range,
+        }
+    }
+}
+
+impl ops::Neg for Fraction {
+    type Output = Fraction;
+    fn neg(self) -> Fraction {
+        Fraction {
+            numerator: -self.numerator,
+            ..self
         }
     }
 }
@@ -822,10 +1405,198 @@ mod tests {
         assert_eq!(93, decimal_hour_in_minute("1", "55").unwrap());
     }
 
+    #[test]
+    fn test_negate_number() {
+        let float = NumberValue::Float(fraction(1, 2).unwrap());
+        let negated = negate_number(&float).unwrap();
+        assert_eq!(-0.5, negated.value());
+        assert!(negated.prefixed());
+        let integer = NumberValue::Integer(IntegerValue::new(5).unwrap());
+        assert_eq!(-5.0, negate_number(&integer).unwrap().value());
+    }
+
+    #[test]
+    fn test_temperature_below_zero() {
+        let temp = TemperatureValue { value: 5.0, unit: None, latent: true };
+        let below = temperature_below_zero(&temp).unwrap();
+        assert_eq!(-5.0, below.value);
+        assert!(!below.latent);
+        assert_eq!(-5.0, temperature_below_zero(&below).unwrap().value);
+    }
+
+    #[test]
+    fn test_compose_temperature_interval() {
+        let min = TemperatureValue { value: 20.0, unit: None, latent: true };
+        let max = TemperatureValue { value: 25.0, unit: Some("celsius"), latent: false };
+        let interval = compose_temperature_interval(&min, &max).unwrap();
+        assert_eq!(Some("celsius"), interval.unit);
+        assert!(!interval.latent);
+        let fahrenheit = TemperatureValue { value: 30.0, unit: Some("fahrenheit"), latent: false };
+        assert!(compose_temperature_interval(&max, &fahrenheit).is_err());
+        assert!(compose_temperature_interval(&max, &min).is_err());
+    }
+
+    #[test]
+    fn test_compose_duration_interval_with_integer() {
+        let min = IntegerValue::new(2).unwrap();
+        let max = DurationValue::new(PeriodComp::hours(3).into());
+        let interval = compose_duration_interval_with_integer(&min, &max).unwrap();
+        assert_eq!(Period::from(PeriodComp::hours(2)), interval.min);
+        assert_eq!(Period::from(PeriodComp::hours(3)), interval.max);
+        let max = DurationValue::new(PeriodComp::hours(3).into()) + DurationValue::new(PeriodComp::minutes(20).into());
+        assert!(compose_duration_interval_with_integer(&min, &max).is_err());
+    }
+
+    #[test]
+    fn test_compose_magnitude() {
+        let float = NumberValue::Float(FloatValue::new(1.5).unwrap());
+        assert_eq!(
+            NumberValue::Integer(IntegerValue { value: 1500, suffixed: true, ..IntegerValue::default() }),
+            compose_magnitude(&float, 1e3).unwrap()
+        );
+        let integer = NumberValue::Integer(IntegerValue::new(25).unwrap());
+        assert_eq!(2.5, compose_magnitude(&integer, 1e-1).unwrap().value());
+        assert!(compose_magnitude(&integer, f64::INFINITY).is_err());
+        let approximate = NumberValue::Integer(IntegerValue {
+            value: 3,
+            grain: Some(1),
+            precision: Precision::Approximate,
+            ..IntegerValue::default()
+        });
+        let thousands = compose_magnitude(&approximate, 1e3).unwrap();
+        assert_eq!(Precision::Approximate, thousands.precision());
+        if let NumberValue::Integer(integer) = thousands {
+            assert_eq!(Some(1), integer.grain);
+        } else {
+            panic!("expected an integer");
+        }
+    }
+
+    #[test]
+    fn test_compose_money_number() {
+        let money = AmountOfMoneyValue { value: 2.0, unit: Some("INR"), ..AmountOfMoneyValue::default() };
+        let cents = NumberValue::Integer(IntegerValue::new(50).unwrap());
+        assert_eq!(2.5, compose_money_number(&money, &cents).unwrap().value);
+        let lakh = NumberValue::Integer(IntegerValue::new_with_grain(500_000, 5).unwrap());
+        assert!(compose_money_number(&money, &lakh).is_err());
+    }
+
+    #[test]
+    fn test_decade_and_century() {
+        assert_eq!(Form::Decade(90), decade(90).unwrap().form);
+        assert_eq!(Form::Decade(2000), decade(2000).unwrap().form);
+        assert!(decade(1995).is_err());
+        assert!(decade(-10).is_err());
+        assert_eq!(Form::Century(1800), century(19).unwrap().form);
+        assert!(century(0).is_err());
+        let late_nineties = decade(90).unwrap().part_of_years(Position::End).unwrap();
+        assert_eq!(PartOfForm::end_of(Form::Decade(90)), late_nineties.form);
+        assert!(year(1990).unwrap().part_of_years(Position::Start).is_err());
+    }
+
+    #[test]
+    fn test_iso_week() {
+        assert_eq!(Form::IsoWeek(42), iso_week(42).unwrap().form);
+        assert_eq!(DatetimeKind::DatePeriod, iso_year_week(2024, 5).unwrap().datetime_kind);
+        assert!(iso_week(0).is_err());
+        assert_eq!(Form::IsoWeek(3), iso_week_of_this_year(3).unwrap().form);
+        assert!(iso_week_of_this_year(54).is_err());
+        assert!(iso_year_week(2024, 54).is_err());
+    }
+
+    #[test]
+    fn test_vague() {
+        let soon = vague(Vagueness::Soon).unwrap();
+        assert_eq!(Form::Vague(Vagueness::Soon), soon.form);
+        assert_eq!(Precision::Approximate, soon.precision);
+        assert_eq!(DatetimeKind::TimePeriod, soon.datetime_kind);
+        assert!(soon.latent);
+        assert_eq!(DatetimeKind::DatePeriod, vague(Vagueness::FewWeeksAgo).unwrap().datetime_kind);
+        // "recently at 5pm" or "from soon to friday" would resolve against now
+        let five_pm = hour(17, false).unwrap();
+        assert!(vague(Vagueness::Recently).unwrap().intersect(&five_pm).is_err());
+        assert!(five_pm.intersect(&soon).is_err());
+        assert!(soon.span_to(&day_of_week(Weekday::Fri).unwrap(), false).is_err());
+        assert!(cycle_nth_after(Grain::Day, 1, &soon).is_err());
+    }
+
+    #[test]
+    fn test_timestamp_offset() {
+        assert_eq!(FixedOffset::east(0), timestamp_offset("z").unwrap());
+        assert_eq!(FixedOffset::east(2 * 3600), timestamp_offset("+02:00").unwrap());
+        assert_eq!(FixedOffset::west(5 * 3600 + 1800), timestamp_offset("-0530").unwrap());
+        assert_eq!(FixedOffset::east(3600), timestamp_offset("+01").unwrap());
+        assert!(timestamp_offset("+25:00").is_err());
+        assert!(iso_timestamp("2019-03-04t10:00:00.250+02:00").is_ok());
+        assert!(iso_timestamp("2019-13-04 10:00:00z").is_err());
+    }
+
+    #[test]
+    fn test_sub_second_durations() {
+        let half_a_second = Period::from(PeriodComp::milliseconds(500));
+        assert_eq!(half_a_second, decimal_seconds(0.5).unwrap().period);
+        assert_eq!(
+            Period::from(PeriodComp::seconds(1)) + PeriodComp::milliseconds(250),
+            decimal_seconds(1.25).unwrap().period
+        );
+        assert_eq!(
+            Period::from(PeriodComp::minutes(1)) + PeriodComp::seconds(23) + PeriodComp::milliseconds(450),
+            stopwatch_duration("1:23.45").unwrap().period
+        );
+        assert_eq!(
+            Period::from(PeriodComp::hours(1)) + PeriodComp::minutes(2) + PeriodComp::seconds(3) + PeriodComp::milliseconds(5),
+            stopwatch_duration("1:02:03,005").unwrap().period
+        );
+        assert!(stopwatch_duration("1:75.2").is_err());
+        assert!(stopwatch_duration("1:23").is_err());
+    }
+
+    #[test]
+    fn test_roman_numeral() {
+        assert_eq!(8, roman_numeral("VIII").unwrap());
+        assert_eq!(14, roman_numeral("xiv").unwrap());
+        assert_eq!(57, roman_numeral("lvii").unwrap());
+        assert_eq!(1994, roman_numeral("mcmxciv").unwrap());
+        assert!(roman_numeral("iiii").is_err());
+        assert!(roman_numeral("vx").is_err());
+        assert!(roman_numeral("mil").is_err());
+        assert!(roman_numeral("xcx").is_err());
+        assert!(roman_numeral("").is_err());
+    }
+
+    #[test]
+    fn test_compose_mixed_fraction() {
+        let integer = IntegerValue::new(1).unwrap();
+        let mixed = compose_mixed_fraction(&integer, &fraction(1, 2).unwrap()).unwrap();
+        assert_eq!(1.5, mixed.value);
+        assert_eq!(Some(Fraction { numerator: 3, denominator: 2 }), mixed.fraction);
+        assert!(compose_mixed_fraction(&integer, &fraction(3, 2).unwrap()).is_err());
+        assert!(compose_mixed_fraction(&integer, &FloatValue::new(0.5).unwrap()).is_err());
+        assert!(!compose_mixed_fraction(&integer, &numeric_fraction(3, 4).unwrap()).unwrap().latent);
+    }
+
+    #[test]
+    fn test_numeric_fraction() {
+        assert!(numeric_fraction(3, 4).unwrap().latent);
+        assert!(numeric_fraction(12, 31).unwrap().latent);
+        assert!(!numeric_fraction(7, 64).unwrap().latent);
+        assert!(!numeric_fraction(15, 16).unwrap().latent);
+        assert_eq!(Some(Fraction { numerator: 7, denominator: 64 }), numeric_fraction(7, 64).unwrap().fraction);
+    }
+
     #[test]
     fn test_computer_easter() {
         assert_eq!((2017, 4, 16), computer_easter(2017));
         assert_eq!((2018, 4, 1), computer_easter(2018));
         assert_eq!((2019, 4, 21), computer_easter(2019));
     }
+
+    #[test]
+    fn test_computer_orthodox_easter() {
+        assert_eq!((2013, 5, 5), computer_orthodox_easter(2013));
+        assert_eq!((2017, 4, 16), computer_orthodox_easter(2017));
+        assert_eq!((2018, 4, 8), computer_orthodox_easter(2018));
+        assert_eq!((2019, 4, 28), computer_orthodox_easter(2019));
+        assert_eq!((2024, 5, 5), computer_orthodox_easter(2024));
+    }
 }
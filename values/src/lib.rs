@@ -10,15 +10,20 @@ extern crate regex;
 pub mod check;
 pub mod dimension;
 pub mod helpers;
+pub mod number_format;
 #[macro_use]
 pub mod macros_training;
 #[macro_use]
 pub mod macros_rules;
 pub mod output;
+pub mod rules_holidays;
+pub mod rules_semester;
+pub mod rules_stopwatch;
+pub mod rules_timestamp;
 pub mod context;
 
 pub use dimension::Dimension;
 pub use dimension::DimensionKind;
 pub use output::Output;
 pub use output::OutputKind;
-pub use context::{ResolverContext, ParsingContext, IdentityContext};
+pub use context::{ResolverContext, ParsingContext, IdentityContext, VagueSpan, VaguenessTable};
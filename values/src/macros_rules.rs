@@ -7,30 +7,39 @@ macro_rules! b {
 
 #[macro_export]
 macro_rules! integer_check_by_range {
-    ($min:expr) => ( ::rustling::core::FilterNodePattern::<IntegerValue>::filter(vec![b!(|integer: &IntegerValue| integer.value >= $min)]) );
-    ($min:expr, $max:expr) => ( ::rustling::core::FilterNodePattern::<IntegerValue>::filter(vec![b!(|integer: &IntegerValue| integer.value >= $min && integer.value <= $max)]) );
-    ($min:expr, $max:expr, $predicate:expr) => ( ::rustling::core::FilterNodePattern::<IntegerValue>::filter(vec![b!(|integer: &IntegerValue| integer.value >= $min && integer.value <= $max), b!($predicate)]) );
+    ($min:expr) => ( ::rustling::core::FilterNodePattern::<IntegerValue>::filter(vec![b!(|integer: &IntegerValue| !integer.latent && integer.value >= $min)]) );
+    ($min:expr, $max:expr) => ( ::rustling::core::FilterNodePattern::<IntegerValue>::filter(vec![b!(|integer: &IntegerValue| !integer.latent && integer.value >= $min && integer.value <= $max)]) );
+    ($min:expr, $max:expr, $predicate:expr) => ( ::rustling::core::FilterNodePattern::<IntegerValue>::filter(vec![b!(|integer: &IntegerValue| !integer.latent && integer.value >= $min && integer.value <= $max), b!($predicate)]) );
 }
 
 
+// Latent integers, i.e. standalone roman numerals, are only numbers on their own and never
+// compose with other rules
 #[macro_export]
 macro_rules! integer_check {
-    () => ( ::rustling::core::AnyNodePattern::<IntegerValue>::new());
-    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<IntegerValue>::filter(vec![b!($predicate)]) );
+    () => ( ::rustling::core::FilterNodePattern::<IntegerValue>::filter(vec![b!(|integer: &IntegerValue| !integer.latent)]) );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<IntegerValue>::filter(vec![b!(|integer: &IntegerValue| !integer.latent), b!($predicate)]) );
 }
 
 
 #[macro_export]
 macro_rules! number_check {
-    () => ( ::rustling::core::AnyNodePattern::<NumberValue>::new() );
-    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<NumberValue>::filter(vec![b!($predicate)]) );
+    () => ( ::rustling::core::FilterNodePattern::<NumberValue>::filter(vec![b!(|number: &NumberValue| !number.is_latent_integer())]) );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<NumberValue>::filter(vec![b!(|number: &NumberValue| !number.is_latent_integer()), b!($predicate)]) );
+}
+
+
+#[macro_export]
+macro_rules! float_check {
+    () => ( ::rustling::core::AnyNodePattern::<FloatValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<FloatValue>::filter(vec![b!($predicate)]) );
 }
 
 
 #[macro_export]
 macro_rules! number_check_by_range {
-    ($min:expr) => ( ::rustling::core::FilterNodePattern::<NumberValue>::filter(vec![b!(|number: &NumberValue| number.value() >= $min)]) );
-    ($min:expr, $max:expr)  => ( ::rustling::core::FilterNodePattern::<NumberValue>::filter(vec![b!(|number: &NumberValue| number.value() >= $min && number.value() <= $max)]) );
+    ($min:expr) => ( ::rustling::core::FilterNodePattern::<NumberValue>::filter(vec![b!(|number: &NumberValue| !number.is_latent_integer() && number.value() >= $min)]) );
+    ($min:expr, $max:expr)  => ( ::rustling::core::FilterNodePattern::<NumberValue>::filter(vec![b!(|number: &NumberValue| !number.is_latent_integer() && number.value() >= $min && number.value() <= $max)]) );
 }
 
 
@@ -18,6 +18,12 @@ macro_rules! check_duration {
     ([$($item:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_duration(period!($( $item ),*), $precision) );
 }
 
+#[macro_export]
+macro_rules! check_duration_interval {
+    ([$($min:expr),*], [$($max:expr),*]) => ( ::rustling_ontology_values::check::check_duration_interval(period!($( $min ),*), period!($( $max ),*), Precision::Exact) );
+    ([$($min:expr),*], [$($max:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_duration_interval(period!($( $min ),*), period!($( $max ),*), $precision) );
+}
+
 #[macro_export]
 macro_rules! check_moment {
     ($context:expr, [$($item:expr),*]) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, None) );
@@ -56,6 +62,7 @@ macro_rules! moment {
     ($y:expr, $m:expr, $d:expr, $h:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms($h, 0, 0)) );
     ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms($h, $min, 0)) );
     ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr, $sec:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms($h, $min, $sec)) );
+    ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr, $sec:expr, $ms:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms_milli($h, $min, $sec, $ms)) );
 }
 
 #[macro_export]
@@ -67,6 +74,7 @@ macro_rules! period {
     ($y:expr, $m:expr, $w:expr, $d:expr, $h:expr) => ( year_period!($y) + month_period!($m) + week_period!($w) + day_period!($d) + hour_period!($h) );
     ($y:expr, $m:expr, $w:expr, $d:expr, $h:expr, $min:expr) => ( year_period!($y) + month_period!($m) + week_period!($w) + day_period!($d) + hour_period!($h) + minute_period!($min) );
     ($y:expr, $m:expr, $w:expr, $d:expr, $h:expr, $min:expr, $sec:expr) => ( year_period!($y) + month_period!($m) + week_period!($w) + day_period!($d) + hour_period!($h) + minute_period!($min) + second_period!($sec) );
+    ($y:expr, $m:expr, $w:expr, $d:expr, $h:expr, $min:expr, $sec:expr, $ms:expr) => ( year_period!($y) + month_period!($m) + week_period!($w) + day_period!($d) + hour_period!($h) + minute_period!($min) + second_period!($sec) + millisecond_period!($ms) );
 }
 
 #[macro_export]
@@ -104,6 +112,11 @@ macro_rules! second_period {
     ($sec:expr) => ( Period::from(PeriodComp::new(Grain::Second, $sec)) );
 }
 
+#[macro_export]
+macro_rules! millisecond_period {
+    ($ms:expr) => ( Period::from(PeriodComp::new(Grain::Millisecond, $ms)) );
+}
+
 #[macro_export]
 macro_rules! grain {
     ($y:expr) => (Grain::Year);
@@ -112,6 +125,7 @@ macro_rules! grain {
     ($y:expr, $m:expr, $d:expr, $h:expr) => (Grain::Hour);
     ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr) => (Grain::Minute);
     ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr, $sec:expr) => (Grain::Second);
+    ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr, $sec:expr, $ms:expr) => (Grain::Millisecond);
 }
 
 #[macro_export]
@@ -0,0 +1,186 @@
+use crate::dimension::*;
+use rustling::{RuleError, RuleResult};
+
+/// How digits of the integral part are grouped
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Grouping {
+    /// Groups of three digits, e.g. "1,000,000"
+    Thousands,
+    /// Last group of three digits, then groups of two digits, e.g. "10,00,000"
+    Indian,
+}
+
+/// Separators a language accepts in digit strings like "1.000.000,50" or "1'000"
+#[derive(Debug, Clone, Copy)]
+pub struct NumberFormat {
+    pub decimal_separators: &'static [char],
+    pub grouping_separators: &'static [char],
+    pub groupings: &'static [Grouping],
+}
+
+impl NumberFormat {
+    /// Regex matching digit strings containing at least one separator of this format
+    pub fn regex(&self) -> String {
+        let escape = |separators: &mut dyn Iterator<Item = &char>| {
+            separators
+                .map(|separator| regex::escape(&separator.to_string()))
+                .collect::<String>()
+        };
+        let separators = escape(
+            &mut self
+                .decimal_separators
+                .iter()
+                .chain(self.grouping_separators.iter())
+                .filter(|separator| **separator != ' '),
+        );
+        if self.grouping_separators.contains(&' ') {
+            // A plain space only separates groups of three digits, so that "2 3" or
+            // "de 10 000 à 12" are not read as a single number
+            let decimal_separators = escape(&mut self.decimal_separators.iter());
+            format!(
+                r#"(\d+(?:[{}]\d+)+|\d{{1,3}}(?: \d{{3}})+(?:[{}]\d+)?)"#,
+                separators, decimal_separators
+            )
+        } else {
+            format!(r#"(\d+(?:[{}]\d+)+)"#, separators)
+        }
+    }
+
+    pub fn parse(&self, text: &str) -> RuleResult<NumberValue> {
+        let (integral, decimal) = self
+            .normalize(text)
+            .ok_or_else(|| -> RuleError { RuleError::Invalid.into() })?;
+        match decimal {
+            Some(decimal) => Ok(FloatValue::new(format!("{}.{}", integral, decimal).parse()?)?.into()),
+            None => Ok(IntegerValue::new(integral.parse()?)?.into()),
+        }
+    }
+
+    /// Split a digit string into its integral digits and its optional decimal digits
+    pub fn normalize(&self, text: &str) -> Option<(String, Option<String>)> {
+        let mut groups: Vec<String> = vec![String::new()];
+        let mut separators: Vec<char> = vec![];
+        for c in text.chars() {
+            if c.is_ascii_digit() {
+                groups.last_mut()?.push(c);
+            } else {
+                separators.push(c);
+                groups.push(String::new());
+            }
+        }
+        if groups.iter().any(|group| group.is_empty()) {
+            return None;
+        }
+        let decimal = match separators.last().cloned() {
+            Some(last) if self.is_decimal_separator(last, &separators, groups.last()?) => {
+                separators.pop();
+                groups.pop()
+            }
+            _ => None,
+        };
+        if !self.is_valid_grouping(&groups, &separators) {
+            return None;
+        }
+        Some((groups.concat(), decimal))
+    }
+
+    fn is_decimal_separator(&self, separator: char, separators: &[char], last_group: &str) -> bool {
+        if !self.decimal_separators.contains(&separator) {
+            return false;
+        }
+        if !self.grouping_separators.contains(&separator) {
+            return true;
+        }
+        // Ambiguous mark, e.g. "1.500": it is a grouping mark when used several times or
+        // when followed by exactly three digits, unless another grouping mark is already used
+        let occurrences = separators.iter().filter(|s| **s == separator).count();
+        let other_grouping = separators.iter().any(|s| *s != separator);
+        occurrences == 1 && (last_group.len() != 3 || other_grouping)
+    }
+
+    fn is_valid_grouping(&self, groups: &[String], separators: &[char]) -> bool {
+        let separator = match separators.first() {
+            Some(separator) => separator,
+            None => return true,
+        };
+        if !self.grouping_separators.contains(separator) || separators.iter().any(|s| s != separator) {
+            return false;
+        }
+        let lengths = groups.iter().map(|group| group.len()).collect::<Vec<_>>();
+        self.groupings.iter().any(|grouping| match grouping {
+            Grouping::Thousands => {
+                lengths[0] <= 3 && lengths[1..].iter().all(|length| *length == 3)
+            }
+            Grouping::Indian => {
+                let (last, middle) = lengths[1..].split_last().unwrap();
+                lengths[0] <= 2 && *last == 3 && middle.iter().all(|length| *length == 2)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EN: NumberFormat = NumberFormat {
+        decimal_separators: &['.'],
+        grouping_separators: &[',', '\u{2009}'],
+        groupings: &[Grouping::Thousands, Grouping::Indian],
+    };
+
+    const DE: NumberFormat = NumberFormat {
+        decimal_separators: &[','],
+        grouping_separators: &['.', '\''],
+        groupings: &[Grouping::Thousands],
+    };
+
+    const FR: NumberFormat = NumberFormat {
+        decimal_separators: &[','],
+        grouping_separators: &['.', ' ', '\u{202f}'],
+        groupings: &[Grouping::Thousands],
+    };
+
+    #[test]
+    fn test_thousands_grouping() {
+        assert_eq!(Some(("1000000".to_string(), None)), EN.normalize("1,000,000"));
+        assert_eq!(Some(("1000000".to_string(), None)), EN.normalize("1\u{2009}000\u{2009}000"));
+        assert_eq!(Some(("1000".to_string(), Some("5".to_string()))), EN.normalize("1,000.5"));
+        assert_eq!(None, EN.normalize("1,00"));
+        assert_eq!(None, EN.normalize("1,000\u{2009}000"));
+    }
+
+    #[test]
+    fn test_indian_grouping() {
+        assert_eq!(Some(("100000".to_string(), None)), EN.normalize("1,00,000"));
+        assert_eq!(Some(("10000000".to_string(), None)), EN.normalize("1,00,00,000"));
+        assert_eq!(None, EN.normalize("1,00,00"));
+    }
+
+    #[test]
+    fn test_ambiguous_separator() {
+        assert_eq!(Some(("1500".to_string(), None)), DE.normalize("1.500"));
+        assert_eq!(Some(("1000000".to_string(), Some("50".to_string()))), DE.normalize("1.000.000,50"));
+        assert_eq!(Some(("1500".to_string(), None)), EN.normalize("1,500"));
+        assert_eq!(Some(("1".to_string(), Some("500".to_string()))), EN.normalize("1.500"));
+    }
+
+    #[test]
+    fn test_dot_is_not_a_german_decimal_separator() {
+        assert_eq!(None, DE.normalize("1.5"));
+        assert_eq!(None, DE.normalize("24.12"));
+        assert_eq!(None, DE.normalize("1'000.50"));
+    }
+
+    #[test]
+    fn test_plain_space_grouping() {
+        let regex = regex::Regex::new(&FR.regex()).unwrap();
+        let matches = |text: &str| regex.find_iter(text).map(|m| m.as_str().to_string()).collect::<Vec<_>>();
+        assert_eq!(vec!["1 000 000,50"], matches("1 000 000,50"));
+        assert_eq!(vec!["10 000"], matches("de 10 000 à 12"));
+        assert!(matches("2 3").is_empty());
+        assert_eq!(vec!["12 000"], matches("12 000 12"));
+        assert_eq!(vec!["1.000"], matches("1.000 3"));
+        assert_eq!(Some(("10000".to_string(), None)), FR.normalize("10 000"));
+    }
+}
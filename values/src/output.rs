@@ -5,21 +5,27 @@ use rustling::Value;
 #[derive(Clone, PartialEq, Debug)]
 pub enum Output {
     Integer(IntegerOutput),
+    FuzzyInteger(FuzzyIntegerOutput),
     Float(FloatOutput),
+    Fraction(FractionOutput),
     Percentage(PercentageOutput),
     Ordinal(OrdinalOutput),
     Datetime(DatetimeOutput),
     DatetimeInterval(DatetimeIntervalOutput),
     AmountOfMoney(AmountOfMoneyOutput),
     Temperature(TemperatureOutput),
+    TemperatureInterval(TemperatureIntervalOutput),
     Duration(DurationOutput),
+    DurationInterval(DurationIntervalOutput),
 }
 
 impl Output {
     pub fn kind(&self) -> OutputKind {
         match self {
             &Output::Integer(_) => OutputKind::Number,
+            &Output::FuzzyInteger(_) => OutputKind::Number,
             &Output::Float(_) => OutputKind::Number,
+            &Output::Fraction(_) => OutputKind::Number,
             &Output::Ordinal(_) => OutputKind::Ordinal,
             Output::Datetime(datetime_output_value) => {
                 match datetime_output_value.datetime_kind {
@@ -43,7 +49,9 @@ impl Output {
             }
             &Output::AmountOfMoney(_) => OutputKind::AmountOfMoney,
             &Output::Temperature(_) => OutputKind::Temperature,
+            &Output::TemperatureInterval(_) => OutputKind::Temperature,
             &Output::Duration(_) => OutputKind::Duration,
+            &Output::DurationInterval(_) => OutputKind::Duration,
             &Output::Percentage(_) => OutputKind::Percentage,
         }
     }
@@ -102,6 +110,9 @@ impl OutputKind {
                     _ => false,
                 }
             }
+            // Intervals are reported under the kind of their bounds
+            Dimension::TemperatureInterval(_) => self == &OutputKind::Temperature,
+            Dimension::DurationInterval(_) => self == &OutputKind::Duration,
             _ => self.to_dim() == dimension_value.kind(),
         }
     }
@@ -110,9 +121,24 @@ impl OutputKind {
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct IntegerOutput(pub i64);
 
+/// Integer given by a fuzzy quantifier, e.g. "a few", with the range it covers
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FuzzyIntegerOutput {
+    pub value: i64,
+    pub min: i64,
+    pub max: i64,
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct FloatOutput(pub f64);
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FractionOutput {
+    pub value: f64,
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct PercentageOutput(pub f64);
 
@@ -160,6 +186,7 @@ pub struct AmountOfMoneyOutput {
     pub value: f64,
     pub precision: Precision,
     pub unit: Option<&'static str>,
+    pub fuzzy_range: Option<(f64, f64)>,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -169,18 +196,38 @@ pub struct TemperatureOutput {
     pub latent: bool,
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TemperatureIntervalOutput {
+    pub min: f64,
+    pub max: f64,
+    pub unit: Option<&'static str>,
+    pub latent: bool,
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct DurationOutput {
     pub period: Period,
     pub precision: Precision,
+    pub fuzzy_range: Option<(Period, Period)>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct DurationIntervalOutput {
+    pub min: Period,
+    pub max: Period,
+    pub precision: Precision,
 }
 
 variant_converters!(Output, Integer, IntegerOutput);
+variant_converters!(Output, FuzzyInteger, FuzzyIntegerOutput);
 variant_converters!(Output, Float, FloatOutput);
+variant_converters!(Output, Fraction, FractionOutput);
 variant_converters!(Output, Percentage, PercentageOutput);
 variant_converters!(Output, Ordinal, OrdinalOutput);
 variant_converters!(Output, Datetime, DatetimeOutput);
 variant_converters!(Output, DatetimeInterval, DatetimeIntervalOutput);
 variant_converters!(Output, AmountOfMoney, AmountOfMoneyOutput);
 variant_converters!(Output, Temperature, TemperatureOutput);
+variant_converters!(Output, TemperatureInterval, TemperatureIntervalOutput);
 variant_converters!(Output, Duration, DurationOutput);
+variant_converters!(Output, DurationInterval, DurationIntervalOutput);
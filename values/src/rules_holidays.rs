@@ -0,0 +1,197 @@
+//! Declarative table of the holidays, shared by all grammars. A grammar only lists the names of
+//! the holidays in its language, the celebration rules are generated from the table.
+use rustling::*;
+use moment::{Grain, Observance, Weekday};
+use crate::dimension::*;
+use crate::helpers;
+
+/// How the date of a holiday is computed every year.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HolidayRule {
+    /// Same month and day every year
+    Fixed(u32, u32),
+    /// Nth weekday of a month, counted from the end of the month if negative
+    NthWeekday(i64, Weekday, u32),
+    /// Days after Easter, as dated by the computus of the resolution context
+    EasterOffset(i64),
+    /// Days after Orthodox Easter
+    OrthodoxEasterOffset(i64),
+    /// Month and day of the Chinese lunisolar calendar
+    Lunar(u32, u32),
+    /// Month and day of the tabular Hijri calendar
+    Hijri(u32, u32),
+    /// Month and day of the Hebrew calendar, months being numbered from Nisan
+    Hebrew(u32, u32),
+}
+
+impl HolidayRule {
+    fn first_day(&self) -> RuleResult<DatetimeValue> {
+        match *self {
+            HolidayRule::Fixed(m, d) => helpers::month_day(m, d),
+            HolidayRule::NthWeekday(n, weekday, m) => helpers::nth_weekday_of_month(n, weekday, m),
+            HolidayRule::EasterOffset(0) => helpers::easter(),
+            HolidayRule::EasterOffset(days) => helpers::cycle_nth_after(Grain::Day, days, &helpers::easter()?),
+            HolidayRule::OrthodoxEasterOffset(0) => helpers::orthodox_easter(),
+            HolidayRule::OrthodoxEasterOffset(days) => helpers::cycle_nth_after(Grain::Day, days, &helpers::orthodox_easter()?),
+            HolidayRule::Lunar(m, d) => helpers::lunar_month_day(m, d),
+            HolidayRule::Hijri(m, d) => helpers::hijri_month_day(m, d),
+            HolidayRule::Hebrew(m, d) => helpers::hebrew_month_day(m, d),
+        }
+    }
+}
+
+macro_rules! holidays {
+    ($($holiday:ident: $name:expr, $rule:expr, $days:expr;)*) => {
+        /// Holidays shared by several languages.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        pub enum Holiday {
+            $($holiday,)*
+        }
+
+        impl Holiday {
+            /// Name of the generated rules.
+            pub fn name(&self) -> &'static str {
+                match *self {
+                    $(Holiday::$holiday => $name,)*
+                }
+            }
+
+            /// Date of the holiday, of its first day if it lasts several days.
+            pub fn rule(&self) -> HolidayRule {
+                use self::HolidayRule::*;
+                match *self {
+                    $(Holiday::$holiday => $rule,)*
+                }
+            }
+
+            /// Number of days the holiday lasts.
+            pub fn days(&self) -> i64 {
+                match *self {
+                    $(Holiday::$holiday => $days,)*
+                }
+            }
+        }
+    }
+}
+
+// Adding a holiday takes one line: its name, the date of its first day and how many days it lasts.
+holidays! {
+    NewYearsDay:             "new year's day",             Fixed(1, 1),                     1;
+    ValentinesDay:           "valentine's day",            Fixed(2, 14),                    1;
+    InternationalWomensDay:  "international women's day",  Fixed(3, 8),                     1;
+    SaintJosephsDay:         "saint joseph's day",         Fixed(3, 19),                    1;
+    InternationalWorkersDay: "international workers' day", Fixed(5, 1),                     1;
+    ChildrensDay:            "children's day",             Fixed(6, 1),                     1;
+    Assumption:              "assumption",                 Fixed(8, 15),                    1;
+    Halloween:               "halloween",                  Fixed(10, 31),                   1;
+    ReformationDay:          "reformation day",            Fixed(10, 31),                   1;
+    AllSaintsDay:            "all saints' day",            Fixed(11, 1),                    1;
+    AllSoulsDay:             "all souls' day",             Fixed(11, 2),                    1;
+    Armistice:               "armistice",                  Fixed(11, 11),                   1;
+    SaintMartinsDay:         "saint martin's day",         Fixed(11, 11),                   1;
+    SaintNicholasDay:        "saint nicholas day",         Fixed(12, 6),                    1;
+    ImmaculateConception:    "immaculate conception",      Fixed(12, 8),                    1;
+    ChristmasEve:            "christmas eve",              Fixed(12, 24),                   1;
+    Christmas:               "christmas",                  Fixed(12, 25),                   1;
+    SaintStephensDay:        "saint stephen's day",        Fixed(12, 26),                   1;
+    NewYearsEve:             "new year's eve",             Fixed(12, 31),                   1;
+    UsIndependenceDay:       "US independence day",        Fixed(7, 4),                     1;
+    UsJuneteenth:            "US juneteenth",              Fixed(6, 19),                    1;
+    UsVeteransDay:           "US veterans day",            Fixed(11, 11),                   1;
+    UsFlagDay:               "US flag day",                Fixed(6, 14),                    1;
+    UsPatriotDay:            "US patriot day",             Fixed(9, 11),                    1;
+    UsWomensEqualityDay:     "US women's equality day",    Fixed(8, 26),                    1;
+    FrenchNationalDay:       "french national day",        Fixed(7, 14),                    1;
+    ItalianLiberationDay:    "italian liberation day",     Fixed(4, 25),                    1;
+    SpanishNationalDay:      "spanish national day",       Fixed(10, 12),                   1;
+    SpanishConstitutionDay:  "spanish constitution day",   Fixed(12, 6),                    1;
+    GermanUnityDay:          "german unity day",           Fixed(10, 3),                    1;
+    ChineseArmyDay:          "chinese army day",           Fixed(8, 1),                     1;
+    ChineseNationalDay:      "chinese national day",       Fixed(10, 1),                    1;
+    // Mother's day became parents' day in 1973, Korea has no father's day of its own
+    KoreanParentsDay:        "korean parents' day",        Fixed(5, 8),                     1;
+    MartinLutherKingDay:     "MLK day",                    NthWeekday(3, Weekday::Mon, 1),  1;
+    UsMemorialDay:           "US memorial day",            NthWeekday(-1, Weekday::Mon, 5), 1;
+    UsLaborDay:              "US labor day",               NthWeekday(1, Weekday::Mon, 9),  1;
+    Thanksgiving:            "thanksgiving",               NthWeekday(4, Weekday::Thu, 11), 1;
+    MothersDay:              "mother's day",               NthWeekday(2, Weekday::Sun, 5),  1;
+    FathersDay:              "father's day",               NthWeekday(3, Weekday::Sun, 6),  1;
+    ChineseMothersDay:       "chinese mother's day",       NthWeekday(2, Weekday::Sun, 5),  1;
+    ChineseFathersDay:       "chinese father's day",       NthWeekday(3, Weekday::Sun, 6),  1;
+    ShroveMonday:            "shrove monday",              EasterOffset(-48),               1;
+    ShroveTuesday:           "shrove tuesday",             EasterOffset(-47),               1;
+    AshWednesday:            "ash wednesday",              EasterOffset(-46),               1;
+    PalmSunday:              "palm sunday",                EasterOffset(-7),                1;
+    MaundyThursday:          "maundy thursday",            EasterOffset(-3),                1;
+    GoodFriday:              "good friday",                EasterOffset(-2),                1;
+    HolySaturday:            "holy saturday",              EasterOffset(-1),                1;
+    Easter:                  "easter",                     EasterOffset(0),                 1;
+    EasterMonday:            "easter monday",              EasterOffset(1),                 1;
+    Ascension:               "ascension",                  EasterOffset(39),                1;
+    Pentecost:               "pentecost",                  EasterOffset(49),                1;
+    WhitMonday:              "whit monday",                EasterOffset(50),                1;
+    CorpusChristi:           "corpus christi",             EasterOffset(60),                1;
+    OrthodoxEaster:          "orthodox easter",            OrthodoxEasterOffset(0),         1;
+    CleanMonday:             "clean monday",               OrthodoxEasterOffset(-48),       1;
+    // Up to Orthodox Easter eve
+    GreatLent:               "great lent",                 OrthodoxEasterOffset(-48),       48;
+    OrthodoxGoodFriday:      "orthodox good friday",       OrthodoxEasterOffset(-2),        1;
+    OrthodoxAscension:       "orthodox ascension",         OrthodoxEasterOffset(39),        1;
+    OrthodoxPentecost:       "orthodox pentecost",         OrthodoxEasterOffset(49),        1;
+    LunarNewYear:            "lunar new year",             Lunar(1, 1),                     1;
+    LanternFestival:         "lantern festival",           Lunar(1, 15),                    1;
+    BuddhasBirthday:         "buddha's birthday",          Lunar(4, 8),                     1;
+    DragonBoatFestival:      "dragon boat festival",       Lunar(5, 5),                     1;
+    Qixi:                    "qixi festival",              Lunar(7, 7),                     1;
+    MidAutumnFestival:       "mid-autumn festival",        Lunar(8, 15),                    1;
+    DoubleNinthFestival:     "double ninth festival",      Lunar(9, 9),                     1;
+    IslamicNewYear:          "islamic new year",           Hijri(1, 1),                     1;
+    // The whole month, of 30 days in the tabular calendar
+    Ramadan:                 "ramadan",                    Hijri(9, 1),                     30;
+    EidAlFitr:               "eid al-fitr",                Hijri(10, 1),                    1;
+    EidAlAdha:               "eid al-adha",                Hijri(12, 10),                   1;
+    Passover:                "passover",                   Hebrew(1, 15),                   8;
+    RoshHashanah:            "rosh hashanah",              Hebrew(7, 1),                    2;
+    YomKippur:               "yom kippur",                 Hebrew(7, 10),                   1;
+    Hanukkah:                "hanukkah",                   Hebrew(9, 25),                   8;
+}
+
+impl Holiday {
+    pub fn value(&self) -> RuleResult<DatetimeValue> {
+        let first_day = self.rule().first_day()?;
+        let value = if self.days() > 1 {
+            first_day.span_to(&helpers::cycle_nth_after(Grain::Day, self.days() - 1, &first_day)?, true)?
+        } else {
+            first_day
+        };
+        Ok(value.form(Form::Celebration))
+    }
+}
+
+/// Generates a rule for each holiday name, given as a regex.
+pub fn rules_holidays(b: &mut RuleSetBuilder<Dimension>, names: &[(Holiday, &'static str)]) -> RustlingResult<()> {
+    for &(holiday, name) in names {
+        b.rule_1_terminal(holiday.name(),
+                          b.reg(name)?,
+                          move |_| holiday.value()
+        );
+    }
+    Ok(())
+}
+
+/// Same as `rules_holidays` for fixed-date holidays, each falling on a weekend being moved to a
+/// weekday per its own observance if the resolution context asks for observed dates. A name
+/// followed by `observed`, e.g. "christmas day off", is always moved.
+pub fn rules_observed_holidays(b: &mut RuleSetBuilder<Dimension>, names: &[(Holiday, Observance, &'static str)], observed: &str) -> RustlingResult<()> {
+    for &(holiday, observance, name) in names {
+        b.rule_1_terminal(holiday.name(),
+                          b.reg(name)?,
+                          move |_| Ok(holiday.value()?.observed(observance))
+        );
+        b.rule_1_terminal("<holiday> (observed)",
+                          b.reg(&format!(r#"(?:{}) (?:{})"#, name, observed))?,
+                          move |_| Ok(holiday.value()?.always_observed(observance))
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! Rules for semesters identified by an ordinal, similar to quarters, shared by the grammars that
+//! only differ by the words around them.
+use rustling::*;
+use moment::Grain;
+use crate::dimension::*;
+use crate::helpers;
+
+/// Words of a language around semesters, as regexes.
+pub struct SemesterWords {
+    /// Before "<ordinal> semester", e.g. "the"
+    pub article: &'static str,
+    /// Between a semester and its year, e.g. "of|in"
+    pub of_year: &'static str,
+    /// After the ordinal of "<ordinal> half of the year"
+    pub half_of_year: &'static str,
+    /// Before "<ordinal> half of the year", e.g. "the"
+    pub half_article: &'static str,
+    /// Letter of the "h1" and "h2" abbreviations
+    pub abbreviation: &'static str,
+}
+
+pub fn rules_semester(b: &mut RuleSetBuilder<Dimension>, words: &SemesterWords) -> RustlingResult<()> {
+    let abbreviation = format!(r#"{}([12])"#, words.abbreviation);
+    b.rule_2("<ordinal> semester",
+             ordinal_check_by_range!(1, 2),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Semester),
+             |ordinal, _| helpers::cycle_nth_after(Grain::Semester, ordinal.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+    );
+    b.rule_3("the <ordinal> semester",
+             b.reg(words.article)?,
+             ordinal_check_by_range!(1, 2),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Semester),
+             |_, ordinal, _| helpers::cycle_nth_after(Grain::Semester, ordinal.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+    );
+    b.rule_3("<ordinal> semester <year>",
+             ordinal_check_by_range!(1, 2),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Semester),
+             datetime_check!(form!(Form::Year(_))),
+             |ordinal, _, year| helpers::cycle_nth_after(Grain::Semester, ordinal.value().value - 1, year.value())
+    );
+    b.rule_4("<ordinal> semester of <year>",
+             ordinal_check_by_range!(1, 2),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Semester),
+             b.reg(words.of_year)?,
+             datetime_check!(form!(Form::Year(_))),
+             |ordinal, _, _, year| helpers::cycle_nth_after(Grain::Semester, ordinal.value().value - 1, year.value())
+    );
+    b.rule_2("<ordinal> half of the year",
+             ordinal_check_by_range!(1, 2),
+             b.reg(words.half_of_year)?,
+             |ordinal, _| helpers::cycle_nth_after(Grain::Semester, ordinal.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+    );
+    b.rule_3("the <ordinal> half of the year",
+             b.reg(words.half_article)?,
+             ordinal_check_by_range!(1, 2),
+             b.reg(words.half_of_year)?,
+             |_, ordinal, _| helpers::cycle_nth_after(Grain::Semester, ordinal.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+    );
+    // A bare "h1" is more likely a heading or a code than a semester
+    b.rule_1_terminal("H1-2",
+                      b.reg(&abbreviation)?,
+                      |text_match| Ok(helpers::cycle_nth_after(Grain::Semester, text_match.group(1).parse::<i64>()? - 1, &helpers::cycle_nth(Grain::Year, 0)?)?.latent())
+    );
+    b.rule_2("H1-2 <year>",
+             b.reg(&abbreviation)?,
+             datetime_check!(form!(Form::Year(_))),
+             |text_match, year| helpers::cycle_nth_after(Grain::Semester, text_match.group(1).parse::<i64>()? - 1, year.value())
+    );
+    Ok(())
+}
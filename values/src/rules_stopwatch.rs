@@ -0,0 +1,24 @@
+//! Language independent rules for sub-second durations, decimal seconds and lap times, shared by
+//! all grammars.
+use rustling::*;
+use moment::{Grain, PeriodComp};
+use crate::dimension::*;
+use crate::helpers;
+
+pub fn rules_stopwatch(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<integer> ms",
+             integer_check_by_range!(1),
+             b.reg(r#"ms"#)?,
+             |integer, _| Ok(DurationValue::new(PeriodComp::milliseconds(integer.value().value).into()))
+    );
+    b.rule_2("<decimal> seconds",
+             float_check!(|float: &FloatValue| float.value > 0.0),
+             unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Second),
+             |float, _| helpers::decimal_seconds(float.value().value)
+    );
+    b.rule_1_terminal("lap time (mm:ss.xx)",
+                      b.reg(r#"(?:\d{1,2}:)?\d{1,2}:[0-5]\d[.,]\d{1,3}"#)?,
+                      |text_match| helpers::stopwatch_duration(text_match.group(0))
+    );
+    Ok(())
+}
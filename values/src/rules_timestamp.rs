@@ -0,0 +1,16 @@
+//! Language independent rules for machine-generated datetimes, shared by all grammars.
+use rustling::*;
+use crate::dimension::*;
+use crate::helpers;
+
+pub fn rules_timestamp(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("timestamp - ISO 8601 / RFC 3339",
+                      b.reg(r#"\d{4}-\d{2}-\d{2}[t ]\d{2}:\d{2}:\d{2}(?:[.,]\d{1,9})?(?:z|[+-]\d{2}(?::?\d{2})?)?"#)?,
+                      |text_match| helpers::iso_timestamp(text_match.group(0))
+    );
+    b.rule_1_terminal("timestamp - unix epoch",
+                      b.reg(r#"(?:@|(?:unix|epoch|posix)(?: time(?:stamp)?)? )(\d{9,10})"#)?,
+                      |text_match| helpers::epoch_timestamp(text_match.group(1).parse()?)
+    );
+    Ok(())
+}